@@ -6,5 +6,15 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './allowance';
+export * from './attachment';
+export * from './consumerApproval';
+export * from './cpiConsumer';
+export * from './feeExemption';
+export * from './hold';
 export * from './marginAccount';
+export * from './marginAccountV2';
+export * from './reservation';
 export * from './tSwap';
+export * from './tSwapV2';
+export * from './withdrawalRequest';
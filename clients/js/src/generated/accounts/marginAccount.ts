@@ -52,6 +52,10 @@ export function getMarginAccountDiscriminatorBytes() {
 
 export type MarginAccount = {
   discriminator: ReadonlyUint8Array;
+  /**
+   * Current owner, which is not the key the address was derived from once
+   * ownership has been handed off (see `MarginAccountV2::seed_owner`).
+   */
   owner: Address;
   name: ReadonlyUint8Array;
   nr: number;
@@ -61,6 +65,10 @@ export type MarginAccount = {
 };
 
 export type MarginAccountArgs = {
+  /**
+   * Current owner, which is not the key the address was derived from once
+   * ownership has been handed off (see `MarginAccountV2::seed_owner`).
+   */
   owner: Address;
   name: ReadonlyUint8Array;
   nr: number;
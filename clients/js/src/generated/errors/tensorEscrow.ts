@@ -18,16 +18,168 @@ import { TENSOR_ESCROW_PROGRAM_ADDRESS } from '../programs';
 export const TENSOR_ESCROW_ERROR__BAD_OWNER = 0x1780; // 6016
 /** BadMargin: bad margin account passed */
 export const TENSOR_ESCROW_ERROR__BAD_MARGIN = 0x178b; // 6027
+/** MarginInUse: margin account still has pools or bids attached */
+export const TENSOR_ESCROW_ERROR__MARGIN_IN_USE = 0x178c; // 6028
+/** MarginAlreadyMigrated: margin account is already on the latest version */
+export const TENSOR_ESCROW_ERROR__MARGIN_ALREADY_MIGRATED = 0x178d; // 6029
+/** MarginRentNotCovered: margin account balance does not cover rent for the new layout */
+export const TENSOR_ESCROW_ERROR__MARGIN_RENT_NOT_COVERED = 0x178e; // 6030
+/** BadSeedPrefix: seed prefix must be between 1 and 32 bytes */
+export const TENSOR_ESCROW_ERROR__BAD_SEED_PREFIX = 0x178f; // 6031
+/** BadCpiSigner: signer is not a PDA of a registered cpi consumer */
+export const TENSOR_ESCROW_ERROR__BAD_CPI_SIGNER = 0x1790; // 6032
+/** MarginMismatch: signing pool or bid does not reference this margin account */
+export const TENSOR_ESCROW_ERROR__MARGIN_MISMATCH = 0x1791; // 6033
+/** AmountExceedsBid: amount exceeds what the bid can draw */
+export const TENSOR_ESCROW_ERROR__AMOUNT_EXCEEDS_BID = 0x1792; // 6034
+/** AllowanceExceeded: amount exceeds the delegate's allowance */
+export const TENSOR_ESCROW_ERROR__ALLOWANCE_EXCEEDED = 0x1793; // 6035
+/** AllowanceExpired: delegate allowance has expired */
+export const TENSOR_ESCROW_ERROR__ALLOWANCE_EXPIRED = 0x1794; // 6036
+/** WithdrawalDelayed: margin account withdrawals have to be requested and wait for the delay */
+export const TENSOR_ESCROW_ERROR__WITHDRAWAL_DELAYED = 0x1795; // 6037
+/** WithdrawalLocked: withdrawal is still locked */
+export const TENSOR_ESCROW_ERROR__WITHDRAWAL_LOCKED = 0x1796; // 6038
+/** InsufficientAvailableBalance: amount exceeds the margin account's available balance */
+export const TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE = 0x1797; // 6039
+/** NoWithdrawalDelay: margin account has no withdrawal delay */
+export const TENSOR_ESCROW_ERROR__NO_WITHDRAWAL_DELAY = 0x1798; // 6040
+/** FeeBpsTooHigh: fee bps exceeds the maximum */
+export const TENSOR_ESCROW_ERROR__FEE_BPS_TOO_HIGH = 0x1799; // 6041
+/** BadFeeVault: fee vault must be owned by the system program */
+export const TENSOR_ESCROW_ERROR__BAD_FEE_VAULT = 0x179a; // 6042
+/** TSwapAlreadyMigrated: tswap is already on the latest version */
+export const TENSOR_ESCROW_ERROR__T_SWAP_ALREADY_MIGRATED = 0x179b; // 6043
+/** Paused: instruction is paused */
+export const TENSOR_ESCROW_ERROR__PAUSED = 0x179c; // 6044
+/** OnlyOwnerCanUnpause: only the owner can unpause */
+export const TENSOR_ESCROW_ERROR__ONLY_OWNER_CAN_UNPAUSE = 0x179d; // 6045
+/** RateLimitExceeded: withdrawal exceeds the caller's rate limit */
+export const TENSOR_ESCROW_ERROR__RATE_LIMIT_EXCEEDED = 0x179e; // 6046
+/** BadCpiCaller: caller is not TAMM, TCOMP or a registered cpi consumer */
+export const TENSOR_ESCROW_ERROR__BAD_CPI_CALLER = 0x179f; // 6047
+/** FeeVaultMissing: fee vault has to be passed in when a fee is due */
+export const TENSOR_ESCROW_ERROR__FEE_VAULT_MISSING = 0x17a0; // 6048
+/** BadCosigner: cosigner is not the tswap cosigner */
+export const TENSOR_ESCROW_ERROR__BAD_COSIGNER = 0x17a1; // 6049
+/** CosignRequired: amount exceeds the cosign threshold and has to be co-signed */
+export const TENSOR_ESCROW_ERROR__COSIGN_REQUIRED = 0x17a2; // 6050
+/** MarginCountMismatch: number of margin accounts passed in doesn't match the amounts */
+export const TENSOR_ESCROW_ERROR__MARGIN_COUNT_MISMATCH = 0x17a3; // 6051
+/** CpiDenied: margin account owner doesn't allow this caller */
+export const TENSOR_ESCROW_ERROR__CPI_DENIED = 0x17a4; // 6052
+/** BadReservationAuthority: authority is neither the margin account owner nor the reservation's caller */
+export const TENSOR_ESCROW_ERROR__BAD_RESERVATION_AUTHORITY = 0x17a5; // 6053
+/** HoldExpired: hold expired */
+export const TENSOR_ESCROW_ERROR__HOLD_EXPIRED = 0x17a6; // 6054
+/** HoldNotExpired: hold can only be released by its caller until it expires */
+export const TENSOR_ESCROW_ERROR__HOLD_NOT_EXPIRED = 0x17a7; // 6055
+/** AmountExceedsHold: amount exceeds the hold */
+export const TENSOR_ESCROW_ERROR__AMOUNT_EXCEEDS_HOLD = 0x17a8; // 6056
+/** CurrencyMismatch: mint is not the currency of the pool or bid */
+export const TENSOR_ESCROW_ERROR__CURRENCY_MISMATCH = 0x17a9; // 6057
+/** UnsupportedMint: mints with a transfer hook are not supported */
+export const TENSOR_ESCROW_ERROR__UNSUPPORTED_MINT = 0x17aa; // 6058
+/** MarginNotMigrated: margin account has to be migrated to V2 first */
+export const TENSOR_ESCROW_ERROR__MARGIN_NOT_MIGRATED = 0x17ab; // 6059
+/** ReservedCpiConsumer: TAMM and TCOMP can't be registered as cpi consumers */
+export const TENSOR_ESCROW_ERROR__RESERVED_CPI_CONSUMER = 0x17ac; // 6060
+/** StaleGrant: granted on a closed margin account that was at this address */
+export const TENSOR_ESCROW_ERROR__STALE_GRANT = 0x17ad; // 6061
+/** MarginClosedTooSoon: margin account can't be closed in the slot it was created or migrated in */
+export const TENSOR_ESCROW_ERROR__MARGIN_CLOSED_TOO_SOON = 0x17ae; // 6062
+/** TSwapNotMigrated: tswap has to be migrated to V2 first */
+export const TENSOR_ESCROW_ERROR__T_SWAP_NOT_MIGRATED = 0x17af; // 6063
+/** DuplicateMarginAccount: margin account passed in more than once */
+export const TENSOR_ESCROW_ERROR__DUPLICATE_MARGIN_ACCOUNT = 0x17b0; // 6064
+/** UntrackedAttachments: pools and bids attached while on V1 have to be reconciled first */
+export const TENSOR_ESCROW_ERROR__UNTRACKED_ATTACHMENTS = 0x17b1; // 6065
 
 export type TensorEscrowError =
+  | typeof TENSOR_ESCROW_ERROR__ALLOWANCE_EXCEEDED
+  | typeof TENSOR_ESCROW_ERROR__ALLOWANCE_EXPIRED
+  | typeof TENSOR_ESCROW_ERROR__AMOUNT_EXCEEDS_BID
+  | typeof TENSOR_ESCROW_ERROR__AMOUNT_EXCEEDS_HOLD
+  | typeof TENSOR_ESCROW_ERROR__BAD_COSIGNER
+  | typeof TENSOR_ESCROW_ERROR__BAD_CPI_CALLER
+  | typeof TENSOR_ESCROW_ERROR__BAD_CPI_SIGNER
+  | typeof TENSOR_ESCROW_ERROR__BAD_FEE_VAULT
   | typeof TENSOR_ESCROW_ERROR__BAD_MARGIN
-  | typeof TENSOR_ESCROW_ERROR__BAD_OWNER;
+  | typeof TENSOR_ESCROW_ERROR__BAD_OWNER
+  | typeof TENSOR_ESCROW_ERROR__BAD_RESERVATION_AUTHORITY
+  | typeof TENSOR_ESCROW_ERROR__BAD_SEED_PREFIX
+  | typeof TENSOR_ESCROW_ERROR__COSIGN_REQUIRED
+  | typeof TENSOR_ESCROW_ERROR__CPI_DENIED
+  | typeof TENSOR_ESCROW_ERROR__CURRENCY_MISMATCH
+  | typeof TENSOR_ESCROW_ERROR__DUPLICATE_MARGIN_ACCOUNT
+  | typeof TENSOR_ESCROW_ERROR__FEE_BPS_TOO_HIGH
+  | typeof TENSOR_ESCROW_ERROR__FEE_VAULT_MISSING
+  | typeof TENSOR_ESCROW_ERROR__HOLD_EXPIRED
+  | typeof TENSOR_ESCROW_ERROR__HOLD_NOT_EXPIRED
+  | typeof TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE
+  | typeof TENSOR_ESCROW_ERROR__MARGIN_ALREADY_MIGRATED
+  | typeof TENSOR_ESCROW_ERROR__MARGIN_CLOSED_TOO_SOON
+  | typeof TENSOR_ESCROW_ERROR__MARGIN_COUNT_MISMATCH
+  | typeof TENSOR_ESCROW_ERROR__MARGIN_IN_USE
+  | typeof TENSOR_ESCROW_ERROR__MARGIN_MISMATCH
+  | typeof TENSOR_ESCROW_ERROR__MARGIN_NOT_MIGRATED
+  | typeof TENSOR_ESCROW_ERROR__MARGIN_RENT_NOT_COVERED
+  | typeof TENSOR_ESCROW_ERROR__NO_WITHDRAWAL_DELAY
+  | typeof TENSOR_ESCROW_ERROR__ONLY_OWNER_CAN_UNPAUSE
+  | typeof TENSOR_ESCROW_ERROR__PAUSED
+  | typeof TENSOR_ESCROW_ERROR__RATE_LIMIT_EXCEEDED
+  | typeof TENSOR_ESCROW_ERROR__RESERVED_CPI_CONSUMER
+  | typeof TENSOR_ESCROW_ERROR__STALE_GRANT
+  | typeof TENSOR_ESCROW_ERROR__T_SWAP_ALREADY_MIGRATED
+  | typeof TENSOR_ESCROW_ERROR__T_SWAP_NOT_MIGRATED
+  | typeof TENSOR_ESCROW_ERROR__UNSUPPORTED_MINT
+  | typeof TENSOR_ESCROW_ERROR__UNTRACKED_ATTACHMENTS
+  | typeof TENSOR_ESCROW_ERROR__WITHDRAWAL_DELAYED
+  | typeof TENSOR_ESCROW_ERROR__WITHDRAWAL_LOCKED;
 
 let tensorEscrowErrorMessages: Record<TensorEscrowError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   tensorEscrowErrorMessages = {
+    [TENSOR_ESCROW_ERROR__ALLOWANCE_EXCEEDED]: `amount exceeds the delegate's allowance`,
+    [TENSOR_ESCROW_ERROR__ALLOWANCE_EXPIRED]: `delegate allowance has expired`,
+    [TENSOR_ESCROW_ERROR__AMOUNT_EXCEEDS_BID]: `amount exceeds what the bid can draw`,
+    [TENSOR_ESCROW_ERROR__AMOUNT_EXCEEDS_HOLD]: `amount exceeds the hold`,
+    [TENSOR_ESCROW_ERROR__BAD_COSIGNER]: `cosigner is not the tswap cosigner`,
+    [TENSOR_ESCROW_ERROR__BAD_CPI_CALLER]: `caller is not TAMM, TCOMP or a registered cpi consumer`,
+    [TENSOR_ESCROW_ERROR__BAD_CPI_SIGNER]: `signer is not a PDA of a registered cpi consumer`,
+    [TENSOR_ESCROW_ERROR__BAD_FEE_VAULT]: `fee vault must be owned by the system program`,
     [TENSOR_ESCROW_ERROR__BAD_MARGIN]: `bad margin account passed`,
     [TENSOR_ESCROW_ERROR__BAD_OWNER]: `bad owner`,
+    [TENSOR_ESCROW_ERROR__BAD_RESERVATION_AUTHORITY]: `authority is neither the margin account owner nor the reservation's caller`,
+    [TENSOR_ESCROW_ERROR__BAD_SEED_PREFIX]: `seed prefix must be between 1 and 32 bytes`,
+    [TENSOR_ESCROW_ERROR__COSIGN_REQUIRED]: `amount exceeds the cosign threshold and has to be co-signed`,
+    [TENSOR_ESCROW_ERROR__CPI_DENIED]: `margin account owner doesn't allow this caller`,
+    [TENSOR_ESCROW_ERROR__CURRENCY_MISMATCH]: `mint is not the currency of the pool or bid`,
+    [TENSOR_ESCROW_ERROR__DUPLICATE_MARGIN_ACCOUNT]: `margin account passed in more than once`,
+    [TENSOR_ESCROW_ERROR__FEE_BPS_TOO_HIGH]: `fee bps exceeds the maximum`,
+    [TENSOR_ESCROW_ERROR__FEE_VAULT_MISSING]: `fee vault has to be passed in when a fee is due`,
+    [TENSOR_ESCROW_ERROR__HOLD_EXPIRED]: `hold expired`,
+    [TENSOR_ESCROW_ERROR__HOLD_NOT_EXPIRED]: `hold can only be released by its caller until it expires`,
+    [TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE]: `amount exceeds the margin account's available balance`,
+    [TENSOR_ESCROW_ERROR__MARGIN_ALREADY_MIGRATED]: `margin account is already on the latest version`,
+    [TENSOR_ESCROW_ERROR__MARGIN_CLOSED_TOO_SOON]: `margin account can't be closed in the slot it was created or migrated in`,
+    [TENSOR_ESCROW_ERROR__MARGIN_COUNT_MISMATCH]: `number of margin accounts passed in doesn't match the amounts`,
+    [TENSOR_ESCROW_ERROR__MARGIN_IN_USE]: `margin account still has pools or bids attached`,
+    [TENSOR_ESCROW_ERROR__MARGIN_MISMATCH]: `signing pool or bid does not reference this margin account`,
+    [TENSOR_ESCROW_ERROR__MARGIN_NOT_MIGRATED]: `margin account has to be migrated to V2 first`,
+    [TENSOR_ESCROW_ERROR__MARGIN_RENT_NOT_COVERED]: `margin account balance does not cover rent for the new layout`,
+    [TENSOR_ESCROW_ERROR__NO_WITHDRAWAL_DELAY]: `margin account has no withdrawal delay`,
+    [TENSOR_ESCROW_ERROR__ONLY_OWNER_CAN_UNPAUSE]: `only the owner can unpause`,
+    [TENSOR_ESCROW_ERROR__PAUSED]: `instruction is paused`,
+    [TENSOR_ESCROW_ERROR__RATE_LIMIT_EXCEEDED]: `withdrawal exceeds the caller's rate limit`,
+    [TENSOR_ESCROW_ERROR__RESERVED_CPI_CONSUMER]: `TAMM and TCOMP can't be registered as cpi consumers`,
+    [TENSOR_ESCROW_ERROR__STALE_GRANT]: `granted on a closed margin account that was at this address`,
+    [TENSOR_ESCROW_ERROR__T_SWAP_ALREADY_MIGRATED]: `tswap is already on the latest version`,
+    [TENSOR_ESCROW_ERROR__T_SWAP_NOT_MIGRATED]: `tswap has to be migrated to V2 first`,
+    [TENSOR_ESCROW_ERROR__UNSUPPORTED_MINT]: `mints with a transfer hook are not supported`,
+    [TENSOR_ESCROW_ERROR__UNTRACKED_ATTACHMENTS]: `pools and bids attached while on V1 have to be reconciled first`,
+    [TENSOR_ESCROW_ERROR__WITHDRAWAL_DELAYED]: `margin account withdrawals have to be requested and wait for the delay`,
+    [TENSOR_ESCROW_ERROR__WITHDRAWAL_LOCKED]: `withdrawal is still locked`,
   };
 }
 
@@ -25,6 +25,7 @@ import {
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -52,6 +53,10 @@ export type CloseMarginAccountInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountRentDestination extends string | IAccountMeta<string> = string,
+  TAccountCosigner extends string | IAccountMeta<string> = string,
+  TAccountEventAuthority extends string | IAccountMeta<string> = string,
+  TAccountProgram extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -70,6 +75,19 @@ export type CloseMarginAccountInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountRentDestination extends string
+        ? WritableAccount<TAccountRentDestination>
+        : TAccountRentDestination,
+      TAccountCosigner extends string
+        ? ReadonlySignerAccount<TAccountCosigner> &
+            IAccountSignerMeta<TAccountCosigner>
+        : TAccountCosigner,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -108,11 +126,20 @@ export type CloseMarginAccountAsyncInput<
   TAccountMarginAccount extends string = string,
   TAccountOwner extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountRentDestination extends string = string,
+  TAccountCosigner extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   tswap?: Address<TAccountTswap>;
   marginAccount: Address<TAccountMarginAccount>;
   owner: TransactionSigner<TAccountOwner>;
   systemProgram?: Address<TAccountSystemProgram>;
+  rentDestination?: Address<TAccountRentDestination>;
+  /** Required once the amount exceeds the cosign threshold. */
+  cosigner?: TransactionSigner<TAccountCosigner>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export async function getCloseMarginAccountInstructionAsync<
@@ -120,13 +147,21 @@ export async function getCloseMarginAccountInstructionAsync<
   TAccountMarginAccount extends string,
   TAccountOwner extends string,
   TAccountSystemProgram extends string,
+  TAccountRentDestination extends string,
+  TAccountCosigner extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
   input: CloseMarginAccountAsyncInput<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountRentDestination,
+    TAccountCosigner,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -135,7 +170,11 @@ export async function getCloseMarginAccountInstructionAsync<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountRentDestination,
+    TAccountCosigner,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -148,6 +187,10 @@ export async function getCloseMarginAccountInstructionAsync<
     marginAccount: { value: input.marginAccount ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    rentDestination: { value: input.rentDestination ?? null, isWritable: true },
+    cosigner: { value: input.cosigner ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -170,6 +213,10 @@ export async function getCloseMarginAccountInstructionAsync<
       getAccountMeta(accounts.marginAccount),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.rentDestination),
+      getAccountMeta(accounts.cosigner),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getCloseMarginAccountInstructionDataEncoder().encode({}),
@@ -178,7 +225,11 @@ export async function getCloseMarginAccountInstructionAsync<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountRentDestination,
+    TAccountCosigner,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -189,11 +240,20 @@ export type CloseMarginAccountInput<
   TAccountMarginAccount extends string = string,
   TAccountOwner extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountRentDestination extends string = string,
+  TAccountCosigner extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   tswap: Address<TAccountTswap>;
   marginAccount: Address<TAccountMarginAccount>;
   owner: TransactionSigner<TAccountOwner>;
   systemProgram?: Address<TAccountSystemProgram>;
+  rentDestination?: Address<TAccountRentDestination>;
+  /** Required once the amount exceeds the cosign threshold. */
+  cosigner?: TransactionSigner<TAccountCosigner>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getCloseMarginAccountInstruction<
@@ -201,13 +261,21 @@ export function getCloseMarginAccountInstruction<
   TAccountMarginAccount extends string,
   TAccountOwner extends string,
   TAccountSystemProgram extends string,
+  TAccountRentDestination extends string,
+  TAccountCosigner extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
   input: CloseMarginAccountInput<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountRentDestination,
+    TAccountCosigner,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseMarginAccountInstruction<
@@ -215,7 +283,11 @@ export function getCloseMarginAccountInstruction<
   TAccountTswap,
   TAccountMarginAccount,
   TAccountOwner,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountRentDestination,
+  TAccountCosigner,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -227,6 +299,10 @@ export function getCloseMarginAccountInstruction<
     marginAccount: { value: input.marginAccount ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    rentDestination: { value: input.rentDestination ?? null, isWritable: true },
+    cosigner: { value: input.cosigner ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -246,6 +322,10 @@ export function getCloseMarginAccountInstruction<
       getAccountMeta(accounts.marginAccount),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.rentDestination),
+      getAccountMeta(accounts.cosigner),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getCloseMarginAccountInstructionDataEncoder().encode({}),
@@ -254,7 +334,11 @@ export function getCloseMarginAccountInstruction<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountRentDestination,
+    TAccountCosigner,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -270,6 +354,11 @@ export type ParsedCloseMarginAccountInstruction<
     marginAccount: TAccountMetas[1];
     owner: TAccountMetas[2];
     systemProgram: TAccountMetas[3];
+    rentDestination?: TAccountMetas[4] | undefined;
+    /** Required once the amount exceeds the cosign threshold. */
+    cosigner?: TAccountMetas[5] | undefined;
+    eventAuthority?: TAccountMetas[6] | undefined;
+    program?: TAccountMetas[7] | undefined;
   };
   data: CloseMarginAccountInstructionData;
 };
@@ -282,7 +371,7 @@ export function parseCloseMarginAccountInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedCloseMarginAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -292,6 +381,13 @@ export function parseCloseMarginAccountInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return
+      accountMeta.address === TENSOR_ESCROW_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -299,6 +395,10 @@ export function parseCloseMarginAccountInstruction<
       marginAccount: getNextAccount(),
       owner: getNextAccount(),
       systemProgram: getNextAccount(),
+      rentDestination: getNextOptionalAccount(),
+      cosigner: getNextOptionalAccount(),
+      eventAuthority: getNextOptionalAccount(),
+      program: getNextOptionalAccount(),
     },
     data: getCloseMarginAccountInstructionDataDecoder().decode(
       instruction.data
@@ -58,6 +58,8 @@ export type DepositMarginAccountInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | IAccountMeta<string> = string,
+  TAccountProgram extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -76,6 +78,12 @@ export type DepositMarginAccountInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -124,11 +132,15 @@ export type DepositMarginAccountAsyncInput<
   TAccountMarginAccount extends string = string,
   TAccountOwner extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   tswap?: Address<TAccountTswap>;
   marginAccount?: Address<TAccountMarginAccount>;
   owner: TransactionSigner<TAccountOwner>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   lamports: DepositMarginAccountInstructionDataArgs['lamports'];
 };
 
@@ -137,13 +149,17 @@ export async function getDepositMarginAccountInstructionAsync<
   TAccountMarginAccount extends string,
   TAccountOwner extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
   input: DepositMarginAccountAsyncInput<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -152,7 +168,9 @@ export async function getDepositMarginAccountInstructionAsync<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -165,6 +183,8 @@ export async function getDepositMarginAccountInstructionAsync<
     marginAccount: { value: input.marginAccount ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -197,6 +217,8 @@ export async function getDepositMarginAccountInstructionAsync<
       getAccountMeta(accounts.marginAccount),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getDepositMarginAccountInstructionDataEncoder().encode(
@@ -207,7 +229,9 @@ export async function getDepositMarginAccountInstructionAsync<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -218,11 +242,15 @@ export type DepositMarginAccountInput<
   TAccountMarginAccount extends string = string,
   TAccountOwner extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   tswap: Address<TAccountTswap>;
   marginAccount: Address<TAccountMarginAccount>;
   owner: TransactionSigner<TAccountOwner>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   lamports: DepositMarginAccountInstructionDataArgs['lamports'];
 };
 
@@ -231,13 +259,17 @@ export function getDepositMarginAccountInstruction<
   TAccountMarginAccount extends string,
   TAccountOwner extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
   input: DepositMarginAccountInput<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): DepositMarginAccountInstruction<
@@ -245,7 +277,9 @@ export function getDepositMarginAccountInstruction<
   TAccountTswap,
   TAccountMarginAccount,
   TAccountOwner,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -257,6 +291,8 @@ export function getDepositMarginAccountInstruction<
     marginAccount: { value: input.marginAccount ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -279,6 +315,8 @@ export function getDepositMarginAccountInstruction<
       getAccountMeta(accounts.marginAccount),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getDepositMarginAccountInstructionDataEncoder().encode(
@@ -289,7 +327,9 @@ export function getDepositMarginAccountInstruction<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -305,6 +345,8 @@ export type ParsedDepositMarginAccountInstruction<
     marginAccount: TAccountMetas[1];
     owner: TAccountMetas[2];
     systemProgram: TAccountMetas[3];
+    eventAuthority?: TAccountMetas[4] | undefined;
+    program?: TAccountMetas[5] | undefined;
   };
   data: DepositMarginAccountInstructionData;
 };
@@ -317,7 +359,7 @@ export function parseDepositMarginAccountInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDepositMarginAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -327,6 +369,13 @@ export function parseDepositMarginAccountInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return
+      accountMeta.address === TENSOR_ESCROW_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -334,6 +383,8 @@ export function parseDepositMarginAccountInstruction<
       marginAccount: getNextAccount(),
       owner: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextOptionalAccount(),
+      program: getNextOptionalAccount(),
     },
     data: getDepositMarginAccountInstructionDataDecoder().decode(
       instruction.data
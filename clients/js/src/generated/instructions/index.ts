@@ -6,10 +6,65 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptMarginOwner';
+export * from './acceptTswapOwner';
+export * from './addFeeExemption';
+export * from './approveAllowance';
+export * from './approveConsumer';
+export * from './attachMarginAccountCpiTamm';
+export * from './attachMarginAccountCpiTcomp';
+export * from './cancelWithdrawal';
+export * from './captureHoldCpiTamm';
+export * from './captureHoldCpiTcomp';
+export * from './closeCpiConsumer';
 export * from './closeMarginAccount';
+export * from './consolidateMarginAccounts';
 export * from './depositMarginAccount';
+export * from './depositMarginAccountFor';
+export * from './depositMarginAccountToken';
+export * from './depositMarginAccounts';
+export * from './detachMarginAccountCpiTamm';
+export * from './detachMarginAccountCpiTcomp';
+export * from './executeWithdrawal';
 export * from './initMarginAccount';
-export * from './initUpdateTswap';
+export * from './initMarginAccountV2';
+export * from './initTswap';
+export * from './initUpdateCpiConsumer';
+export * from './migrateMarginAccount';
+export * from './migrateTswap';
+export * from './placeHoldCpiTamm';
+export * from './placeHoldCpiTcomp';
+export * from './proposeMarginOwner';
+export * from './proposeTswapOwner';
+export * from './reconcileAttachments';
+export * from './releaseHold';
+export * from './releaseReservation';
+export * from './removeFeeExemption';
+export * from './requestWithdrawal';
+export * from './reserveMarginLamports';
+export * from './reserveMarginLamportsFromConsumer';
+export * from './revokeAllowance';
+export * from './revokeConsumer';
+export * from './setCosignThreshold';
+export * from './setCosigner';
+export * from './setCpiDenied';
+export * from './setCpiFee';
+export * from './setFeeVault';
+export * from './setFeesEnabled';
+export * from './setGuardian';
+export * from './setHoldExpiry';
+export * from './setMarginCosignThreshold';
+export * from './setPaused';
+export * from './setRateLimit';
+export * from './setWithdrawalDelay';
+export * from './spendAllowance';
+export * from './transferBetweenMarginAccounts';
+export * from './updateTswapConfig';
+export * from './withdrawFees';
 export * from './withdrawMarginAccount';
 export * from './withdrawMarginAccountCpiTamm';
 export * from './withdrawMarginAccountCpiTcomp';
+export * from './withdrawMarginAccountFromConsumer';
+export * from './withdrawMarginAccountToken';
+export * from './withdrawMarginAccountTokenCpiTamm';
+export * from './withdrawMarginAccountTokenCpiTcomp';
@@ -59,6 +59,8 @@ export type InitMarginAccountInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | IAccountMeta<string> = string,
+  TAccountProgram extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -77,6 +79,12 @@ export type InitMarginAccountInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -136,11 +144,15 @@ export type InitMarginAccountAsyncInput<
   TAccountMarginAccount extends string = string,
   TAccountOwner extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   tswap?: Address<TAccountTswap>;
   marginAccount?: Address<TAccountMarginAccount>;
   owner: TransactionSigner<TAccountOwner>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   marginNr?: InitMarginAccountInstructionDataArgs['marginNr'];
   name?: InitMarginAccountInstructionDataArgs['name'];
 };
@@ -150,13 +162,17 @@ export async function getInitMarginAccountInstructionAsync<
   TAccountMarginAccount extends string,
   TAccountOwner extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
   input: InitMarginAccountAsyncInput<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -165,7 +181,9 @@ export async function getInitMarginAccountInstructionAsync<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -178,6 +196,8 @@ export async function getInitMarginAccountInstructionAsync<
     marginAccount: { value: input.marginAccount ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -213,6 +233,8 @@ export async function getInitMarginAccountInstructionAsync<
       getAccountMeta(accounts.marginAccount),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getInitMarginAccountInstructionDataEncoder().encode(
@@ -223,7 +245,9 @@ export async function getInitMarginAccountInstructionAsync<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -234,11 +258,15 @@ export type InitMarginAccountInput<
   TAccountMarginAccount extends string = string,
   TAccountOwner extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   tswap: Address<TAccountTswap>;
   marginAccount: Address<TAccountMarginAccount>;
   owner: TransactionSigner<TAccountOwner>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   marginNr?: InitMarginAccountInstructionDataArgs['marginNr'];
   name?: InitMarginAccountInstructionDataArgs['name'];
 };
@@ -248,13 +276,17 @@ export function getInitMarginAccountInstruction<
   TAccountMarginAccount extends string,
   TAccountOwner extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
   input: InitMarginAccountInput<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitMarginAccountInstruction<
@@ -262,7 +294,9 @@ export function getInitMarginAccountInstruction<
   TAccountTswap,
   TAccountMarginAccount,
   TAccountOwner,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -274,6 +308,8 @@ export function getInitMarginAccountInstruction<
     marginAccount: { value: input.marginAccount ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -299,6 +335,8 @@ export function getInitMarginAccountInstruction<
       getAccountMeta(accounts.marginAccount),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getInitMarginAccountInstructionDataEncoder().encode(
@@ -309,7 +347,9 @@ export function getInitMarginAccountInstruction<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -325,6 +365,8 @@ export type ParsedInitMarginAccountInstruction<
     marginAccount: TAccountMetas[1];
     owner: TAccountMetas[2];
     systemProgram: TAccountMetas[3];
+    eventAuthority?: TAccountMetas[4] | undefined;
+    program?: TAccountMetas[5] | undefined;
   };
   data: InitMarginAccountInstructionData;
 };
@@ -337,7 +379,7 @@ export function parseInitMarginAccountInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedInitMarginAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -347,6 +389,13 @@ export function parseInitMarginAccountInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return
+      accountMeta.address === TENSOR_ESCROW_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -354,6 +403,8 @@ export function parseInitMarginAccountInstruction<
       marginAccount: getNextAccount(),
       owner: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextOptionalAccount(),
+      program: getNextOptionalAccount(),
     },
     data: getInitMarginAccountInstructionDataDecoder().decode(instruction.data),
   };
@@ -41,17 +41,15 @@ import {
   type TSwapConfigArgs,
 } from '../types';
 
-export const INIT_UPDATE_TSWAP_DISCRIMINATOR = new Uint8Array([
-  140, 185, 54, 172, 15, 94, 31, 155,
+export const INIT_TSWAP_DISCRIMINATOR = new Uint8Array([
+  23, 40, 66, 36, 249, 61, 97, 195,
 ]);
 
-export function getInitUpdateTswapDiscriminatorBytes() {
-  return fixEncoderSize(getBytesEncoder(), 8).encode(
-    INIT_UPDATE_TSWAP_DISCRIMINATOR
-  );
+export function getInitTswapDiscriminatorBytes() {
+  return fixEncoderSize(getBytesEncoder(), 8).encode(INIT_TSWAP_DISCRIMINATOR);
 }
 
-export type InitUpdateTswapInstruction<
+export type InitTswapInstruction<
   TProgram extends string = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
   TAccountTswap extends string | IAccountMeta<string> = string,
   TAccountFeeVault extends string | IAccountMeta<string> = string,
@@ -60,7 +58,8 @@ export type InitUpdateTswapInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
-  TAccountNewOwner extends string | IAccountMeta<string> = string,
+  TAccountEventAuthority extends string | IAccountMeta<string> = string,
+  TAccountProgram extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -83,55 +82,58 @@ export type InitUpdateTswapInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
-      TAccountNewOwner extends string
-        ? ReadonlySignerAccount<TAccountNewOwner> &
-            IAccountSignerMeta<TAccountNewOwner>
-        : TAccountNewOwner,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
-export type InitUpdateTswapInstructionData = {
+export type InitTswapInstructionData = {
   discriminator: ReadonlyUint8Array;
   config: TSwapConfig;
 };
 
-export type InitUpdateTswapInstructionDataArgs = { config: TSwapConfigArgs };
+export type InitTswapInstructionDataArgs = { config: TSwapConfigArgs };
 
-export function getInitUpdateTswapInstructionDataEncoder(): Encoder<InitUpdateTswapInstructionDataArgs> {
+export function getInitTswapInstructionDataEncoder(): Encoder<InitTswapInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['config', getTSwapConfigEncoder()],
     ]),
-    (value) => ({ ...value, discriminator: INIT_UPDATE_TSWAP_DISCRIMINATOR })
+    (value) => ({ ...value, discriminator: INIT_TSWAP_DISCRIMINATOR })
   );
 }
 
-export function getInitUpdateTswapInstructionDataDecoder(): Decoder<InitUpdateTswapInstructionData> {
+export function getInitTswapInstructionDataDecoder(): Decoder<InitTswapInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['config', getTSwapConfigDecoder()],
   ]);
 }
 
-export function getInitUpdateTswapInstructionDataCodec(): Codec<
-  InitUpdateTswapInstructionDataArgs,
-  InitUpdateTswapInstructionData
+export function getInitTswapInstructionDataCodec(): Codec<
+  InitTswapInstructionDataArgs,
+  InitTswapInstructionData
 > {
   return combineCodec(
-    getInitUpdateTswapInstructionDataEncoder(),
-    getInitUpdateTswapInstructionDataDecoder()
+    getInitTswapInstructionDataEncoder(),
+    getInitTswapInstructionDataDecoder()
   );
 }
 
-export type InitUpdateTswapAsyncInput<
+export type InitTswapAsyncInput<
   TAccountTswap extends string = string,
   TAccountFeeVault extends string = string,
   TAccountCosigner extends string = string,
   TAccountOwner extends string = string,
   TAccountSystemProgram extends string = string,
-  TAccountNewOwner extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   tswap?: Address<TAccountTswap>;
   feeVault: Address<TAccountFeeVault>;
@@ -139,37 +141,41 @@ export type InitUpdateTswapAsyncInput<
   cosigner: TransactionSigner<TAccountCosigner>;
   owner: TransactionSigner<TAccountOwner>;
   systemProgram?: Address<TAccountSystemProgram>;
-  newOwner: TransactionSigner<TAccountNewOwner>;
-  config: InitUpdateTswapInstructionDataArgs['config'];
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+  config: InitTswapInstructionDataArgs['config'];
 };
 
-export async function getInitUpdateTswapInstructionAsync<
+export async function getInitTswapInstructionAsync<
   TAccountTswap extends string,
   TAccountFeeVault extends string,
   TAccountCosigner extends string,
   TAccountOwner extends string,
   TAccountSystemProgram extends string,
-  TAccountNewOwner extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
-  input: InitUpdateTswapAsyncInput<
+  input: InitTswapAsyncInput<
     TAccountTswap,
     TAccountFeeVault,
     TAccountCosigner,
     TAccountOwner,
     TAccountSystemProgram,
-    TAccountNewOwner
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
-  InitUpdateTswapInstruction<
+  InitTswapInstruction<
     TProgramAddress,
     TAccountTswap,
     TAccountFeeVault,
     TAccountCosigner,
     TAccountOwner,
     TAccountSystemProgram,
-    TAccountNewOwner
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -183,7 +189,8 @@ export async function getInitUpdateTswapInstructionAsync<
     cosigner: { value: input.cosigner ?? null, isWritable: false },
     owner: { value: input.owner ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
-    newOwner: { value: input.newOwner ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -210,32 +217,35 @@ export async function getInitUpdateTswapInstructionAsync<
       getAccountMeta(accounts.cosigner),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.systemProgram),
-      getAccountMeta(accounts.newOwner),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
-    data: getInitUpdateTswapInstructionDataEncoder().encode(
-      args as InitUpdateTswapInstructionDataArgs
+    data: getInitTswapInstructionDataEncoder().encode(
+      args as InitTswapInstructionDataArgs
     ),
-  } as InitUpdateTswapInstruction<
+  } as InitTswapInstruction<
     TProgramAddress,
     TAccountTswap,
     TAccountFeeVault,
     TAccountCosigner,
     TAccountOwner,
     TAccountSystemProgram,
-    TAccountNewOwner
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
 }
 
-export type InitUpdateTswapInput<
+export type InitTswapInput<
   TAccountTswap extends string = string,
   TAccountFeeVault extends string = string,
   TAccountCosigner extends string = string,
   TAccountOwner extends string = string,
   TAccountSystemProgram extends string = string,
-  TAccountNewOwner extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   tswap: Address<TAccountTswap>;
   feeVault: Address<TAccountFeeVault>;
@@ -243,36 +253,40 @@ export type InitUpdateTswapInput<
   cosigner: TransactionSigner<TAccountCosigner>;
   owner: TransactionSigner<TAccountOwner>;
   systemProgram?: Address<TAccountSystemProgram>;
-  newOwner: TransactionSigner<TAccountNewOwner>;
-  config: InitUpdateTswapInstructionDataArgs['config'];
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+  config: InitTswapInstructionDataArgs['config'];
 };
 
-export function getInitUpdateTswapInstruction<
+export function getInitTswapInstruction<
   TAccountTswap extends string,
   TAccountFeeVault extends string,
   TAccountCosigner extends string,
   TAccountOwner extends string,
   TAccountSystemProgram extends string,
-  TAccountNewOwner extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
-  input: InitUpdateTswapInput<
+  input: InitTswapInput<
     TAccountTswap,
     TAccountFeeVault,
     TAccountCosigner,
     TAccountOwner,
     TAccountSystemProgram,
-    TAccountNewOwner
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
-): InitUpdateTswapInstruction<
+): InitTswapInstruction<
   TProgramAddress,
   TAccountTswap,
   TAccountFeeVault,
   TAccountCosigner,
   TAccountOwner,
   TAccountSystemProgram,
-  TAccountNewOwner
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -285,7 +299,8 @@ export function getInitUpdateTswapInstruction<
     cosigner: { value: input.cosigner ?? null, isWritable: false },
     owner: { value: input.owner ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
-    newOwner: { value: input.newOwner ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -309,26 +324,28 @@ export function getInitUpdateTswapInstruction<
       getAccountMeta(accounts.cosigner),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.systemProgram),
-      getAccountMeta(accounts.newOwner),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
-    data: getInitUpdateTswapInstructionDataEncoder().encode(
-      args as InitUpdateTswapInstructionDataArgs
+    data: getInitTswapInstructionDataEncoder().encode(
+      args as InitTswapInstructionDataArgs
     ),
-  } as InitUpdateTswapInstruction<
+  } as InitTswapInstruction<
     TProgramAddress,
     TAccountTswap,
     TAccountFeeVault,
     TAccountCosigner,
     TAccountOwner,
     TAccountSystemProgram,
-    TAccountNewOwner
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
 }
 
-export type ParsedInitUpdateTswapInstruction<
+export type ParsedInitTswapInstruction<
   TProgram extends string = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
   TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
 > = {
@@ -340,20 +357,21 @@ export type ParsedInitUpdateTswapInstruction<
     cosigner: TAccountMetas[2];
     owner: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
-    newOwner: TAccountMetas[5];
+    eventAuthority?: TAccountMetas[5] | undefined;
+    program?: TAccountMetas[6] | undefined;
   };
-  data: InitUpdateTswapInstructionData;
+  data: InitTswapInstructionData;
 };
 
-export function parseInitUpdateTswapInstruction<
+export function parseInitTswapInstruction<
   TProgram extends string,
   TAccountMetas extends readonly IAccountMeta[],
 >(
   instruction: IInstruction<TProgram> &
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
-): ParsedInitUpdateTswapInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+): ParsedInitTswapInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -363,6 +381,13 @@ export function parseInitUpdateTswapInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return
+      accountMeta.address === TENSOR_ESCROW_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -371,8 +396,9 @@ export function parseInitUpdateTswapInstruction<
       cosigner: getNextAccount(),
       owner: getNextAccount(),
       systemProgram: getNextAccount(),
-      newOwner: getNextAccount(),
+      eventAuthority: getNextOptionalAccount(),
+      program: getNextOptionalAccount(),
     },
-    data: getInitUpdateTswapInstructionDataDecoder().decode(instruction.data),
+    data: getInitTswapInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -27,6 +27,7 @@ import {
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -58,6 +59,10 @@ export type WithdrawMarginAccountInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountCosigner extends string | IAccountMeta<string> = string,
+  TAccountDestination extends string | IAccountMeta<string> = string,
+  TAccountEventAuthority extends string | IAccountMeta<string> = string,
+  TAccountProgram extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -76,6 +81,19 @@ export type WithdrawMarginAccountInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountCosigner extends string
+        ? ReadonlySignerAccount<TAccountCosigner> &
+            IAccountSignerMeta<TAccountCosigner>
+        : TAccountCosigner,
+      TAccountDestination extends string
+        ? WritableAccount<TAccountDestination>
+        : TAccountDestination,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -124,11 +142,21 @@ export type WithdrawMarginAccountAsyncInput<
   TAccountMarginAccount extends string = string,
   TAccountOwner extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountCosigner extends string = string,
+  TAccountDestination extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   tswap?: Address<TAccountTswap>;
   marginAccount?: Address<TAccountMarginAccount>;
   owner: TransactionSigner<TAccountOwner>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Required once the amount exceeds the cosign threshold. */
+  cosigner?: TransactionSigner<TAccountCosigner>;
+  /** The owner when not passed in. */
+  destination?: Address<TAccountDestination>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   lamports: WithdrawMarginAccountInstructionDataArgs['lamports'];
 };
 
@@ -137,13 +165,21 @@ export async function getWithdrawMarginAccountInstructionAsync<
   TAccountMarginAccount extends string,
   TAccountOwner extends string,
   TAccountSystemProgram extends string,
+  TAccountCosigner extends string,
+  TAccountDestination extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
   input: WithdrawMarginAccountAsyncInput<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCosigner,
+    TAccountDestination,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -152,7 +188,11 @@ export async function getWithdrawMarginAccountInstructionAsync<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCosigner,
+    TAccountDestination,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -165,6 +205,10 @@ export async function getWithdrawMarginAccountInstructionAsync<
     marginAccount: { value: input.marginAccount ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    cosigner: { value: input.cosigner ?? null, isWritable: false },
+    destination: { value: input.destination ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -197,6 +241,10 @@ export async function getWithdrawMarginAccountInstructionAsync<
       getAccountMeta(accounts.marginAccount),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.cosigner),
+      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getWithdrawMarginAccountInstructionDataEncoder().encode(
@@ -207,7 +255,11 @@ export async function getWithdrawMarginAccountInstructionAsync<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCosigner,
+    TAccountDestination,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -218,11 +270,21 @@ export type WithdrawMarginAccountInput<
   TAccountMarginAccount extends string = string,
   TAccountOwner extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountCosigner extends string = string,
+  TAccountDestination extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   tswap: Address<TAccountTswap>;
   marginAccount: Address<TAccountMarginAccount>;
   owner: TransactionSigner<TAccountOwner>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Required once the amount exceeds the cosign threshold. */
+  cosigner?: TransactionSigner<TAccountCosigner>;
+  /** The owner when not passed in. */
+  destination?: Address<TAccountDestination>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   lamports: WithdrawMarginAccountInstructionDataArgs['lamports'];
 };
 
@@ -231,13 +293,21 @@ export function getWithdrawMarginAccountInstruction<
   TAccountMarginAccount extends string,
   TAccountOwner extends string,
   TAccountSystemProgram extends string,
+  TAccountCosigner extends string,
+  TAccountDestination extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
   input: WithdrawMarginAccountInput<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCosigner,
+    TAccountDestination,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): WithdrawMarginAccountInstruction<
@@ -245,7 +315,11 @@ export function getWithdrawMarginAccountInstruction<
   TAccountTswap,
   TAccountMarginAccount,
   TAccountOwner,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountCosigner,
+  TAccountDestination,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -257,6 +331,10 @@ export function getWithdrawMarginAccountInstruction<
     marginAccount: { value: input.marginAccount ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    cosigner: { value: input.cosigner ?? null, isWritable: false },
+    destination: { value: input.destination ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -279,6 +357,10 @@ export function getWithdrawMarginAccountInstruction<
       getAccountMeta(accounts.marginAccount),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.cosigner),
+      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getWithdrawMarginAccountInstructionDataEncoder().encode(
@@ -289,7 +371,11 @@ export function getWithdrawMarginAccountInstruction<
     TAccountTswap,
     TAccountMarginAccount,
     TAccountOwner,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountCosigner,
+    TAccountDestination,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -305,6 +391,12 @@ export type ParsedWithdrawMarginAccountInstruction<
     marginAccount: TAccountMetas[1];
     owner: TAccountMetas[2];
     systemProgram: TAccountMetas[3];
+    /** Required once the amount exceeds the cosign threshold. */
+    cosigner?: TAccountMetas[4] | undefined;
+    /** The owner when not passed in. */
+    destination?: TAccountMetas[5] | undefined;
+    eventAuthority?: TAccountMetas[6] | undefined;
+    program?: TAccountMetas[7] | undefined;
   };
   data: WithdrawMarginAccountInstructionData;
 };
@@ -317,7 +409,7 @@ export function parseWithdrawMarginAccountInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedWithdrawMarginAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -327,6 +419,13 @@ export function parseWithdrawMarginAccountInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return
+      accountMeta.address === TENSOR_ESCROW_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -334,6 +433,10 @@ export function parseWithdrawMarginAccountInstruction<
       marginAccount: getNextAccount(),
       owner: getNextAccount(),
       systemProgram: getNextAccount(),
+      cosigner: getNextOptionalAccount(),
+      destination: getNextOptionalAccount(),
+      eventAuthority: getNextOptionalAccount(),
+      program: getNextOptionalAccount(),
     },
     data: getWithdrawMarginAccountInstructionDataDecoder().decode(
       instruction.data
@@ -34,8 +34,15 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findTSwapPda } from '../pdas';
 import { TENSOR_ESCROW_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  WithdrawMode,
+  getWithdrawModeDecoder,
+  getWithdrawModeEncoder,
+  type WithdrawModeArgs,
+} from '../types';
 
 export const WITHDRAW_MARGIN_ACCOUNT_CPI_TAMM_DISCRIMINATOR = new Uint8Array([
   35, 89, 16, 235, 226, 89, 248, 45,
@@ -56,6 +63,12 @@ export type WithdrawMarginAccountCpiTammInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTswap extends string | IAccountMeta<string> = string,
+  TAccountFeeVault extends string | IAccountMeta<string> = string,
+  TAccountFeeExemption extends string | IAccountMeta<string> = string,
+  TAccountReservation extends string | IAccountMeta<string> = string,
+  TAccountEventAuthority extends string | IAccountMeta<string> = string,
+  TAccountProgram extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -76,6 +89,24 @@ export type WithdrawMarginAccountCpiTammInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountTswap extends string
+        ? WritableAccount<TAccountTswap>
+        : TAccountTswap,
+      TAccountFeeVault extends string
+        ? WritableAccount<TAccountFeeVault>
+        : TAccountFeeVault,
+      TAccountFeeExemption extends string
+        ? ReadonlyAccount<TAccountFeeExemption>
+        : TAccountFeeExemption,
+      TAccountReservation extends string
+        ? WritableAccount<TAccountReservation>
+        : TAccountReservation,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -85,12 +116,14 @@ export type WithdrawMarginAccountCpiTammInstructionData = {
   bump: number;
   poolId: ReadonlyUint8Array;
   lamports: bigint;
+  mode: WithdrawMode;
 };
 
 export type WithdrawMarginAccountCpiTammInstructionDataArgs = {
   bump: number;
   poolId: ReadonlyUint8Array;
   lamports: number | bigint;
+  mode?: WithdrawModeArgs;
 };
 
 export function getWithdrawMarginAccountCpiTammInstructionDataEncoder(): Encoder<WithdrawMarginAccountCpiTammInstructionDataArgs> {
@@ -100,10 +133,12 @@ export function getWithdrawMarginAccountCpiTammInstructionDataEncoder(): Encoder
       ['bump', getU8Encoder()],
       ['poolId', fixEncoderSize(getBytesEncoder(), 32)],
       ['lamports', getU64Encoder()],
+      ['mode', getWithdrawModeEncoder()],
     ]),
     (value) => ({
       ...value,
       discriminator: WITHDRAW_MARGIN_ACCOUNT_CPI_TAMM_DISCRIMINATOR,
+      mode: value.mode ?? WithdrawMode.Exact,
     })
   );
 }
@@ -114,6 +149,7 @@ export function getWithdrawMarginAccountCpiTammInstructionDataDecoder(): Decoder
     ['bump', getU8Decoder()],
     ['poolId', fixDecoderSize(getBytesDecoder(), 32)],
     ['lamports', getU64Decoder()],
+    ['mode', getWithdrawModeDecoder()],
   ]);
 }
 
@@ -127,21 +163,180 @@ export function getWithdrawMarginAccountCpiTammInstructionDataCodec(): Codec<
   );
 }
 
+export type WithdrawMarginAccountCpiTammAsyncInput<
+  TAccountMarginAccount extends string = string,
+  TAccountPool extends string = string,
+  TAccountOwner extends string = string,
+  TAccountDestination extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTswap extends string = string,
+  TAccountFeeVault extends string = string,
+  TAccountFeeExemption extends string = string,
+  TAccountReservation extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  marginAccount: Address<TAccountMarginAccount>;
+  pool: TransactionSigner<TAccountPool>;
+  owner: Address<TAccountOwner>;
+  destination: Address<TAccountDestination>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tswap?: Address<TAccountTswap>;
+  feeVault?: Address<TAccountFeeVault>;
+  feeExemption?: Address<TAccountFeeExemption>;
+  reservation?: Address<TAccountReservation>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+  bump: WithdrawMarginAccountCpiTammInstructionDataArgs['bump'];
+  poolId: WithdrawMarginAccountCpiTammInstructionDataArgs['poolId'];
+  lamports: WithdrawMarginAccountCpiTammInstructionDataArgs['lamports'];
+  mode?: WithdrawMarginAccountCpiTammInstructionDataArgs['mode'];
+};
+
+export async function getWithdrawMarginAccountCpiTammInstructionAsync<
+  TAccountMarginAccount extends string,
+  TAccountPool extends string,
+  TAccountOwner extends string,
+  TAccountDestination extends string,
+  TAccountSystemProgram extends string,
+  TAccountTswap extends string,
+  TAccountFeeVault extends string,
+  TAccountFeeExemption extends string,
+  TAccountReservation extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
+>(
+  input: WithdrawMarginAccountCpiTammAsyncInput<
+    TAccountMarginAccount,
+    TAccountPool,
+    TAccountOwner,
+    TAccountDestination,
+    TAccountSystemProgram,
+    TAccountTswap,
+    TAccountFeeVault,
+    TAccountFeeExemption,
+    TAccountReservation,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  WithdrawMarginAccountCpiTammInstruction<
+    TProgramAddress,
+    TAccountMarginAccount,
+    TAccountPool,
+    TAccountOwner,
+    TAccountDestination,
+    TAccountSystemProgram,
+    TAccountTswap,
+    TAccountFeeVault,
+    TAccountFeeExemption,
+    TAccountReservation,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? TENSOR_ESCROW_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    marginAccount: { value: input.marginAccount ?? null, isWritable: true },
+    pool: { value: input.pool ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: false },
+    destination: { value: input.destination ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tswap: { value: input.tswap ?? null, isWritable: true },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
+    feeExemption: { value: input.feeExemption ?? null, isWritable: false },
+    reservation: { value: input.reservation ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tswap.value) {
+    accounts.tswap.value = await findTSwapPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.marginAccount),
+      getAccountMeta(accounts.pool),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tswap),
+      getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.feeExemption),
+      getAccountMeta(accounts.reservation),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    programAddress,
+    data: getWithdrawMarginAccountCpiTammInstructionDataEncoder().encode(
+      args as WithdrawMarginAccountCpiTammInstructionDataArgs
+    ),
+  } as WithdrawMarginAccountCpiTammInstruction<
+    TProgramAddress,
+    TAccountMarginAccount,
+    TAccountPool,
+    TAccountOwner,
+    TAccountDestination,
+    TAccountSystemProgram,
+    TAccountTswap,
+    TAccountFeeVault,
+    TAccountFeeExemption,
+    TAccountReservation,
+    TAccountEventAuthority,
+    TAccountProgram
+  >;
+
+  return instruction;
+}
+
 export type WithdrawMarginAccountCpiTammInput<
   TAccountMarginAccount extends string = string,
   TAccountPool extends string = string,
   TAccountOwner extends string = string,
   TAccountDestination extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountTswap extends string = string,
+  TAccountFeeVault extends string = string,
+  TAccountFeeExemption extends string = string,
+  TAccountReservation extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   marginAccount: Address<TAccountMarginAccount>;
   pool: TransactionSigner<TAccountPool>;
   owner: Address<TAccountOwner>;
   destination: Address<TAccountDestination>;
   systemProgram?: Address<TAccountSystemProgram>;
+  tswap: Address<TAccountTswap>;
+  feeVault?: Address<TAccountFeeVault>;
+  feeExemption?: Address<TAccountFeeExemption>;
+  reservation?: Address<TAccountReservation>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   bump: WithdrawMarginAccountCpiTammInstructionDataArgs['bump'];
   poolId: WithdrawMarginAccountCpiTammInstructionDataArgs['poolId'];
   lamports: WithdrawMarginAccountCpiTammInstructionDataArgs['lamports'];
+  mode?: WithdrawMarginAccountCpiTammInstructionDataArgs['mode'];
 };
 
 export function getWithdrawMarginAccountCpiTammInstruction<
@@ -150,6 +345,12 @@ export function getWithdrawMarginAccountCpiTammInstruction<
   TAccountOwner extends string,
   TAccountDestination extends string,
   TAccountSystemProgram extends string,
+  TAccountTswap extends string,
+  TAccountFeeVault extends string,
+  TAccountFeeExemption extends string,
+  TAccountReservation extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
   input: WithdrawMarginAccountCpiTammInput<
@@ -157,7 +358,13 @@ export function getWithdrawMarginAccountCpiTammInstruction<
     TAccountPool,
     TAccountOwner,
     TAccountDestination,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTswap,
+    TAccountFeeVault,
+    TAccountFeeExemption,
+    TAccountReservation,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): WithdrawMarginAccountCpiTammInstruction<
@@ -166,7 +373,13 @@ export function getWithdrawMarginAccountCpiTammInstruction<
   TAccountPool,
   TAccountOwner,
   TAccountDestination,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountTswap,
+  TAccountFeeVault,
+  TAccountFeeExemption,
+  TAccountReservation,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -179,6 +392,12 @@ export function getWithdrawMarginAccountCpiTammInstruction<
     owner: { value: input.owner ?? null, isWritable: false },
     destination: { value: input.destination ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tswap: { value: input.tswap ?? null, isWritable: true },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
+    feeExemption: { value: input.feeExemption ?? null, isWritable: false },
+    reservation: { value: input.reservation ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -202,6 +421,12 @@ export function getWithdrawMarginAccountCpiTammInstruction<
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.destination),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tswap),
+      getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.feeExemption),
+      getAccountMeta(accounts.reservation),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getWithdrawMarginAccountCpiTammInstructionDataEncoder().encode(
@@ -213,7 +438,13 @@ export function getWithdrawMarginAccountCpiTammInstruction<
     TAccountPool,
     TAccountOwner,
     TAccountDestination,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTswap,
+    TAccountFeeVault,
+    TAccountFeeExemption,
+    TAccountReservation,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -230,6 +461,12 @@ export type ParsedWithdrawMarginAccountCpiTammInstruction<
     owner: TAccountMetas[2];
     destination: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    tswap: TAccountMetas[5];
+    feeVault?: TAccountMetas[6] | undefined;
+    feeExemption?: TAccountMetas[7] | undefined;
+    reservation?: TAccountMetas[8] | undefined;
+    eventAuthority?: TAccountMetas[9] | undefined;
+    program?: TAccountMetas[10] | undefined;
   };
   data: WithdrawMarginAccountCpiTammInstructionData;
 };
@@ -242,7 +479,7 @@ export function parseWithdrawMarginAccountCpiTammInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedWithdrawMarginAccountCpiTammInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -252,6 +489,13 @@ export function parseWithdrawMarginAccountCpiTammInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return
+      accountMeta.address === TENSOR_ESCROW_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -260,6 +504,12 @@ export function parseWithdrawMarginAccountCpiTammInstruction<
       owner: getNextAccount(),
       destination: getNextAccount(),
       systemProgram: getNextAccount(),
+      tswap: getNextAccount(),
+      feeVault: getNextOptionalAccount(),
+      feeExemption: getNextOptionalAccount(),
+      reservation: getNextOptionalAccount(),
+      eventAuthority: getNextOptionalAccount(),
+      program: getNextOptionalAccount(),
     },
     data: getWithdrawMarginAccountCpiTammInstructionDataDecoder().decode(
       instruction.data
@@ -36,8 +36,15 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findTSwapPda } from '../pdas';
 import { TENSOR_ESCROW_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  WithdrawMode,
+  getWithdrawModeDecoder,
+  getWithdrawModeEncoder,
+  type WithdrawModeArgs,
+} from '../types';
 
 export const WITHDRAW_MARGIN_ACCOUNT_CPI_TCOMP_DISCRIMINATOR = new Uint8Array([
   201, 156, 163, 27, 243, 14, 36, 237,
@@ -58,6 +65,12 @@ export type WithdrawMarginAccountCpiTcompInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTswap extends string | IAccountMeta<string> = string,
+  TAccountFeeVault extends string | IAccountMeta<string> = string,
+  TAccountFeeExemption extends string | IAccountMeta<string> = string,
+  TAccountReservation extends string | IAccountMeta<string> = string,
+  TAccountEventAuthority extends string | IAccountMeta<string> = string,
+  TAccountProgram extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -79,6 +92,24 @@ export type WithdrawMarginAccountCpiTcompInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountTswap extends string
+        ? WritableAccount<TAccountTswap>
+        : TAccountTswap,
+      TAccountFeeVault extends string
+        ? WritableAccount<TAccountFeeVault>
+        : TAccountFeeVault,
+      TAccountFeeExemption extends string
+        ? ReadonlyAccount<TAccountFeeExemption>
+        : TAccountFeeExemption,
+      TAccountReservation extends string
+        ? WritableAccount<TAccountReservation>
+        : TAccountReservation,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -88,12 +119,14 @@ export type WithdrawMarginAccountCpiTcompInstructionData = {
   bump: number;
   bidId: Address;
   lamports: bigint;
+  mode: WithdrawMode;
 };
 
 export type WithdrawMarginAccountCpiTcompInstructionDataArgs = {
   bump: number;
   bidId: Address;
   lamports: number | bigint;
+  mode?: WithdrawModeArgs;
 };
 
 export function getWithdrawMarginAccountCpiTcompInstructionDataEncoder(): Encoder<WithdrawMarginAccountCpiTcompInstructionDataArgs> {
@@ -103,10 +136,12 @@ export function getWithdrawMarginAccountCpiTcompInstructionDataEncoder(): Encode
       ['bump', getU8Encoder()],
       ['bidId', getAddressEncoder()],
       ['lamports', getU64Encoder()],
+      ['mode', getWithdrawModeEncoder()],
     ]),
     (value) => ({
       ...value,
       discriminator: WITHDRAW_MARGIN_ACCOUNT_CPI_TCOMP_DISCRIMINATOR,
+      mode: value.mode ?? WithdrawMode.Exact,
     })
   );
 }
@@ -117,6 +152,7 @@ export function getWithdrawMarginAccountCpiTcompInstructionDataDecoder(): Decode
     ['bump', getU8Decoder()],
     ['bidId', getAddressDecoder()],
     ['lamports', getU64Decoder()],
+    ['mode', getWithdrawModeDecoder()],
   ]);
 }
 
@@ -130,21 +166,180 @@ export function getWithdrawMarginAccountCpiTcompInstructionDataCodec(): Codec<
   );
 }
 
+export type WithdrawMarginAccountCpiTcompAsyncInput<
+  TAccountMarginAccount extends string = string,
+  TAccountBidState extends string = string,
+  TAccountOwner extends string = string,
+  TAccountDestination extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTswap extends string = string,
+  TAccountFeeVault extends string = string,
+  TAccountFeeExemption extends string = string,
+  TAccountReservation extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  marginAccount: Address<TAccountMarginAccount>;
+  bidState: TransactionSigner<TAccountBidState>;
+  owner: Address<TAccountOwner>;
+  destination: Address<TAccountDestination>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tswap?: Address<TAccountTswap>;
+  feeVault?: Address<TAccountFeeVault>;
+  feeExemption?: Address<TAccountFeeExemption>;
+  reservation?: Address<TAccountReservation>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+  bump: WithdrawMarginAccountCpiTcompInstructionDataArgs['bump'];
+  bidId: WithdrawMarginAccountCpiTcompInstructionDataArgs['bidId'];
+  lamports: WithdrawMarginAccountCpiTcompInstructionDataArgs['lamports'];
+  mode?: WithdrawMarginAccountCpiTcompInstructionDataArgs['mode'];
+};
+
+export async function getWithdrawMarginAccountCpiTcompInstructionAsync<
+  TAccountMarginAccount extends string,
+  TAccountBidState extends string,
+  TAccountOwner extends string,
+  TAccountDestination extends string,
+  TAccountSystemProgram extends string,
+  TAccountTswap extends string,
+  TAccountFeeVault extends string,
+  TAccountFeeExemption extends string,
+  TAccountReservation extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
+>(
+  input: WithdrawMarginAccountCpiTcompAsyncInput<
+    TAccountMarginAccount,
+    TAccountBidState,
+    TAccountOwner,
+    TAccountDestination,
+    TAccountSystemProgram,
+    TAccountTswap,
+    TAccountFeeVault,
+    TAccountFeeExemption,
+    TAccountReservation,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  WithdrawMarginAccountCpiTcompInstruction<
+    TProgramAddress,
+    TAccountMarginAccount,
+    TAccountBidState,
+    TAccountOwner,
+    TAccountDestination,
+    TAccountSystemProgram,
+    TAccountTswap,
+    TAccountFeeVault,
+    TAccountFeeExemption,
+    TAccountReservation,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? TENSOR_ESCROW_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    marginAccount: { value: input.marginAccount ?? null, isWritable: true },
+    bidState: { value: input.bidState ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: false },
+    destination: { value: input.destination ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tswap: { value: input.tswap ?? null, isWritable: true },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
+    feeExemption: { value: input.feeExemption ?? null, isWritable: false },
+    reservation: { value: input.reservation ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tswap.value) {
+    accounts.tswap.value = await findTSwapPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.marginAccount),
+      getAccountMeta(accounts.bidState),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tswap),
+      getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.feeExemption),
+      getAccountMeta(accounts.reservation),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    programAddress,
+    data: getWithdrawMarginAccountCpiTcompInstructionDataEncoder().encode(
+      args as WithdrawMarginAccountCpiTcompInstructionDataArgs
+    ),
+  } as WithdrawMarginAccountCpiTcompInstruction<
+    TProgramAddress,
+    TAccountMarginAccount,
+    TAccountBidState,
+    TAccountOwner,
+    TAccountDestination,
+    TAccountSystemProgram,
+    TAccountTswap,
+    TAccountFeeVault,
+    TAccountFeeExemption,
+    TAccountReservation,
+    TAccountEventAuthority,
+    TAccountProgram
+  >;
+
+  return instruction;
+}
+
 export type WithdrawMarginAccountCpiTcompInput<
   TAccountMarginAccount extends string = string,
   TAccountBidState extends string = string,
   TAccountOwner extends string = string,
   TAccountDestination extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountTswap extends string = string,
+  TAccountFeeVault extends string = string,
+  TAccountFeeExemption extends string = string,
+  TAccountReservation extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   marginAccount: Address<TAccountMarginAccount>;
   bidState: TransactionSigner<TAccountBidState>;
   owner: Address<TAccountOwner>;
   destination: Address<TAccountDestination>;
   systemProgram?: Address<TAccountSystemProgram>;
+  tswap: Address<TAccountTswap>;
+  feeVault?: Address<TAccountFeeVault>;
+  feeExemption?: Address<TAccountFeeExemption>;
+  reservation?: Address<TAccountReservation>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   bump: WithdrawMarginAccountCpiTcompInstructionDataArgs['bump'];
   bidId: WithdrawMarginAccountCpiTcompInstructionDataArgs['bidId'];
   lamports: WithdrawMarginAccountCpiTcompInstructionDataArgs['lamports'];
+  mode?: WithdrawMarginAccountCpiTcompInstructionDataArgs['mode'];
 };
 
 export function getWithdrawMarginAccountCpiTcompInstruction<
@@ -153,6 +348,12 @@ export function getWithdrawMarginAccountCpiTcompInstruction<
   TAccountOwner extends string,
   TAccountDestination extends string,
   TAccountSystemProgram extends string,
+  TAccountTswap extends string,
+  TAccountFeeVault extends string,
+  TAccountFeeExemption extends string,
+  TAccountReservation extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof TENSOR_ESCROW_PROGRAM_ADDRESS,
 >(
   input: WithdrawMarginAccountCpiTcompInput<
@@ -160,7 +361,13 @@ export function getWithdrawMarginAccountCpiTcompInstruction<
     TAccountBidState,
     TAccountOwner,
     TAccountDestination,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTswap,
+    TAccountFeeVault,
+    TAccountFeeExemption,
+    TAccountReservation,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): WithdrawMarginAccountCpiTcompInstruction<
@@ -169,7 +376,13 @@ export function getWithdrawMarginAccountCpiTcompInstruction<
   TAccountBidState,
   TAccountOwner,
   TAccountDestination,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountTswap,
+  TAccountFeeVault,
+  TAccountFeeExemption,
+  TAccountReservation,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -182,6 +395,12 @@ export function getWithdrawMarginAccountCpiTcompInstruction<
     owner: { value: input.owner ?? null, isWritable: false },
     destination: { value: input.destination ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tswap: { value: input.tswap ?? null, isWritable: true },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
+    feeExemption: { value: input.feeExemption ?? null, isWritable: false },
+    reservation: { value: input.reservation ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -205,6 +424,12 @@ export function getWithdrawMarginAccountCpiTcompInstruction<
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.destination),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tswap),
+      getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.feeExemption),
+      getAccountMeta(accounts.reservation),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
     data: getWithdrawMarginAccountCpiTcompInstructionDataEncoder().encode(
@@ -216,7 +441,13 @@ export function getWithdrawMarginAccountCpiTcompInstruction<
     TAccountBidState,
     TAccountOwner,
     TAccountDestination,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTswap,
+    TAccountFeeVault,
+    TAccountFeeExemption,
+    TAccountReservation,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -233,6 +464,12 @@ export type ParsedWithdrawMarginAccountCpiTcompInstruction<
     owner: TAccountMetas[2];
     destination: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    tswap: TAccountMetas[5];
+    feeVault?: TAccountMetas[6] | undefined;
+    feeExemption?: TAccountMetas[7] | undefined;
+    reservation?: TAccountMetas[8] | undefined;
+    eventAuthority?: TAccountMetas[9] | undefined;
+    program?: TAccountMetas[10] | undefined;
   };
   data: WithdrawMarginAccountCpiTcompInstructionData;
 };
@@ -245,7 +482,7 @@ export function parseWithdrawMarginAccountCpiTcompInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedWithdrawMarginAccountCpiTcompInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -255,6 +492,13 @@ export function parseWithdrawMarginAccountCpiTcompInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return
+      accountMeta.address === TENSOR_ESCROW_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -263,6 +507,12 @@ export function parseWithdrawMarginAccountCpiTcompInstruction<
       owner: getNextAccount(),
       destination: getNextAccount(),
       systemProgram: getNextAccount(),
+      tswap: getNextAccount(),
+      feeVault: getNextOptionalAccount(),
+      feeExemption: getNextOptionalAccount(),
+      reservation: getNextOptionalAccount(),
+      eventAuthority: getNextOptionalAccount(),
+      program: getNextOptionalAccount(),
     },
     data: getWithdrawMarginAccountCpiTcompInstructionDataDecoder().decode(
       instruction.data
@@ -7,4 +7,5 @@
  */
 
 export * from './marginAccount';
+export * from './marginAccountV2';
 export * from './tSwap';
@@ -14,21 +14,86 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/web3.js';
 import {
+  type ParsedAcceptMarginOwnerInstruction,
+  type ParsedAcceptTswapOwnerInstruction,
+  type ParsedAddFeeExemptionInstruction,
+  type ParsedApproveAllowanceInstruction,
+  type ParsedApproveConsumerInstruction,
+  type ParsedAttachMarginAccountCpiTammInstruction,
+  type ParsedAttachMarginAccountCpiTcompInstruction,
+  type ParsedCancelWithdrawalInstruction,
+  type ParsedCaptureHoldCpiTammInstruction,
+  type ParsedCaptureHoldCpiTcompInstruction,
+  type ParsedCloseCpiConsumerInstruction,
   type ParsedCloseMarginAccountInstruction,
+  type ParsedConsolidateMarginAccountsInstruction,
+  type ParsedDepositMarginAccountForInstruction,
   type ParsedDepositMarginAccountInstruction,
+  type ParsedDepositMarginAccountTokenInstruction,
+  type ParsedDepositMarginAccountsInstruction,
+  type ParsedDetachMarginAccountCpiTammInstruction,
+  type ParsedDetachMarginAccountCpiTcompInstruction,
+  type ParsedExecuteWithdrawalInstruction,
   type ParsedInitMarginAccountInstruction,
-  type ParsedInitUpdateTswapInstruction,
+  type ParsedInitMarginAccountV2Instruction,
+  type ParsedInitTswapInstruction,
+  type ParsedInitUpdateCpiConsumerInstruction,
+  type ParsedMigrateMarginAccountInstruction,
+  type ParsedMigrateTswapInstruction,
+  type ParsedPlaceHoldCpiTammInstruction,
+  type ParsedPlaceHoldCpiTcompInstruction,
+  type ParsedProposeMarginOwnerInstruction,
+  type ParsedProposeTswapOwnerInstruction,
+  type ParsedReconcileAttachmentsInstruction,
+  type ParsedReleaseHoldInstruction,
+  type ParsedReleaseReservationInstruction,
+  type ParsedRemoveFeeExemptionInstruction,
+  type ParsedRequestWithdrawalInstruction,
+  type ParsedReserveMarginLamportsFromConsumerInstruction,
+  type ParsedReserveMarginLamportsInstruction,
+  type ParsedRevokeAllowanceInstruction,
+  type ParsedRevokeConsumerInstruction,
+  type ParsedSetCosignThresholdInstruction,
+  type ParsedSetCosignerInstruction,
+  type ParsedSetCpiDeniedInstruction,
+  type ParsedSetCpiFeeInstruction,
+  type ParsedSetFeeVaultInstruction,
+  type ParsedSetFeesEnabledInstruction,
+  type ParsedSetGuardianInstruction,
+  type ParsedSetHoldExpiryInstruction,
+  type ParsedSetMarginCosignThresholdInstruction,
+  type ParsedSetPausedInstruction,
+  type ParsedSetRateLimitInstruction,
+  type ParsedSetWithdrawalDelayInstruction,
+  type ParsedSpendAllowanceInstruction,
+  type ParsedTransferBetweenMarginAccountsInstruction,
+  type ParsedUpdateTswapConfigInstruction,
+  type ParsedWithdrawFeesInstruction,
   type ParsedWithdrawMarginAccountCpiTammInstruction,
   type ParsedWithdrawMarginAccountCpiTcompInstruction,
+  type ParsedWithdrawMarginAccountFromConsumerInstruction,
   type ParsedWithdrawMarginAccountInstruction,
+  type ParsedWithdrawMarginAccountTokenCpiTammInstruction,
+  type ParsedWithdrawMarginAccountTokenCpiTcompInstruction,
+  type ParsedWithdrawMarginAccountTokenInstruction,
 } from '../instructions';
 
 export const TENSOR_ESCROW_PROGRAM_ADDRESS =
   'TSWAPaqyCSx2KABk68Shruf4rp7CxcNi8hAsbdwmHbN' as Address<'TSWAPaqyCSx2KABk68Shruf4rp7CxcNi8hAsbdwmHbN'>;
 
 export enum TensorEscrowAccount {
+  Allowance,
+  Attachment,
+  ConsumerApproval,
+  CpiConsumer,
+  FeeExemption,
+  Hold,
   MarginAccount,
+  MarginAccountV2,
+  Reservation,
   TSwap,
+  TSwapV2,
+  WithdrawalRequest,
 }
 
 export function identifyTensorEscrowAccount(
@@ -36,6 +101,73 @@ export function identifyTensorEscrowAccount(
 ): TensorEscrowAccount {
   const data = 'data' in account ? account.data : account;
   if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([144, 8, 184, 213, 49, 248, 73, 131])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowAccount.Allowance;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([255, 150, 79, 28, 206, 74, 113, 70])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowAccount.Attachment;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([45, 6, 42, 252, 42, 237, 206, 3])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowAccount.ConsumerApproval;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([228, 136, 169, 243, 204, 43, 73, 37])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowAccount.CpiConsumer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([203, 171, 87, 15, 227, 37, 162, 31])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowAccount.FeeExemption;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([110, 65, 238, 142, 146, 91, 196, 171])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowAccount.Hold;
+  }
+  if (
+    data.length === 143 &&
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
@@ -46,7 +178,31 @@ export function identifyTensorEscrowAccount(
   ) {
     return TensorEscrowAccount.MarginAccount;
   }
+  if (
+    data.length === 344 &&
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([133, 220, 173, 213, 179, 211, 43, 238])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowAccount.MarginAccountV2;
+  }
   if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([188, 235, 0, 111, 208, 253, 247, 212])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowAccount.Reservation;
+  }
+  if (
+    data.length === 108 &&
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
@@ -57,19 +213,97 @@ export function identifyTensorEscrowAccount(
   ) {
     return TensorEscrowAccount.TSwap;
   }
+  if (
+    data.length === 364 &&
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([169, 211, 171, 36, 219, 189, 79, 188])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowAccount.TSwapV2;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([242, 88, 147, 173, 182, 62, 229, 193])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowAccount.WithdrawalRequest;
+  }
   throw new Error(
     'The provided account could not be identified as a tensorEscrow account.'
   );
 }
 
 export enum TensorEscrowInstruction {
-  InitUpdateTswap,
+  InitTswap,
+  MigrateTswap,
+  UpdateTswapConfig,
+  SetCosigner,
+  SetFeeVault,
+  ProposeTswapOwner,
+  AcceptTswapOwner,
+  SetGuardian,
+  SetPaused,
+  SetRateLimit,
+  SetFeesEnabled,
+  SetCpiFee,
+  AddFeeExemption,
+  RemoveFeeExemption,
+  WithdrawFees,
+  SetCosignThreshold,
+  SetHoldExpiry,
   InitMarginAccount,
   CloseMarginAccount,
   DepositMarginAccount,
   WithdrawMarginAccount,
   WithdrawMarginAccountCpiTamm,
   WithdrawMarginAccountCpiTcomp,
+  DepositMarginAccountToken,
+  WithdrawMarginAccountToken,
+  WithdrawMarginAccountTokenCpiTamm,
+  WithdrawMarginAccountTokenCpiTcomp,
+  AttachMarginAccountCpiTamm,
+  DetachMarginAccountCpiTamm,
+  AttachMarginAccountCpiTcomp,
+  DetachMarginAccountCpiTcomp,
+  MigrateMarginAccount,
+  ReconcileAttachments,
+  InitMarginAccountV2,
+  DepositMarginAccountFor,
+  DepositMarginAccounts,
+  InitUpdateCpiConsumer,
+  CloseCpiConsumer,
+  ApproveConsumer,
+  RevokeConsumer,
+  WithdrawMarginAccountFromConsumer,
+  ApproveAllowance,
+  RevokeAllowance,
+  SpendAllowance,
+  SetWithdrawalDelay,
+  SetMarginCosignThreshold,
+  SetCpiDenied,
+  RequestWithdrawal,
+  CancelWithdrawal,
+  ExecuteWithdrawal,
+  TransferBetweenMarginAccounts,
+  ConsolidateMarginAccounts,
+  ReserveMarginLamports,
+  ReserveMarginLamportsFromConsumer,
+  ReleaseReservation,
+  PlaceHoldCpiTamm,
+  PlaceHoldCpiTcomp,
+  CaptureHoldCpiTamm,
+  CaptureHoldCpiTcomp,
+  ReleaseHold,
+  ProposeMarginOwner,
+  AcceptMarginOwner,
 }
 
 export function identifyTensorEscrowInstruction(
@@ -80,12 +314,188 @@ export function identifyTensorEscrowInstruction(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([140, 185, 54, 172, 15, 94, 31, 155])
+        new Uint8Array([23, 40, 66, 36, 249, 61, 97, 195])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.InitTswap;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([114, 204, 189, 128, 47, 63, 78, 121])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.MigrateTswap;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([106, 40, 232, 201, 153, 30, 0, 234])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.UpdateTswapConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([208, 219, 250, 102, 122, 211, 46, 213])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetCosigner;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([123, 251, 35, 203, 85, 56, 54, 5])
       ),
       0
     )
   ) {
-    return TensorEscrowInstruction.InitUpdateTswap;
+    return TensorEscrowInstruction.SetFeeVault;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([226, 132, 248, 45, 41, 25, 213, 182])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ProposeTswapOwner;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([132, 240, 210, 67, 116, 134, 114, 207])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.AcceptTswapOwner;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([147, 243, 50, 121, 154, 164, 50, 30])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetGuardian;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([91, 60, 125, 192, 176, 225, 166, 218])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetPaused;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([42, 212, 44, 91, 198, 58, 60, 239])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetRateLimit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([1, 99, 82, 126, 60, 7, 25, 133])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetFeesEnabled;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([222, 159, 72, 155, 216, 224, 41, 207])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetCpiFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([189, 238, 101, 182, 238, 47, 93, 30])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.AddFeeExemption;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([158, 59, 24, 139, 29, 141, 63, 15])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.RemoveFeeExemption;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([198, 212, 171, 109, 144, 215, 174, 89])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.WithdrawFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([141, 70, 31, 162, 252, 64, 12, 26])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetCosignThreshold;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 224, 224, 233, 130, 146, 213, 37])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetHoldExpiry;
   }
   if (
     containsBytes(
@@ -153,32 +563,626 @@ export function identifyTensorEscrowInstruction(
   ) {
     return TensorEscrowInstruction.WithdrawMarginAccountCpiTcomp;
   }
-  throw new Error(
-    'The provided instruction could not be identified as a tensorEscrow instruction.'
-  );
-}
-
-export type ParsedTensorEscrowInstruction<
-  TProgram extends string = 'TSWAPaqyCSx2KABk68Shruf4rp7CxcNi8hAsbdwmHbN',
-> =
-  | ({
-      instructionType: TensorEscrowInstruction.InitUpdateTswap;
-    } & ParsedInitUpdateTswapInstruction<TProgram>)
-  | ({
-      instructionType: TensorEscrowInstruction.InitMarginAccount;
-    } & ParsedInitMarginAccountInstruction<TProgram>)
-  | ({
-      instructionType: TensorEscrowInstruction.CloseMarginAccount;
-    } & ParsedCloseMarginAccountInstruction<TProgram>)
-  | ({
-      instructionType: TensorEscrowInstruction.DepositMarginAccount;
-    } & ParsedDepositMarginAccountInstruction<TProgram>)
-  | ({
-      instructionType: TensorEscrowInstruction.WithdrawMarginAccount;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([160, 49, 64, 249, 190, 99, 86, 30])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.DepositMarginAccountToken;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([239, 228, 73, 136, 81, 184, 208, 173])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.WithdrawMarginAccountToken;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([51, 62, 238, 111, 205, 193, 77, 149])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.WithdrawMarginAccountTokenCpiTamm;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([238, 19, 59, 162, 198, 0, 225, 77])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.WithdrawMarginAccountTokenCpiTcomp;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([243, 100, 247, 41, 109, 20, 101, 242])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.AttachMarginAccountCpiTamm;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([227, 29, 11, 12, 240, 154, 38, 3])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.DetachMarginAccountCpiTamm;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([41, 200, 218, 84, 154, 231, 190, 101])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.AttachMarginAccountCpiTcomp;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([16, 68, 196, 123, 201, 205, 86, 24])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.DetachMarginAccountCpiTcomp;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 121, 150, 5, 134, 49, 5, 125])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.MigrateMarginAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([43, 221, 158, 230, 203, 30, 184, 119])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ReconcileAttachments;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([92, 255, 14, 91, 40, 83, 18, 29])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.InitMarginAccountV2;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([181, 104, 173, 164, 204, 123, 68, 151])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.DepositMarginAccountFor;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([151, 77, 62, 226, 76, 109, 33, 147])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.DepositMarginAccounts;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([199, 164, 38, 88, 50, 210, 83, 104])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.InitUpdateCpiConsumer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([19, 254, 74, 12, 120, 225, 61, 117])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.CloseCpiConsumer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([180, 133, 239, 239, 146, 15, 14, 218])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ApproveConsumer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([35, 107, 227, 233, 115, 162, 127, 173])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.RevokeConsumer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([186, 26, 199, 134, 220, 177, 32, 72])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.WithdrawMarginAccountFromConsumer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([100, 169, 165, 25, 25, 255, 11, 45])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ApproveAllowance;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([121, 114, 141, 153, 128, 164, 101, 113])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.RevokeAllowance;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([3, 59, 105, 56, 209, 44, 44, 7])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SpendAllowance;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([188, 153, 14, 109, 50, 127, 169, 158])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetWithdrawalDelay;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([127, 170, 63, 194, 67, 132, 64, 116])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetMarginCosignThreshold;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([143, 172, 47, 217, 115, 76, 172, 60])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.SetCpiDenied;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([251, 85, 121, 205, 56, 201, 12, 177])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.RequestWithdrawal;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([183, 104, 181, 250, 28, 128, 210, 70])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.CancelWithdrawal;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([113, 121, 203, 232, 137, 139, 248, 249])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ExecuteWithdrawal;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([87, 80, 7, 135, 12, 249, 48, 5])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.TransferBetweenMarginAccounts;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([134, 236, 84, 85, 239, 211, 148, 21])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ConsolidateMarginAccounts;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([197, 167, 175, 207, 178, 117, 250, 131])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ReserveMarginLamports;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 106, 96, 81, 196, 192, 140, 244])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ReserveMarginLamportsFromConsumer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([57, 15, 101, 135, 113, 21, 168, 247])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ReleaseReservation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([101, 199, 4, 217, 235, 168, 118, 161])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.PlaceHoldCpiTamm;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 247, 11, 208, 102, 198, 159, 163])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.PlaceHoldCpiTcomp;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([48, 211, 132, 18, 150, 58, 135, 247])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.CaptureHoldCpiTamm;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([73, 250, 80, 100, 131, 48, 45, 194])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.CaptureHoldCpiTcomp;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([106, 109, 70, 162, 197, 158, 92, 243])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ReleaseHold;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([4, 55, 0, 231, 138, 94, 163, 181])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.ProposeMarginOwner;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([42, 95, 248, 212, 100, 98, 31, 198])
+      ),
+      0
+    )
+  ) {
+    return TensorEscrowInstruction.AcceptMarginOwner;
+  }
+  throw new Error(
+    'The provided instruction could not be identified as a tensorEscrow instruction.'
+  );
+}
+
+export type ParsedTensorEscrowInstruction<
+  TProgram extends string = 'TSWAPaqyCSx2KABk68Shruf4rp7CxcNi8hAsbdwmHbN',
+> =
+  | ({
+      instructionType: TensorEscrowInstruction.InitTswap;
+    } & ParsedInitTswapInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.MigrateTswap;
+    } & ParsedMigrateTswapInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.UpdateTswapConfig;
+    } & ParsedUpdateTswapConfigInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetCosigner;
+    } & ParsedSetCosignerInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetFeeVault;
+    } & ParsedSetFeeVaultInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ProposeTswapOwner;
+    } & ParsedProposeTswapOwnerInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.AcceptTswapOwner;
+    } & ParsedAcceptTswapOwnerInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetGuardian;
+    } & ParsedSetGuardianInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetPaused;
+    } & ParsedSetPausedInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetRateLimit;
+    } & ParsedSetRateLimitInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetFeesEnabled;
+    } & ParsedSetFeesEnabledInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetCpiFee;
+    } & ParsedSetCpiFeeInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.AddFeeExemption;
+    } & ParsedAddFeeExemptionInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.RemoveFeeExemption;
+    } & ParsedRemoveFeeExemptionInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.WithdrawFees;
+    } & ParsedWithdrawFeesInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetCosignThreshold;
+    } & ParsedSetCosignThresholdInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetHoldExpiry;
+    } & ParsedSetHoldExpiryInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.InitMarginAccount;
+    } & ParsedInitMarginAccountInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.CloseMarginAccount;
+    } & ParsedCloseMarginAccountInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.DepositMarginAccount;
+    } & ParsedDepositMarginAccountInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.WithdrawMarginAccount;
     } & ParsedWithdrawMarginAccountInstruction<TProgram>)
   | ({
       instructionType: TensorEscrowInstruction.WithdrawMarginAccountCpiTamm;
     } & ParsedWithdrawMarginAccountCpiTammInstruction<TProgram>)
   | ({
       instructionType: TensorEscrowInstruction.WithdrawMarginAccountCpiTcomp;
-    } & ParsedWithdrawMarginAccountCpiTcompInstruction<TProgram>);
+    } & ParsedWithdrawMarginAccountCpiTcompInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.DepositMarginAccountToken;
+    } & ParsedDepositMarginAccountTokenInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.WithdrawMarginAccountToken;
+    } & ParsedWithdrawMarginAccountTokenInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.WithdrawMarginAccountTokenCpiTamm;
+    } & ParsedWithdrawMarginAccountTokenCpiTammInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.WithdrawMarginAccountTokenCpiTcomp;
+    } & ParsedWithdrawMarginAccountTokenCpiTcompInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.AttachMarginAccountCpiTamm;
+    } & ParsedAttachMarginAccountCpiTammInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.DetachMarginAccountCpiTamm;
+    } & ParsedDetachMarginAccountCpiTammInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.AttachMarginAccountCpiTcomp;
+    } & ParsedAttachMarginAccountCpiTcompInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.DetachMarginAccountCpiTcomp;
+    } & ParsedDetachMarginAccountCpiTcompInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.MigrateMarginAccount;
+    } & ParsedMigrateMarginAccountInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ReconcileAttachments;
+    } & ParsedReconcileAttachmentsInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.InitMarginAccountV2;
+    } & ParsedInitMarginAccountV2Instruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.DepositMarginAccountFor;
+    } & ParsedDepositMarginAccountForInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.DepositMarginAccounts;
+    } & ParsedDepositMarginAccountsInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.InitUpdateCpiConsumer;
+    } & ParsedInitUpdateCpiConsumerInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.CloseCpiConsumer;
+    } & ParsedCloseCpiConsumerInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ApproveConsumer;
+    } & ParsedApproveConsumerInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.RevokeConsumer;
+    } & ParsedRevokeConsumerInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.WithdrawMarginAccountFromConsumer;
+    } & ParsedWithdrawMarginAccountFromConsumerInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ApproveAllowance;
+    } & ParsedApproveAllowanceInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.RevokeAllowance;
+    } & ParsedRevokeAllowanceInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SpendAllowance;
+    } & ParsedSpendAllowanceInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetWithdrawalDelay;
+    } & ParsedSetWithdrawalDelayInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetMarginCosignThreshold;
+    } & ParsedSetMarginCosignThresholdInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.SetCpiDenied;
+    } & ParsedSetCpiDeniedInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.RequestWithdrawal;
+    } & ParsedRequestWithdrawalInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.CancelWithdrawal;
+    } & ParsedCancelWithdrawalInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ExecuteWithdrawal;
+    } & ParsedExecuteWithdrawalInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.TransferBetweenMarginAccounts;
+    } & ParsedTransferBetweenMarginAccountsInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ConsolidateMarginAccounts;
+    } & ParsedConsolidateMarginAccountsInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ReserveMarginLamports;
+    } & ParsedReserveMarginLamportsInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ReserveMarginLamportsFromConsumer;
+    } & ParsedReserveMarginLamportsFromConsumerInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ReleaseReservation;
+    } & ParsedReleaseReservationInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.PlaceHoldCpiTamm;
+    } & ParsedPlaceHoldCpiTammInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.PlaceHoldCpiTcomp;
+    } & ParsedPlaceHoldCpiTcompInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.CaptureHoldCpiTamm;
+    } & ParsedCaptureHoldCpiTammInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.CaptureHoldCpiTcomp;
+    } & ParsedCaptureHoldCpiTcompInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ReleaseHold;
+    } & ParsedReleaseHoldInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.ProposeMarginOwner;
+    } & ParsedProposeMarginOwnerInstruction<TProgram>)
+  | ({
+      instructionType: TensorEscrowInstruction.AcceptMarginOwner;
+    } & ParsedAcceptMarginOwnerInstruction<TProgram>);
@@ -6,4 +6,8 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './caller';
+export * from './cpiReturnData';
+export * from './rateLimit';
 export * from './tSwapConfig';
+export * from './withdrawMode';
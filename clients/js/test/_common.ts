@@ -28,7 +28,12 @@ import {
   signAndSendTransaction,
   TSWAP_SINGLETON,
 } from '@tensor-foundation/test-helpers';
-import { getInitUpdateTswapInstruction } from '../src';
+import {
+  findMarginAccountV2Pda,
+  getDepositMarginAccountInstructionAsync,
+  getInitMarginAccountV2InstructionAsync,
+  getInitTswapInstruction,
+} from '../src';
 import { v4 } from 'uuid';
 import {
   getCreateWhitelistV2Instruction,
@@ -114,11 +119,18 @@ export const initTswap = async (client: Client) => {
 
   const tswap = TSWAP_SINGLETON;
 
-  const initTswapIx = getInitUpdateTswapInstruction({
+  // TSwap can only be initialized once per validator.
+  const { value: existing } = await client.rpc
+    .getAccountInfo(tswap, { encoding: 'base64' })
+    .send();
+  if (existing) {
+    return;
+  }
+
+  const initTswapIx = getInitTswapInstruction({
     tswap,
     owner: tswapOwner,
-    newOwner: tswapOwner,
-    feeVault: DEFAULT_PUBKEY, // Dummy fee vault
+    feeVault: tswapOwner.address, // Dummy fee vault, has to be system-owned
     cosigner: tswapOwner,
     config: { feeBps: 0 },
   });
@@ -128,6 +140,51 @@ export const initTswap = async (client: Client) => {
     (tx) => signAndSendTransaction(client, tx)
   );
 };
+
+// Creates a V2 margin account for `owner`, funded with `lamports` on top of its rent.
+export const initMarginAccountV2 = async ({
+  client,
+  owner,
+  marginNr = 0,
+  lamports = 0n,
+}: {
+  client: Client;
+  owner: KeyPairSigner;
+  marginNr?: number;
+  lamports?: bigint;
+}) => {
+  const [marginAccount] = await findMarginAccountV2Pda({
+    owner: owner.address,
+    marginNr,
+  });
+
+  const initIx = await getInitMarginAccountV2InstructionAsync({
+    marginAccount,
+    owner,
+    marginNr,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(initIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  if (lamports > 0n) {
+    const depositIx = await getDepositMarginAccountInstructionAsync({
+      owner,
+      marginAccount,
+      lamports,
+    });
+    await pipe(
+      await createDefaultTransaction(client, owner),
+      (tx) => appendTransactionMessageInstruction(depositIx, tx),
+      (tx) => signAndSendTransaction(client, tx)
+    );
+  }
+
+  return marginAccount;
+};
+
 export const generateUuid = () => uuidToUint8Array(v4());
 export const uuidToUint8Array = (uuid: string) => {
   const encoder = new TextEncoder();
@@ -1446,3 +1446,229 @@ test("a custom program can't imitate being the escrow program to drain the margi
     ANCHOR_ERROR__INVALID_PROGRAM_ID
   );
 });
+
+test('a pool attached to one margin account cannot withdraw from another margin account of the same owner', async (t) => {
+  const client = createDefaultSolanaClient();
+  const marginAccountOwner = await generateKeyPairSignerWithSol(client);
+  const nftUpdateAuthority = await generateKeyPairSignerWithSol(client);
+  const seller = await generateKeyPairSignerWithSol(client);
+  await initTswap(client);
+
+  const { whitelist } = await createWhitelistV2({
+    client,
+    updateAuthority: nftUpdateAuthority,
+  });
+
+  // Two margin accounts of the same owner, only the second one is funded
+  const [attachedMarginPda] = await findMarginAccountPda({
+    owner: marginAccountOwner.address,
+    marginNr: 0,
+    tswap: TSWAP_SINGLETON,
+  });
+  const [otherMarginPda] = await findMarginAccountPda({
+    owner: marginAccountOwner.address,
+    marginNr: 1,
+    tswap: TSWAP_SINGLETON,
+  });
+
+  for (const [marginAccount, marginNr] of [
+    [attachedMarginPda, 0],
+    [otherMarginPda, 1],
+  ] as const) {
+    const createMarginAccountIx = await getInitMarginAccountInstructionAsync({
+      marginAccount,
+      owner: marginAccountOwner,
+      marginNr,
+    });
+    await pipe(
+      await createDefaultTransaction(client, marginAccountOwner),
+      (tx) => appendTransactionMessageInstruction(createMarginAccountIx, tx),
+      (tx) => signAndSendTransaction(client, tx)
+    );
+  }
+
+  const depositIx = await getDepositMarginAccountInstructionAsync({
+    owner: marginAccountOwner,
+    marginAccount: otherMarginPda,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  await pipe(
+    await createDefaultTransaction(client, marginAccountOwner),
+    (tx) => appendTransactionMessageInstruction(depositIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const poolId = generateUuid();
+  const [poolPda] = await findPoolPda({
+    poolId,
+    owner: marginAccountOwner.address,
+  });
+
+  // The pool is attached to the first margin account
+  const createPoolIx = await getCreatePoolInstructionAsync({
+    owner: marginAccountOwner,
+    whitelist,
+    pool: poolPda,
+    poolId,
+    config: {
+      poolType: PoolType.Trade,
+      startingPrice: LAMPORTS_PER_SOL / 2n,
+      delta: 0,
+      mmCompoundFees: false,
+      mmFeeBps: null,
+      curveType: CurveType.Linear,
+    },
+    sharedEscrow: attachedMarginPda,
+  });
+  await pipe(
+    await createDefaultTransaction(client, marginAccountOwner),
+    (tx) => appendTransactionMessageInstruction(createPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const { legacy: mint } = await mintLegacyCoreAndT22({
+    client,
+    owner: seller,
+    mintAuthority: nftUpdateAuthority,
+  });
+
+  const balanceBefore = (await client.rpc.getBalance(otherMarginPda).send())
+    .value;
+
+  // Sell into the pool, pointing it at the other margin account
+  const sellIx = await getSellNftTradePoolInstructionAsync({
+    owner: marginAccountOwner.address,
+    pool: poolPda,
+    mint,
+    whitelist,
+    minPrice: 0n,
+    taker: seller,
+    // (!)
+    sharedEscrow: otherMarginPda,
+    escrowProgram: TENSOR_ESCROW_PROGRAM_ADDRESS,
+    creators: [nftUpdateAuthority.address],
+  });
+
+  const tx = pipe(
+    await createDefaultTransaction(client, seller),
+    (tx) => appendTransactionMessageInstruction(sellIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // TAMM rejects the mismatched shared escrow before calling in, the escrow's own
+  // MarginMismatch check is covered in clients/rust/tests/cpi_callers.rs.
+  await t.throwsAsync(tx);
+
+  const balanceAfter = (await client.rpc.getBalance(otherMarginPda).send())
+    .value;
+  t.is(balanceAfter, balanceBefore);
+});
+
+test('a bid attached to one margin account cannot withdraw from another margin account of the same owner', async (t) => {
+  const client = createDefaultSolanaClient();
+  const marginAccountOwner = await generateKeyPairSignerWithSol(client);
+  const nftUpdateAuthority = await generateKeyPairSignerWithSol(client);
+  const seller = await generateKeyPairSignerWithSol(client);
+  await initTswap(client);
+
+  const { whitelist } = await createWhitelistV2({
+    client,
+    updateAuthority: nftUpdateAuthority,
+  });
+
+  // Two margin accounts of the same owner, only the second one is funded
+  const [attachedMarginPda] = await findMarginAccountPda({
+    owner: marginAccountOwner.address,
+    marginNr: 0,
+    tswap: TSWAP_SINGLETON,
+  });
+  const [otherMarginPda] = await findMarginAccountPda({
+    owner: marginAccountOwner.address,
+    marginNr: 1,
+    tswap: TSWAP_SINGLETON,
+  });
+
+  for (const [marginAccount, marginNr] of [
+    [attachedMarginPda, 0],
+    [otherMarginPda, 1],
+  ] as const) {
+    const createMarginAccountIx = await getInitMarginAccountInstructionAsync({
+      marginAccount,
+      owner: marginAccountOwner,
+      marginNr,
+    });
+    await pipe(
+      await createDefaultTransaction(client, marginAccountOwner),
+      (tx) => appendTransactionMessageInstruction(createMarginAccountIx, tx),
+      (tx) => signAndSendTransaction(client, tx)
+    );
+  }
+
+  const depositIx = await getDepositMarginAccountInstructionAsync({
+    owner: marginAccountOwner,
+    marginAccount: otherMarginPda,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  await pipe(
+    await createDefaultTransaction(client, marginAccountOwner),
+    (tx) => appendTransactionMessageInstruction(depositIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // The bid is attached to the first margin account
+  const bidId = getAddressDecoder().decode(generateUuid());
+  const [bidStatePda] = await findBidStatePda({
+    bidId,
+    owner: marginAccountOwner.address,
+  });
+  const createBidIx = await getBidInstructionAsync({
+    owner: marginAccountOwner,
+    target: Target.Whitelist,
+    targetId: whitelist,
+    bidId,
+    bidState: bidStatePda,
+    sharedEscrow: attachedMarginPda,
+    amount: LAMPORTS_PER_SOL / 2n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, marginAccountOwner),
+    (tx) => appendTransactionMessageInstruction(createBidIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const { legacy: mint } = await mintLegacyCoreAndT22({
+    client,
+    owner: seller,
+    mintAuthority: nftUpdateAuthority,
+  });
+
+  const balanceBefore = (await client.rpc.getBalance(otherMarginPda).send())
+    .value;
+
+  // Sell into the bid, pointing it at the other margin account
+  const takeBidIx = await getTakeBidLegacyInstructionAsync({
+    bidState: bidStatePda,
+    owner: marginAccountOwner.address,
+    seller,
+    mint,
+    whitelist,
+    minAmount: 0n,
+    // (!)
+    sharedEscrow: otherMarginPda,
+    creators: [nftUpdateAuthority.address],
+  });
+
+  const tx = pipe(
+    await createDefaultTransaction(client, seller),
+    (tx) => appendTransactionMessageInstruction(takeBidIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // TCOMP rejects the mismatched shared escrow before calling in, the escrow's own
+  // MarginMismatch check is covered in clients/rust/tests/cpi_callers.rs.
+  await t.throwsAsync(tx);
+
+  const balanceAfter = (await client.rpc.getBalance(otherMarginPda).send())
+    .value;
+  t.is(balanceAfter, balanceBefore);
+});
@@ -0,0 +1,148 @@
+import {
+  appendTransactionMessageInstruction,
+  pipe,
+  some,
+} from '@solana/web3.js';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  generateKeyPairSignerWithSol,
+  LAMPORTS_PER_SOL,
+  signAndSendTransaction,
+} from '@tensor-foundation/test-helpers';
+import test from 'ava';
+import {
+  fetchMarginAccountV2,
+  getSetMarginCosignThresholdInstructionAsync,
+  getWithdrawMarginAccountInstructionAsync,
+  TENSOR_ESCROW_ERROR__COSIGN_REQUIRED,
+} from '../src';
+import {
+  expectCustomError,
+  getOwner,
+  initMarginAccountV2,
+  initTswap,
+} from './_common';
+
+const THRESHOLD = LAMPORTS_PER_SOL / 4n;
+
+test('it requires the cosigner for withdrawals above the margin threshold', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  await initTswap(client);
+  // initTswap sets the TSwap owner as cosigner
+  const cosigner = await getOwner();
+
+  const marginAccount = await initMarginAccountV2({
+    client,
+    owner,
+    lamports: LAMPORTS_PER_SOL,
+  });
+
+  const setThresholdIx = await getSetMarginCosignThresholdInstructionAsync({
+    marginAccount,
+    owner,
+    threshold: THRESHOLD,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(setThresholdIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const margin = await fetchMarginAccountV2(client.rpc, marginAccount);
+  t.deepEqual(margin.data.cosignThreshold, some(THRESHOLD));
+
+  // Up to the threshold the owner withdraws alone
+  const smallWithdrawIx = await getWithdrawMarginAccountInstructionAsync({
+    owner,
+    marginAccount,
+    lamports: THRESHOLD,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(smallWithdrawIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Above it without the cosigner fails
+  const largeWithdrawIx = await getWithdrawMarginAccountInstructionAsync({
+    owner,
+    marginAccount,
+    lamports: THRESHOLD + 1n,
+  });
+  const largeWithdrawTx = pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(largeWithdrawIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+  await expectCustomError(
+    t,
+    largeWithdrawTx,
+    TENSOR_ESCROW_ERROR__COSIGN_REQUIRED
+  );
+
+  // And goes through once co-signed
+  const cosignedWithdrawIx = await getWithdrawMarginAccountInstructionAsync({
+    owner,
+    marginAccount,
+    cosigner,
+    lamports: THRESHOLD + 1n,
+  });
+  const balanceBefore = (await client.rpc.getBalance(marginAccount).send())
+    .value;
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(cosignedWithdrawIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+  const balanceAfter = (await client.rpc.getBalance(marginAccount).send())
+    .value;
+  t.is(balanceAfter, balanceBefore - THRESHOLD - 1n);
+});
+
+test('it requires the cosigner to raise the margin threshold', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  await initTswap(client);
+
+  const marginAccount = await initMarginAccountV2({
+    client,
+    owner,
+    lamports: LAMPORTS_PER_SOL,
+  });
+
+  const setThresholdIx = await getSetMarginCosignThresholdInstructionAsync({
+    marginAccount,
+    owner,
+    threshold: THRESHOLD,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(setThresholdIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // A stolen key can't lift the threshold on its own
+  const removeThresholdIx = await getSetMarginCosignThresholdInstructionAsync({
+    marginAccount,
+    owner,
+    threshold: null,
+  });
+  const tx = pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(removeThresholdIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, tx, TENSOR_ESCROW_ERROR__COSIGN_REQUIRED);
+
+  const margin = await fetchMarginAccountV2(client.rpc, marginAccount);
+  t.deepEqual(margin.data.cosignThreshold, some(THRESHOLD));
+});
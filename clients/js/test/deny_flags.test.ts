@@ -0,0 +1,170 @@
+import { appendTransactionMessageInstruction, pipe } from '@solana/web3.js';
+import {
+  CurveType,
+  findPoolPda,
+  getCreatePoolInstructionAsync,
+  getSellNftTradePoolInstructionAsync,
+  PoolType,
+} from '@tensor-foundation/amm';
+import { createDefaultNft } from '@tensor-foundation/mpl-token-metadata';
+import {
+  Client,
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  generateKeyPairSignerWithSol,
+  LAMPORTS_PER_SOL,
+  signAndSendTransaction,
+  TSWAP_SINGLETON,
+} from '@tensor-foundation/test-helpers';
+import test from 'ava';
+import {
+  fetchMarginAccountV2,
+  findMarginAccountPda,
+  getDepositMarginAccountInstructionAsync,
+  getInitMarginAccountInstructionAsync,
+  getMigrateMarginAccountInstructionAsync,
+  getSetCpiDeniedInstruction,
+  TENSOR_ESCROW_ERROR__CPI_DENIED,
+  TENSOR_ESCROW_PROGRAM_ADDRESS,
+} from '../src';
+import {
+  createWhitelistV2,
+  expectCustomError,
+  generateUuid,
+  initTswap,
+} from './_common';
+
+// Mirrors the program's `DENY_*` flags
+const DENY_TAMM_CPI = 1;
+const DENY_TCOMP_CPI = 2;
+
+// Creates a funded margin account denying `cpiDenied`, with a trade pool on it.
+const setupPool = async (client: Client, cpiDenied: number) => {
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  const seller = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  await initTswap(client);
+
+  const { whitelist } = await createWhitelistV2({
+    client,
+    updateAuthority: seller,
+  });
+
+  // Deny flags live in the V2 layout, so migrate the margin account in place
+  const [marginAccountPda] = await findMarginAccountPda({
+    owner: owner.address,
+    marginNr: 0,
+    tswap: TSWAP_SINGLETON,
+  });
+  const initIx = await getInitMarginAccountInstructionAsync({
+    marginAccount: marginAccountPda,
+    owner,
+  });
+  const migrateIx = await getMigrateMarginAccountInstructionAsync({
+    marginAccount: marginAccountPda,
+    owner: owner.address,
+    payer: owner,
+  });
+  const depositIx = await getDepositMarginAccountInstructionAsync({
+    owner,
+    marginAccount: marginAccountPda,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  const setCpiDeniedIx = getSetCpiDeniedInstruction({
+    marginAccount: marginAccountPda,
+    owner,
+    cpiDenied,
+  });
+  for (const ix of [initIx, migrateIx, depositIx, setCpiDeniedIx]) {
+    await pipe(
+      await createDefaultTransaction(client, owner),
+      (tx) => appendTransactionMessageInstruction(ix, tx),
+      (tx) => signAndSendTransaction(client, tx)
+    );
+  }
+
+  const poolId = generateUuid();
+  const [poolPda] = await findPoolPda({ poolId, owner: owner.address });
+  const createPoolIx = await getCreatePoolInstructionAsync({
+    owner,
+    whitelist,
+    pool: poolPda,
+    poolId,
+    config: {
+      poolType: PoolType.Trade,
+      startingPrice: LAMPORTS_PER_SOL / 2n,
+      delta: 0,
+      mmCompoundFees: false,
+      mmFeeBps: null,
+      curveType: CurveType.Linear,
+    },
+    sharedEscrow: marginAccountPda,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(createPoolIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Seller mints an NFT to sell into the pool
+  const { mint } = await createDefaultNft({
+    client,
+    payer: seller,
+    authority: seller,
+    owner: seller.address,
+  });
+  const sellNftIx = await getSellNftTradePoolInstructionAsync({
+    owner: owner.address,
+    pool: poolPda,
+    mint,
+    minPrice: 1,
+    whitelist,
+    taker: seller,
+    sharedEscrow: marginAccountPda,
+    escrowProgram: TENSOR_ESCROW_PROGRAM_ADDRESS,
+    creators: [seller.address],
+  });
+  const sellTx = async () =>
+    pipe(
+      await createDefaultTransaction(client, seller),
+      (tx) => appendTransactionMessageInstruction(sellNftIx, tx),
+      (tx) => signAndSendTransaction(client, tx)
+    );
+
+  return { marginAccountPda, sellTx };
+};
+
+test('a margin account restricted to TAMM still pays for pool sales', async (t) => {
+  const client = createDefaultSolanaClient();
+  const { marginAccountPda, sellTx } = await setupPool(client, DENY_TCOMP_CPI);
+
+  const margin = await fetchMarginAccountV2(client.rpc, marginAccountPda);
+  t.is(margin.data.cpiDenied, DENY_TCOMP_CPI);
+
+  const balanceBefore = (await client.rpc.getBalance(marginAccountPda).send())
+    .value;
+  await sellTx();
+  const balanceAfter = (await client.rpc.getBalance(marginAccountPda).send())
+    .value;
+
+  t.is(balanceAfter, balanceBefore - LAMPORTS_PER_SOL / 2n);
+});
+
+test('a margin account denying TAMM cannot pay for pool sales', async (t) => {
+  const client = createDefaultSolanaClient();
+  const { marginAccountPda, sellTx } = await setupPool(client, DENY_TAMM_CPI);
+
+  const balanceBefore = (await client.rpc.getBalance(marginAccountPda).send())
+    .value;
+  await expectCustomError(t, sellTx(), TENSOR_ESCROW_ERROR__CPI_DENIED);
+
+  t.is(
+    (await client.rpc.getBalance(marginAccountPda).send()).value,
+    balanceBefore
+  );
+});
@@ -0,0 +1,76 @@
+import { appendTransactionMessageInstruction, pipe } from '@solana/web3.js';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  generateKeyPairSignerWithSol,
+  LAMPORTS_PER_SOL,
+  signAndSendTransaction,
+} from '@tensor-foundation/test-helpers';
+import test from 'ava';
+import { getDepositMarginAccountForInstructionAsync } from '../src';
+import { expectCustomError, initMarginAccountV2, initTswap } from './_common';
+
+test('anyone can deposit into a margin account for its owner', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(client);
+  const sponsor = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  await initTswap(client);
+
+  const marginAccount = await initMarginAccountV2({ client, owner });
+  const balanceBefore = (await client.rpc.getBalance(marginAccount).send())
+    .value;
+
+  // The owner doesn't sign
+  const depositIx = await getDepositMarginAccountForInstructionAsync({
+    marginAccount,
+    owner: owner.address,
+    payer: sponsor,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  await pipe(
+    await createDefaultTransaction(client, sponsor),
+    (tx) => appendTransactionMessageInstruction(depositIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const balanceAfter = (await client.rpc.getBalance(marginAccount).send())
+    .value;
+  t.is(balanceAfter, balanceBefore + LAMPORTS_PER_SOL);
+});
+
+test('it cannot deposit for the wrong owner', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(client);
+  const sponsor = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  await initTswap(client);
+
+  const marginAccount = await initMarginAccountV2({ client, owner });
+  const balanceBefore = (await client.rpc.getBalance(marginAccount).send())
+    .value;
+
+  const depositIx = await getDepositMarginAccountForInstructionAsync({
+    marginAccount,
+    // (!)
+    owner: sponsor.address,
+    payer: sponsor,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  const tx = pipe(
+    await createDefaultTransaction(client, sponsor),
+    (tx) => appendTransactionMessageInstruction(depositIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const ANCHOR_CONSTRAINT_HAS_ONE_ERROR_CODE = 2001;
+  await expectCustomError(t, tx, ANCHOR_CONSTRAINT_HAS_ONE_ERROR_CODE);
+  t.is(
+    (await client.rpc.getBalance(marginAccount).send()).value,
+    balanceBefore
+  );
+});
@@ -5,6 +5,7 @@ import {
   getWithdrawMarginAccountInstructionAsync,
   getCloseMarginAccountInstructionAsync,
   fetchMaybeMarginAccount,
+  TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE,
 } from '../src';
 import {
   TSWAP_SINGLETON,
@@ -78,8 +79,11 @@ test('it can initialize, deposit, withdraw and close a margin account', async (t
     (tx) => signAndSendTransaction(client, tx)
   );
 
-  const INT_OVERFLOW_ERROR = 1103;
-  await expectCustomError(t, withdrawMoreThanAvailableTx, INT_OVERFLOW_ERROR);
+  await expectCustomError(
+    t,
+    withdrawMoreThanAvailableTx,
+    TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE
+  );
 
   // Close the margin account
   const closeMarginAccountIx = await getCloseMarginAccountInstructionAsync({
@@ -0,0 +1,183 @@
+import {
+  Address,
+  airdropFactory,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  getUtf8Encoder,
+  lamports,
+  pipe,
+} from '@solana/web3.js';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  generateKeyPairSignerWithSol,
+  LAMPORTS_PER_SOL,
+  ONE_SOL,
+  signAndSendTransaction,
+  TSWAP_SINGLETON,
+} from '@tensor-foundation/test-helpers';
+import test from 'ava';
+import {
+  fetchFeeExemption,
+  fetchMaybeFeeExemption,
+  fetchTSwap,
+  getAddFeeExemptionInstructionAsync,
+  getRemoveFeeExemptionInstructionAsync,
+  getSetFeeVaultInstructionAsync,
+  getWithdrawFeesInstructionAsync,
+  TENSOR_ESCROW_ERROR__BAD_OWNER,
+  TENSOR_ESCROW_PROGRAM_ADDRESS,
+} from '../src';
+import { expectCustomError, getAndFundOwner, initTswap } from './_common';
+
+const findFeeVaultPda = async () =>
+  await getProgramDerivedAddress({
+    programAddress: TENSOR_ESCROW_PROGRAM_ADDRESS,
+    seeds: [getUtf8Encoder().encode('fee_vault')],
+  });
+
+const findFeeExemptionPda = async (owner: Address) =>
+  await getProgramDerivedAddress({
+    programAddress: TENSOR_ESCROW_PROGRAM_ADDRESS,
+    seeds: [
+      getUtf8Encoder().encode('fee_exemption'),
+      getAddressEncoder().encode(owner),
+    ],
+  });
+
+test('it can exempt an owner from fees and lift the exemption', async (t) => {
+  const client = createDefaultSolanaClient();
+  await initTswap(client);
+  const tswapOwner = await getAndFundOwner(client);
+  const exemptOwner = await generateKeyPairSigner();
+
+  const [feeExemption] = await findFeeExemptionPda(exemptOwner.address);
+
+  const addIx = await getAddFeeExemptionInstructionAsync({
+    feeExemption,
+    exemptOwner: exemptOwner.address,
+    owner: tswapOwner,
+  });
+  await pipe(
+    await createDefaultTransaction(client, tswapOwner),
+    (tx) => appendTransactionMessageInstruction(addIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const exemption = await fetchFeeExemption(client.rpc, feeExemption);
+  t.is(exemption.data.owner, exemptOwner.address);
+
+  const removeIx = await getRemoveFeeExemptionInstructionAsync({
+    feeExemption,
+    owner: tswapOwner,
+  });
+  await pipe(
+    await createDefaultTransaction(client, tswapOwner),
+    (tx) => appendTransactionMessageInstruction(removeIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const removed = await fetchMaybeFeeExemption(client.rpc, feeExemption);
+  t.false(removed.exists);
+});
+
+test('only the program owner can exempt owners from fees', async (t) => {
+  const client = createDefaultSolanaClient();
+  await initTswap(client);
+  const notOwner = await generateKeyPairSignerWithSol(client);
+
+  const [feeExemption] = await findFeeExemptionPda(notOwner.address);
+
+  const addIx = await getAddFeeExemptionInstructionAsync({
+    feeExemption,
+    exemptOwner: notOwner.address,
+    owner: notOwner,
+  });
+  const tx = pipe(
+    await createDefaultTransaction(client, notOwner),
+    (tx) => appendTransactionMessageInstruction(addIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, tx, TENSOR_ESCROW_ERROR__BAD_OWNER);
+  t.false((await fetchMaybeFeeExemption(client.rpc, feeExemption)).exists);
+});
+
+test('it can withdraw fees from the fee vault PDA', async (t) => {
+  const client = createDefaultSolanaClient();
+  await initTswap(client);
+  const tswapOwner = await getAndFundOwner(client);
+  const destination = await generateKeyPairSigner();
+
+  const [feeVault] = await findFeeVaultPda();
+  await airdropFactory(client)({
+    recipientAddress: feeVault,
+    lamports: lamports(ONE_SOL),
+    commitment: 'confirmed',
+  });
+
+  // Point TSwap at the fee vault PDA, fees stay disabled so other tests
+  // don't notice
+  const tswap = await fetchTSwap(client.rpc, TSWAP_SINGLETON);
+  const setFeeVaultIx = await getSetFeeVaultInstructionAsync({
+    owner: tswapOwner,
+    feeVault,
+  });
+  await pipe(
+    await createDefaultTransaction(client, tswapOwner),
+    (tx) => appendTransactionMessageInstruction(setFeeVaultIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const withdrawFeesIx = await getWithdrawFeesInstructionAsync({
+    feeVault,
+    owner: tswapOwner,
+    destination: destination.address,
+    lamports: LAMPORTS_PER_SOL / 2n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, tswapOwner),
+    (tx) => appendTransactionMessageInstruction(withdrawFeesIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.is(
+    (await client.rpc.getBalance(destination.address).send()).value,
+    LAMPORTS_PER_SOL / 2n
+  );
+
+  // Restore the previous fee vault
+  const restoreIx = await getSetFeeVaultInstructionAsync({
+    owner: tswapOwner,
+    feeVault: tswap.data.feeVault,
+  });
+  await pipe(
+    await createDefaultTransaction(client, tswapOwner),
+    (tx) => appendTransactionMessageInstruction(restoreIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+});
+
+test('only the program owner can withdraw fees', async (t) => {
+  const client = createDefaultSolanaClient();
+  await initTswap(client);
+  const notOwner = await generateKeyPairSignerWithSol(client);
+
+  const [feeVault] = await findFeeVaultPda();
+
+  const withdrawFeesIx = await getWithdrawFeesInstructionAsync({
+    feeVault,
+    owner: notOwner,
+    destination: notOwner.address,
+    lamports: 1n,
+  });
+  const tx = pipe(
+    await createDefaultTransaction(client, notOwner),
+    (tx) => appendTransactionMessageInstruction(withdrawFeesIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, tx, TENSOR_ESCROW_ERROR__BAD_OWNER);
+});
@@ -0,0 +1,146 @@
+import { appendTransactionMessageInstruction, pipe } from '@solana/web3.js';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  generateKeyPairSignerWithSol,
+  LAMPORTS_PER_SOL,
+  signAndSendTransaction,
+} from '@tensor-foundation/test-helpers';
+import test from 'ava';
+import {
+  fetchMarginAccountV2,
+  getAcceptMarginOwnerInstruction,
+  getProposeMarginOwnerInstruction,
+  getWithdrawMarginAccountInstructionAsync,
+  TENSOR_ESCROW_ERROR__BAD_OWNER,
+} from '../src';
+import {
+  DEFAULT_PUBKEY,
+  expectCustomError,
+  initMarginAccountV2,
+  initTswap,
+} from './_common';
+
+test('it can hand off a margin account to a new owner', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  const newOwner = await generateKeyPairSignerWithSol(client);
+  await initTswap(client);
+
+  const marginAccount = await initMarginAccountV2({
+    client,
+    owner,
+    lamports: LAMPORTS_PER_SOL,
+  });
+
+  // Propose the new owner
+  const proposeIx = getProposeMarginOwnerInstruction({
+    marginAccount,
+    owner,
+    newOwner: newOwner.address,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(proposeIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  let margin = await fetchMarginAccountV2(client.rpc, marginAccount);
+  t.is(margin.data.owner, owner.address);
+  t.is(margin.data.pendingOwner, newOwner.address);
+
+  // The new owner accepts
+  const acceptIx = getAcceptMarginOwnerInstruction({
+    marginAccount,
+    newOwner,
+  });
+  await pipe(
+    await createDefaultTransaction(client, newOwner),
+    (tx) => appendTransactionMessageInstruction(acceptIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  margin = await fetchMarginAccountV2(client.rpc, marginAccount);
+  t.is(margin.data.owner, newOwner.address);
+  t.is(margin.data.originalOwner, owner.address);
+  t.is(margin.data.pendingOwner, DEFAULT_PUBKEY);
+
+  // The new owner can withdraw from the same address
+  const withdrawIx = await getWithdrawMarginAccountInstructionAsync({
+    owner: newOwner,
+    marginAccount,
+    lamports: LAMPORTS_PER_SOL / 2n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, newOwner),
+    (tx) => appendTransactionMessageInstruction(withdrawIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // The previous owner can't anymore
+  const oldOwnerWithdrawIx = await getWithdrawMarginAccountInstructionAsync({
+    owner,
+    marginAccount,
+    lamports: LAMPORTS_PER_SOL / 4n,
+  });
+  const oldOwnerWithdrawTx = pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(oldOwnerWithdrawIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const ANCHOR_CONSTRAINT_HAS_ONE_ERROR_CODE = 2001;
+  await expectCustomError(
+    t,
+    oldOwnerWithdrawTx,
+    ANCHOR_CONSTRAINT_HAS_ONE_ERROR_CODE
+  );
+});
+
+test('it cannot take over a margin account without being proposed', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  const newOwner = await generateKeyPairSignerWithSol(client);
+  const attacker = await generateKeyPairSignerWithSol(client);
+  await initTswap(client);
+
+  const marginAccount = await initMarginAccountV2({
+    client,
+    owner,
+    lamports: LAMPORTS_PER_SOL,
+  });
+
+  const proposeIx = getProposeMarginOwnerInstruction({
+    marginAccount,
+    owner,
+    newOwner: newOwner.address,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(proposeIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Someone else tries to accept the handoff
+  const acceptIx = getAcceptMarginOwnerInstruction({
+    marginAccount,
+    newOwner: attacker,
+  });
+  const tx = pipe(
+    await createDefaultTransaction(client, attacker),
+    (tx) => appendTransactionMessageInstruction(acceptIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(t, tx, TENSOR_ESCROW_ERROR__BAD_OWNER);
+
+  const margin = await fetchMarginAccountV2(client.rpc, marginAccount);
+  t.is(margin.data.owner, owner.address);
+  t.is(margin.data.pendingOwner, newOwner.address);
+});
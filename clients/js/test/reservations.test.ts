@@ -0,0 +1,218 @@
+import {
+  Address,
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  getUtf8Encoder,
+  pipe,
+} from '@solana/web3.js';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  generateKeyPairSignerWithSol,
+  LAMPORTS_PER_SOL,
+  signAndSendTransaction,
+} from '@tensor-foundation/test-helpers';
+import test from 'ava';
+import {
+  fetchMarginAccountV2,
+  fetchMaybeReservation,
+  fetchReservation,
+  getReleaseReservationInstruction,
+  getReserveMarginLamportsInstruction,
+  getWithdrawMarginAccountInstructionAsync,
+  TENSOR_ESCROW_ERROR__BAD_RESERVATION_AUTHORITY,
+  TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE,
+  TENSOR_ESCROW_PROGRAM_ADDRESS,
+} from '../src';
+import { expectCustomError, initMarginAccountV2, initTswap } from './_common';
+
+// u64::MAX, releases the whole reservation
+const RELEASE_ALL = 2n ** 64n - 1n;
+
+const findReservationPda = async (
+  marginAccount: Address,
+  callerAccount: Address
+) =>
+  await getProgramDerivedAddress({
+    programAddress: TENSOR_ESCROW_PROGRAM_ADDRESS,
+    seeds: [
+      getUtf8Encoder().encode('reservation'),
+      getAddressEncoder().encode(marginAccount),
+      getAddressEncoder().encode(callerAccount),
+    ],
+  });
+
+test('it can reserve lamports for a caller and release them', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  // Stands in for the pool or bid the lamports are earmarked for
+  const callerAccount = await generateKeyPairSigner();
+  await initTswap(client);
+
+  const marginAccount = await initMarginAccountV2({
+    client,
+    owner,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  const [reservation] = await findReservationPda(
+    marginAccount,
+    callerAccount.address
+  );
+
+  const reserveIx = getReserveMarginLamportsInstruction({
+    marginAccount,
+    reservation,
+    owner,
+    callerAccount: callerAccount.address,
+    lamports: (3n * LAMPORTS_PER_SOL) / 4n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(reserveIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.is(
+    (await fetchReservation(client.rpc, reservation)).data.amount,
+    (3n * LAMPORTS_PER_SOL) / 4n
+  );
+  t.is(
+    (await fetchMarginAccountV2(client.rpc, marginAccount)).data
+      .reservedLamports,
+    (3n * LAMPORTS_PER_SOL) / 4n
+  );
+
+  // The caller hands the whole reservation back
+  const releaseIx = getReleaseReservationInstruction({
+    marginAccount,
+    reservation,
+    authority: callerAccount,
+    rentPayer: owner.address,
+    lamports: RELEASE_ALL,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(releaseIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.false((await fetchMaybeReservation(client.rpc, reservation)).exists);
+  t.is(
+    (await fetchMarginAccountV2(client.rpc, marginAccount)).data
+      .reservedLamports,
+    0n
+  );
+});
+
+test('the owner cannot withdraw reserved lamports', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  const callerAccount = await generateKeyPairSigner();
+  await initTswap(client);
+
+  const marginAccount = await initMarginAccountV2({
+    client,
+    owner,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  const [reservation] = await findReservationPda(
+    marginAccount,
+    callerAccount.address
+  );
+
+  const reserveIx = getReserveMarginLamportsInstruction({
+    marginAccount,
+    reservation,
+    owner,
+    callerAccount: callerAccount.address,
+    lamports: (3n * LAMPORTS_PER_SOL) / 4n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(reserveIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Only a quarter is left unreserved
+  const withdrawIx = await getWithdrawMarginAccountInstructionAsync({
+    owner,
+    marginAccount,
+    lamports: LAMPORTS_PER_SOL / 2n,
+  });
+  const tx = pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(withdrawIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    tx,
+    TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE
+  );
+});
+
+test('only the owner or the caller can release a reservation', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  const callerAccount = await generateKeyPairSigner();
+  const attacker = await generateKeyPairSignerWithSol(client);
+  await initTswap(client);
+
+  const marginAccount = await initMarginAccountV2({
+    client,
+    owner,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  const [reservation] = await findReservationPda(
+    marginAccount,
+    callerAccount.address
+  );
+
+  const reserveIx = getReserveMarginLamportsInstruction({
+    marginAccount,
+    reservation,
+    owner,
+    callerAccount: callerAccount.address,
+    lamports: LAMPORTS_PER_SOL / 2n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(reserveIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const releaseIx = getReleaseReservationInstruction({
+    marginAccount,
+    reservation,
+    authority: attacker,
+    rentPayer: owner.address,
+    lamports: RELEASE_ALL,
+  });
+  const tx = pipe(
+    await createDefaultTransaction(client, attacker),
+    (tx) => appendTransactionMessageInstruction(releaseIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    tx,
+    TENSOR_ESCROW_ERROR__BAD_RESERVATION_AUTHORITY
+  );
+  t.is(
+    (await fetchReservation(client.rpc, reservation)).data.amount,
+    LAMPORTS_PER_SOL / 2n
+  );
+});
@@ -0,0 +1,136 @@
+import { appendTransactionMessageInstruction, pipe } from '@solana/web3.js';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  generateKeyPairSignerWithSol,
+  LAMPORTS_PER_SOL,
+  signAndSendTransaction,
+} from '@tensor-foundation/test-helpers';
+import test from 'ava';
+import {
+  getTransferBetweenMarginAccountsInstruction,
+  TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE,
+} from '../src';
+import { expectCustomError, initMarginAccountV2, initTswap } from './_common';
+
+test('it can transfer lamports between margin accounts of the same owner', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  await initTswap(client);
+
+  const source = await initMarginAccountV2({
+    client,
+    owner,
+    marginNr: 0,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  const destination = await initMarginAccountV2({
+    client,
+    owner,
+    marginNr: 3,
+  });
+
+  const sourceBefore = (await client.rpc.getBalance(source).send()).value;
+  const destinationBefore = (await client.rpc.getBalance(destination).send())
+    .value;
+
+  const transferIx = getTransferBetweenMarginAccountsInstruction({
+    sourceMarginAccount: source,
+    destinationMarginAccount: destination,
+    owner,
+    lamports: LAMPORTS_PER_SOL / 2n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(transferIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const sourceAfter = (await client.rpc.getBalance(source).send()).value;
+  const destinationAfter = (await client.rpc.getBalance(destination).send())
+    .value;
+  t.is(sourceAfter, sourceBefore - LAMPORTS_PER_SOL / 2n);
+  t.is(destinationAfter, destinationBefore + LAMPORTS_PER_SOL / 2n);
+});
+
+test('it cannot transfer the rent of the source margin account', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  await initTswap(client);
+
+  const source = await initMarginAccountV2({
+    client,
+    owner,
+    marginNr: 0,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  const destination = await initMarginAccountV2({
+    client,
+    owner,
+    marginNr: 3,
+  });
+
+  // Moving the whole balance would leave the source below rent exemption
+  const sourceBefore = (await client.rpc.getBalance(source).send()).value;
+  const transferIx = getTransferBetweenMarginAccountsInstruction({
+    sourceMarginAccount: source,
+    destinationMarginAccount: destination,
+    owner,
+    lamports: sourceBefore,
+  });
+  const tx = pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(transferIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    tx,
+    TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE
+  );
+  t.is((await client.rpc.getBalance(source).send()).value, sourceBefore);
+});
+
+test('it cannot transfer into a margin account of another owner', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  const otherOwner = await generateKeyPairSignerWithSol(client);
+  await initTswap(client);
+
+  const source = await initMarginAccountV2({
+    client,
+    owner,
+    marginNr: 0,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  const destination = await initMarginAccountV2({
+    client,
+    owner: otherOwner,
+    marginNr: 0,
+  });
+
+  const transferIx = getTransferBetweenMarginAccountsInstruction({
+    sourceMarginAccount: source,
+    destinationMarginAccount: destination,
+    owner,
+    lamports: LAMPORTS_PER_SOL / 2n,
+  });
+  const tx = pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(transferIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const ANCHOR_CONSTRAINT_HAS_ONE_ERROR_CODE = 2001;
+  await expectCustomError(t, tx, ANCHOR_CONSTRAINT_HAS_ONE_ERROR_CODE);
+});
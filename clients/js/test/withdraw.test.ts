@@ -0,0 +1,133 @@
+import {
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  pipe,
+} from '@solana/web3.js';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  generateKeyPairSignerWithSol,
+  LAMPORTS_PER_SOL,
+  signAndSendTransaction,
+} from '@tensor-foundation/test-helpers';
+import test from 'ava';
+import {
+  getDepositMarginAccountInstructionAsync,
+  getWithdrawMarginAccountInstructionAsync,
+  TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE,
+} from '../src';
+import { expectCustomError, initMarginAccountV2, initTswap } from './_common';
+
+// u64::MAX, withdraws everything above the rent-exempt minimum
+const WITHDRAW_ALL = 2n ** 64n - 1n;
+
+test('it can withdraw to another destination', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  const destination = await generateKeyPairSigner();
+  await initTswap(client);
+
+  const marginAccount = await initMarginAccountV2({
+    client,
+    owner,
+    lamports: LAMPORTS_PER_SOL,
+  });
+
+  const withdrawIx = await getWithdrawMarginAccountInstructionAsync({
+    owner,
+    marginAccount,
+    destination: destination.address,
+    lamports: LAMPORTS_PER_SOL / 2n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(withdrawIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.is(
+    (await client.rpc.getBalance(destination.address).send()).value,
+    LAMPORTS_PER_SOL / 2n
+  );
+});
+
+test('it can withdraw everything above the rent', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  const destination = await generateKeyPairSigner();
+  await initTswap(client);
+
+  // A fresh margin account only holds its rent
+  const marginAccount = await initMarginAccountV2({ client, owner });
+  const rent = (await client.rpc.getBalance(marginAccount).send()).value;
+
+  const depositIx = await getDepositMarginAccountInstructionAsync({
+    owner,
+    marginAccount,
+    lamports: LAMPORTS_PER_SOL,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(depositIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  const withdrawIx = await getWithdrawMarginAccountInstructionAsync({
+    owner,
+    marginAccount,
+    destination: destination.address,
+    lamports: WITHDRAW_ALL,
+  });
+  await pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(withdrawIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  t.is((await client.rpc.getBalance(marginAccount).send()).value, rent);
+  t.is(
+    (await client.rpc.getBalance(destination.address).send()).value,
+    LAMPORTS_PER_SOL
+  );
+});
+
+test('it cannot withdraw the rent of the margin account', async (t) => {
+  const client = createDefaultSolanaClient();
+  const owner = await generateKeyPairSignerWithSol(
+    client,
+    5n * LAMPORTS_PER_SOL
+  );
+  await initTswap(client);
+
+  const marginAccount = await initMarginAccountV2({
+    client,
+    owner,
+    lamports: LAMPORTS_PER_SOL,
+  });
+
+  // The exact balance includes the rent, unlike WITHDRAW_ALL
+  const balance = (await client.rpc.getBalance(marginAccount).send()).value;
+  const withdrawIx = await getWithdrawMarginAccountInstructionAsync({
+    owner,
+    marginAccount,
+    lamports: balance,
+  });
+  const tx = pipe(
+    await createDefaultTransaction(client, owner),
+    (tx) => appendTransactionMessageInstruction(withdrawIx, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  await expectCustomError(
+    t,
+    tx,
+    TENSOR_ESCROW_ERROR__INSUFFICIENT_AVAILABLE_BALANCE
+  );
+  t.is((await client.rpc.getBalance(marginAccount).send()).value, balance);
+});
@@ -0,0 +1,522 @@
+//! Typed events emitted by the program and a decoder for them.
+//!
+//! Events are emitted through a self-CPI whose instruction data is the 8-byte
+//! event instruction tag followed by the event discriminator and the Borsh
+//! payload. When the event accounts are not passed in, the same payload
+//! (without the tag) is logged with `sol_log_data` instead.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::types::Caller;
+
+/// Prefix of the self-CPI instruction data carrying an event.
+pub const EVENT_IX_TAG_LE: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct InitMarginEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub nr: u16,
+    pub version: u8,
+}
+
+impl InitMarginEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [64, 125, 11, 215, 21, 21, 101, 214];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct MigrateMarginEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub payer: Pubkey,
+    pub version: u8,
+}
+
+impl MigrateMarginEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [223, 135, 106, 50, 241, 102, 202, 53];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ReconcileAttachmentsEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+}
+
+impl ReconcileAttachmentsEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [174, 198, 130, 149, 172, 104, 204, 193];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CloseMarginEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub rent_destination: Pubkey,
+}
+
+impl CloseMarginEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [172, 190, 214, 178, 128, 155, 237, 42];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct DepositEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub post_balance: u64,
+}
+
+impl DepositEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [120, 248, 61, 83, 31, 142, 107, 144];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub caller: Caller,
+    pub caller_program: Option<Pubkey>,
+    pub caller_account: Option<Pubkey>,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub post_balance: u64,
+    pub destination: Pubkey,
+}
+
+impl WithdrawEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [22, 9, 133, 26, 160, 44, 71, 192];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AttachEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub caller: Caller,
+    pub caller_account: Pubkey,
+    pub attached: bool,
+    pub pools_attached: u32,
+    pub bids_attached: u32,
+}
+
+impl AttachEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [246, 168, 138, 40, 99, 120, 39, 60];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub amount: u64,
+    pub expires_at: Option<i64>,
+}
+
+impl AllowanceEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [168, 232, 63, 120, 229, 38, 106, 126];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalDelayEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub withdrawal_delay: u32,
+    pub pending_withdrawal_delay: u32,
+    pub withdrawal_delay_unlock_at: i64,
+}
+
+impl WithdrawalDelayEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [201, 163, 62, 239, 14, 243, 243, 99];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalRequestEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub request_nr: u16,
+    pub amount: u64,
+    pub unlock_at: i64,
+    pub cancelled: bool,
+}
+
+impl WithdrawalRequestEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [223, 105, 92, 147, 28, 49, 115, 238];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct MarginTransferEvent {
+    pub owner: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub source_post_balance: u64,
+    pub destination_post_balance: u64,
+}
+
+impl MarginTransferEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [38, 177, 78, 184, 172, 148, 129, 241];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct OwnerTransferEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub accepted: bool,
+}
+
+impl OwnerTransferEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [13, 78, 9, 151, 245, 201, 121, 188];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PauseEvent {
+    pub tswap: Pubkey,
+    pub authority: Pubkey,
+    pub guardian: Pubkey,
+    pub paused: u8,
+}
+
+impl PauseEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [32, 51, 61, 169, 156, 104, 130, 43];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitEvent {
+    pub caller: Caller,
+    pub caller_program: Option<Pubkey>,
+    pub limit: u64,
+    pub window: u32,
+}
+
+impl RateLimitEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [205, 160, 64, 233, 24, 33, 10, 210];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitExceededEvent {
+    pub margin: Pubkey,
+    pub caller: Caller,
+    pub caller_program: Option<Pubkey>,
+    pub amount: u64,
+    pub used: u64,
+    pub limit: u64,
+    pub window: u32,
+}
+
+impl RateLimitExceededEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [173, 54, 146, 53, 86, 201, 229, 220];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct FeeEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub caller: Caller,
+    pub caller_program: Option<Pubkey>,
+    pub amount: u64,
+    pub fee_vault: Pubkey,
+}
+
+impl FeeEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [73, 79, 78, 127, 184, 213, 13, 220];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfigEvent {
+    pub fees_enabled: bool,
+    pub caller: Option<Caller>,
+    pub caller_program: Option<Pubkey>,
+    pub fee_bps: Option<u16>,
+}
+
+impl FeeConfigEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [254, 74, 77, 252, 81, 85, 216, 157];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct FeeExemptionEvent {
+    pub owner: Pubkey,
+    pub exempt: bool,
+}
+
+impl FeeExemptionEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [158, 245, 75, 139, 235, 180, 241, 100];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawFeesEvent {
+    pub fee_vault: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+}
+
+impl WithdrawFeesEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [236, 118, 138, 90, 139, 173, 177, 89];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CosignThresholdEvent {
+    pub margin: Option<Pubkey>,
+    pub owner: Pubkey,
+    pub threshold: Option<u64>,
+}
+
+impl CosignThresholdEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [82, 249, 129, 169, 217, 10, 253, 139];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct DepositForEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub payer: Pubkey,
+    pub amount: u64,
+    pub post_balance: u64,
+}
+
+impl DepositForEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [217, 30, 244, 34, 137, 155, 123, 10];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CpiDeniedEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub cpi_denied: u8,
+}
+
+impl CpiDeniedEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [113, 26, 5, 168, 106, 26, 103, 128];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ReservationEvent {
+    pub margin: Pubkey,
+    pub caller_account: Pubkey,
+    pub amount: u64,
+    pub reserved_lamports: u64,
+}
+
+impl ReservationEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [3, 96, 167, 104, 5, 33, 1, 222];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct HoldEvent {
+    pub margin: Pubkey,
+    pub hold: Pubkey,
+    pub caller: Caller,
+    pub caller_account: Pubkey,
+    pub amount: u64,
+    pub captured: u64,
+    pub expiry_slot: u64,
+    pub held_lamports: u64,
+}
+
+impl HoldEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [165, 228, 129, 21, 107, 96, 88, 168];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct HoldExpiryEvent {
+    pub owner: Pubkey,
+    pub hold_expiry_slots: u64,
+}
+
+impl HoldExpiryEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [157, 53, 34, 147, 195, 49, 246, 147];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TSwapUpdateEvent {
+    pub tswap: Pubkey,
+    pub owner: Pubkey,
+    pub fee_vault: Pubkey,
+    pub cosigner: Pubkey,
+    pub fee_bps: u16,
+    pub pending_owner: Pubkey,
+}
+
+impl TSwapUpdateEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [38, 18, 181, 137, 42, 176, 137, 67];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CpiConsumerEvent {
+    pub program_id: Pubkey,
+    pub seed_prefix: Vec<u8>,
+    pub signer_discriminator: [u8; 8],
+    pub registered: bool,
+}
+
+impl CpiConsumerEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [141, 69, 119, 156, 111, 186, 233, 106];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CpiConsumerUpdateEvent {
+    pub program_id: Pubkey,
+    pub seed_prefix: Vec<u8>,
+    pub signer_discriminator: [u8; 8],
+}
+
+impl CpiConsumerUpdateEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [137, 87, 10, 8, 141, 250, 168, 155];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ConsumerApprovalEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub consumer_program: Pubkey,
+    pub approved: bool,
+}
+
+impl ConsumerApprovalEvent {
+    pub const DISCRIMINATOR: [u8; 8] = [254, 128, 12, 87, 143, 160, 13, 75];
+}
+
+/// Any event emitted by the program.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EscrowEvent {
+    InitMargin(InitMarginEvent),
+    MigrateMargin(MigrateMarginEvent),
+    ReconcileAttachments(ReconcileAttachmentsEvent),
+    CloseMargin(CloseMarginEvent),
+    Deposit(DepositEvent),
+    Withdraw(WithdrawEvent),
+    Attach(AttachEvent),
+    Allowance(AllowanceEvent),
+    WithdrawalDelay(WithdrawalDelayEvent),
+    WithdrawalRequest(WithdrawalRequestEvent),
+    MarginTransfer(MarginTransferEvent),
+    OwnerTransfer(OwnerTransferEvent),
+    Pause(PauseEvent),
+    RateLimit(RateLimitEvent),
+    RateLimitExceeded(RateLimitExceededEvent),
+    Fee(FeeEvent),
+    FeeConfig(FeeConfigEvent),
+    FeeExemption(FeeExemptionEvent),
+    WithdrawFees(WithdrawFeesEvent),
+    CosignThreshold(CosignThresholdEvent),
+    DepositFor(DepositForEvent),
+    CpiDenied(CpiDeniedEvent),
+    Reservation(ReservationEvent),
+    Hold(HoldEvent),
+    HoldExpiry(HoldExpiryEvent),
+    TSwapUpdate(TSwapUpdateEvent),
+    CpiConsumer(CpiConsumerEvent),
+    CpiConsumerUpdate(CpiConsumerUpdateEvent),
+    ConsumerApproval(ConsumerApprovalEvent),
+}
+
+impl EscrowEvent {
+    /// Decodes an event from either the self-CPI instruction data or a
+    /// `Program data:` log entry (base64-decoded).
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let data = data.strip_prefix(&EVENT_IX_TAG_LE[..]).unwrap_or(data);
+        if data.len() < 8 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "missing event discriminator",
+            ));
+        }
+
+        let (discriminator, mut payload) = data.split_at(8);
+        let event = match discriminator {
+            d if d == InitMarginEvent::DISCRIMINATOR => {
+                Self::InitMargin(InitMarginEvent::deserialize(&mut payload)?)
+            }
+            d if d == MigrateMarginEvent::DISCRIMINATOR => {
+                Self::MigrateMargin(MigrateMarginEvent::deserialize(&mut payload)?)
+            }
+            d if d == ReconcileAttachmentsEvent::DISCRIMINATOR => {
+                Self::ReconcileAttachments(ReconcileAttachmentsEvent::deserialize(&mut payload)?)
+            }
+            d if d == CloseMarginEvent::DISCRIMINATOR => {
+                Self::CloseMargin(CloseMarginEvent::deserialize(&mut payload)?)
+            }
+            d if d == DepositEvent::DISCRIMINATOR => {
+                Self::Deposit(DepositEvent::deserialize(&mut payload)?)
+            }
+            d if d == WithdrawEvent::DISCRIMINATOR => {
+                Self::Withdraw(WithdrawEvent::deserialize(&mut payload)?)
+            }
+            d if d == AttachEvent::DISCRIMINATOR => {
+                Self::Attach(AttachEvent::deserialize(&mut payload)?)
+            }
+            d if d == AllowanceEvent::DISCRIMINATOR => {
+                Self::Allowance(AllowanceEvent::deserialize(&mut payload)?)
+            }
+            d if d == WithdrawalDelayEvent::DISCRIMINATOR => {
+                Self::WithdrawalDelay(WithdrawalDelayEvent::deserialize(&mut payload)?)
+            }
+            d if d == WithdrawalRequestEvent::DISCRIMINATOR => {
+                Self::WithdrawalRequest(WithdrawalRequestEvent::deserialize(&mut payload)?)
+            }
+            d if d == MarginTransferEvent::DISCRIMINATOR => {
+                Self::MarginTransfer(MarginTransferEvent::deserialize(&mut payload)?)
+            }
+            d if d == OwnerTransferEvent::DISCRIMINATOR => {
+                Self::OwnerTransfer(OwnerTransferEvent::deserialize(&mut payload)?)
+            }
+            d if d == PauseEvent::DISCRIMINATOR => {
+                Self::Pause(PauseEvent::deserialize(&mut payload)?)
+            }
+            d if d == RateLimitEvent::DISCRIMINATOR => {
+                Self::RateLimit(RateLimitEvent::deserialize(&mut payload)?)
+            }
+            d if d == RateLimitExceededEvent::DISCRIMINATOR => {
+                Self::RateLimitExceeded(RateLimitExceededEvent::deserialize(&mut payload)?)
+            }
+            d if d == FeeEvent::DISCRIMINATOR => Self::Fee(FeeEvent::deserialize(&mut payload)?),
+            d if d == FeeConfigEvent::DISCRIMINATOR => {
+                Self::FeeConfig(FeeConfigEvent::deserialize(&mut payload)?)
+            }
+            d if d == FeeExemptionEvent::DISCRIMINATOR => {
+                Self::FeeExemption(FeeExemptionEvent::deserialize(&mut payload)?)
+            }
+            d if d == WithdrawFeesEvent::DISCRIMINATOR => {
+                Self::WithdrawFees(WithdrawFeesEvent::deserialize(&mut payload)?)
+            }
+            d if d == CosignThresholdEvent::DISCRIMINATOR => {
+                Self::CosignThreshold(CosignThresholdEvent::deserialize(&mut payload)?)
+            }
+            d if d == DepositForEvent::DISCRIMINATOR => {
+                Self::DepositFor(DepositForEvent::deserialize(&mut payload)?)
+            }
+            d if d == CpiDeniedEvent::DISCRIMINATOR => {
+                Self::CpiDenied(CpiDeniedEvent::deserialize(&mut payload)?)
+            }
+            d if d == ReservationEvent::DISCRIMINATOR => {
+                Self::Reservation(ReservationEvent::deserialize(&mut payload)?)
+            }
+            d if d == HoldEvent::DISCRIMINATOR => Self::Hold(HoldEvent::deserialize(&mut payload)?),
+            d if d == HoldExpiryEvent::DISCRIMINATOR => {
+                Self::HoldExpiry(HoldExpiryEvent::deserialize(&mut payload)?)
+            }
+            d if d == TSwapUpdateEvent::DISCRIMINATOR => {
+                Self::TSwapUpdate(TSwapUpdateEvent::deserialize(&mut payload)?)
+            }
+            d if d == CpiConsumerEvent::DISCRIMINATOR => {
+                Self::CpiConsumer(CpiConsumerEvent::deserialize(&mut payload)?)
+            }
+            d if d == CpiConsumerUpdateEvent::DISCRIMINATOR => {
+                Self::CpiConsumerUpdate(CpiConsumerUpdateEvent::deserialize(&mut payload)?)
+            }
+            d if d == ConsumerApprovalEvent::DISCRIMINATOR => {
+                Self::ConsumerApproval(ConsumerApprovalEvent::deserialize(&mut payload)?)
+            }
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "unknown event discriminator",
+                ))
+            }
+        };
+
+        Ok(event)
+    }
+}
@@ -13,6 +13,8 @@ use solana_program::pubkey::Pubkey;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MarginAccount {
     pub discriminator: [u8; 8],
+    /// Current owner, which is not the key the address was derived from once
+    /// ownership has been handed off (see `MarginAccountV2::seed_owner`).
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
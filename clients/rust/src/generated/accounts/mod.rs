@@ -5,8 +5,28 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#allowance;
+pub(crate) mod r#attachment;
+pub(crate) mod r#consumer_approval;
+pub(crate) mod r#cpi_consumer;
+pub(crate) mod r#fee_exemption;
+pub(crate) mod r#hold;
 pub(crate) mod r#margin_account;
+pub(crate) mod r#margin_account_v2;
+pub(crate) mod r#reservation;
 pub(crate) mod r#t_swap;
+pub(crate) mod r#t_swap_v2;
+pub(crate) mod r#withdrawal_request;
 
+pub use self::r#allowance::*;
+pub use self::r#attachment::*;
+pub use self::r#consumer_approval::*;
+pub use self::r#cpi_consumer::*;
+pub use self::r#fee_exemption::*;
+pub use self::r#hold::*;
 pub use self::r#margin_account::*;
+pub use self::r#margin_account_v2::*;
+pub use self::r#reservation::*;
 pub use self::r#t_swap::*;
+pub use self::r#t_swap_v2::*;
+pub use self::r#withdrawal_request::*;
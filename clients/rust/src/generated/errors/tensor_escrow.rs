@@ -16,6 +16,120 @@ pub enum TensorEscrowError {
     /// 6027 - bad margin account passed
     #[error("bad margin account passed")]
     BadMargin = 0x178B,
+    /// 6028 - margin account still has pools or bids attached
+    #[error("margin account still has pools or bids attached")]
+    MarginInUse = 0x178C,
+    /// 6029 - margin account is already on the latest version
+    #[error("margin account is already on the latest version")]
+    MarginAlreadyMigrated = 0x178D,
+    /// 6030 - margin account balance does not cover rent for the new layout
+    #[error("margin account balance does not cover rent for the new layout")]
+    MarginRentNotCovered = 0x178E,
+    /// 6031 - seed prefix must be between 1 and 32 bytes
+    #[error("seed prefix must be between 1 and 32 bytes")]
+    BadSeedPrefix = 0x178F,
+    /// 6032 - signer is not a PDA of a registered cpi consumer
+    #[error("signer is not a PDA of a registered cpi consumer")]
+    BadCpiSigner = 0x1790,
+    /// 6033 - signing pool or bid does not reference this margin account
+    #[error("signing pool or bid does not reference this margin account")]
+    MarginMismatch = 0x1791,
+    /// 6034 - amount exceeds what the bid can draw
+    #[error("amount exceeds what the bid can draw")]
+    AmountExceedsBid = 0x1792,
+    /// 6035 - amount exceeds the delegate's allowance
+    #[error("amount exceeds the delegate's allowance")]
+    AllowanceExceeded = 0x1793,
+    /// 6036 - delegate allowance has expired
+    #[error("delegate allowance has expired")]
+    AllowanceExpired = 0x1794,
+    /// 6037 - margin account withdrawals have to be requested and wait for the delay
+    #[error("margin account withdrawals have to be requested and wait for the delay")]
+    WithdrawalDelayed = 0x1795,
+    /// 6038 - withdrawal is still locked
+    #[error("withdrawal is still locked")]
+    WithdrawalLocked = 0x1796,
+    /// 6039 - amount exceeds the margin account's available balance
+    #[error("amount exceeds the margin account's available balance")]
+    InsufficientAvailableBalance = 0x1797,
+    /// 6040 - margin account has no withdrawal delay
+    #[error("margin account has no withdrawal delay")]
+    NoWithdrawalDelay = 0x1798,
+    /// 6041 - fee bps exceeds the maximum
+    #[error("fee bps exceeds the maximum")]
+    FeeBpsTooHigh = 0x1799,
+    /// 6042 - fee vault must be owned by the system program
+    #[error("fee vault must be owned by the system program")]
+    BadFeeVault = 0x179A,
+    /// 6043 - tswap is already on the latest version
+    #[error("tswap is already on the latest version")]
+    TSwapAlreadyMigrated = 0x179B,
+    /// 6044 - instruction is paused
+    #[error("instruction is paused")]
+    Paused = 0x179C,
+    /// 6045 - only the owner can unpause
+    #[error("only the owner can unpause")]
+    OnlyOwnerCanUnpause = 0x179D,
+    /// 6046 - withdrawal exceeds the caller's rate limit
+    #[error("withdrawal exceeds the caller's rate limit")]
+    RateLimitExceeded = 0x179E,
+    /// 6047 - caller is not TAMM, TCOMP or a registered cpi consumer
+    #[error("caller is not TAMM, TCOMP or a registered cpi consumer")]
+    BadCpiCaller = 0x179F,
+    /// 6048 - fee vault has to be passed in when a fee is due
+    #[error("fee vault has to be passed in when a fee is due")]
+    FeeVaultMissing = 0x17A0,
+    /// 6049 - cosigner is not the tswap cosigner
+    #[error("cosigner is not the tswap cosigner")]
+    BadCosigner = 0x17A1,
+    /// 6050 - amount exceeds the cosign threshold and has to be co-signed
+    #[error("amount exceeds the cosign threshold and has to be co-signed")]
+    CosignRequired = 0x17A2,
+    /// 6051 - number of margin accounts passed in doesn't match the amounts
+    #[error("number of margin accounts passed in doesn't match the amounts")]
+    MarginCountMismatch = 0x17A3,
+    /// 6052 - margin account owner doesn't allow this caller
+    #[error("margin account owner doesn't allow this caller")]
+    CpiDenied = 0x17A4,
+    /// 6053 - authority is neither the margin account owner nor the reservation's caller
+    #[error("authority is neither the margin account owner nor the reservation's caller")]
+    BadReservationAuthority = 0x17A5,
+    /// 6054 - hold expired
+    #[error("hold expired")]
+    HoldExpired = 0x17A6,
+    /// 6055 - hold can only be released by its caller until it expires
+    #[error("hold can only be released by its caller until it expires")]
+    HoldNotExpired = 0x17A7,
+    /// 6056 - amount exceeds the hold
+    #[error("amount exceeds the hold")]
+    AmountExceedsHold = 0x17A8,
+    /// 6057 - mint is not the currency of the pool or bid
+    #[error("mint is not the currency of the pool or bid")]
+    CurrencyMismatch = 0x17A9,
+    /// 6058 - mints with a transfer hook are not supported
+    #[error("mints with a transfer hook are not supported")]
+    UnsupportedMint = 0x17AA,
+    /// 6059 - margin account has to be migrated to V2 first
+    #[error("margin account has to be migrated to V2 first")]
+    MarginNotMigrated = 0x17AB,
+    /// 6060 - TAMM and TCOMP can't be registered as cpi consumers
+    #[error("TAMM and TCOMP can't be registered as cpi consumers")]
+    ReservedCpiConsumer = 0x17AC,
+    /// 6061 - granted on a closed margin account that was at this address
+    #[error("granted on a closed margin account that was at this address")]
+    StaleGrant = 0x17AD,
+    /// 6062 - margin account can't be closed in the slot it was created or migrated in
+    #[error("margin account can't be closed in the slot it was created or migrated in")]
+    MarginClosedTooSoon = 0x17AE,
+    /// 6063 - tswap has to be migrated to V2 first
+    #[error("tswap has to be migrated to V2 first")]
+    TSwapNotMigrated = 0x17AF,
+    /// 6064 - margin account passed in more than once
+    #[error("margin account passed in more than once")]
+    DuplicateMarginAccount = 0x17B0,
+    /// 6065 - pools and bids attached while on V1 have to be reconciled first
+    #[error("pools and bids attached while on V1 have to be reconciled first")]
+    UntrackedAttachments = 0x17B1,
 }
 
 impl solana_program::program_error::PrintProgramError for TensorEscrowError {
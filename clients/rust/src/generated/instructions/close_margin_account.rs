@@ -18,6 +18,14 @@ pub struct CloseMarginAccount {
     pub owner: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub rent_destination: Option<solana_program::pubkey::Pubkey>,
+    /// Required once the amount exceeds the cosign threshold.
+    pub cosigner: Option<solana_program::pubkey::Pubkey>,
+
+    pub event_authority: Option<solana_program::pubkey::Pubkey>,
+
+    pub program: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl CloseMarginAccount {
@@ -29,7 +37,7 @@ impl CloseMarginAccount {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.tswap, false,
         ));
@@ -44,6 +52,48 @@ impl CloseMarginAccount {
             self.system_program,
             false,
         ));
+        if let Some(rent_destination) = self.rent_destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rent_destination,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(cosigner) = self.cosigner {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                cosigner, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                event_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                program, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&CloseMarginAccountInstructionData::new()).unwrap();
 
@@ -83,12 +133,20 @@ impl Default for CloseMarginAccountInstructionData {
 ///   1. `[writable]` margin_account
 ///   2. `[writable, signer]` owner
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[writable, optional]` rent_destination
+///   5. `[signer, optional]` cosigner
+///   6. `[optional]` event_authority
+///   7. `[optional]` program
 #[derive(Clone, Debug, Default)]
 pub struct CloseMarginAccountBuilder {
     tswap: Option<solana_program::pubkey::Pubkey>,
     margin_account: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    rent_destination: Option<solana_program::pubkey::Pubkey>,
+    cosigner: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -117,6 +175,37 @@ impl CloseMarginAccountBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn rent_destination(
+        &mut self,
+        rent_destination: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.rent_destination = rent_destination;
+        self
+    }
+    /// `[optional account]`
+    /// Required once the amount exceeds the cosign threshold.
+    #[inline(always)]
+    pub fn cosigner(&mut self, cosigner: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.cosigner = cosigner;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(&mut self, program: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.program = program;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -144,6 +233,10 @@ impl CloseMarginAccountBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent_destination: self.rent_destination,
+            cosigner: self.cosigner,
+            event_authority: self.event_authority,
+            program: self.program,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -159,6 +252,14 @@ pub struct CloseMarginAccountCpiAccounts<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rent_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required once the amount exceeds the cosign threshold.
+    pub cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `close_margin_account` CPI instruction.
@@ -173,6 +274,14 @@ pub struct CloseMarginAccountCpi<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rent_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required once the amount exceeds the cosign threshold.
+    pub cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> CloseMarginAccountCpi<'a, 'b> {
@@ -186,6 +295,10 @@ impl<'a, 'b> CloseMarginAccountCpi<'a, 'b> {
             margin_account: accounts.margin_account,
             owner: accounts.owner,
             system_program: accounts.system_program,
+            rent_destination: accounts.rent_destination,
+            cosigner: accounts.cosigner,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
         }
     }
     #[inline(always)]
@@ -221,7 +334,7 @@ impl<'a, 'b> CloseMarginAccountCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.tswap.key,
             false,
@@ -238,6 +351,50 @@ impl<'a, 'b> CloseMarginAccountCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(rent_destination) = self.rent_destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rent_destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(cosigner) = self.cosigner {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *cosigner.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *event_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -252,12 +409,24 @@ impl<'a, 'b> CloseMarginAccountCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.tswap.clone());
         account_infos.push(self.margin_account.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(rent_destination) = self.rent_destination {
+            account_infos.push(rent_destination.clone());
+        }
+        if let Some(cosigner) = self.cosigner {
+            account_infos.push(cosigner.clone());
+        }
+        if let Some(event_authority) = self.event_authority {
+            account_infos.push(event_authority.clone());
+        }
+        if let Some(program) = self.program {
+            account_infos.push(program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -278,6 +447,10 @@ impl<'a, 'b> CloseMarginAccountCpi<'a, 'b> {
 ///   1. `[writable]` margin_account
 ///   2. `[writable, signer]` owner
 ///   3. `[]` system_program
+///   4. `[writable, optional]` rent_destination
+///   5. `[signer, optional]` cosigner
+///   6. `[optional]` event_authority
+///   7. `[optional]` program
 #[derive(Clone, Debug)]
 pub struct CloseMarginAccountCpiBuilder<'a, 'b> {
     instruction: Box<CloseMarginAccountCpiBuilderInstruction<'a, 'b>>,
@@ -291,6 +464,10 @@ impl<'a, 'b> CloseMarginAccountCpiBuilder<'a, 'b> {
             margin_account: None,
             owner: None,
             system_program: None,
+            rent_destination: None,
+            cosigner: None,
+            event_authority: None,
+            program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -321,6 +498,43 @@ impl<'a, 'b> CloseMarginAccountCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn rent_destination(
+        &mut self,
+        rent_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rent_destination = rent_destination;
+        self
+    }
+    /// `[optional account]`
+    /// Required once the amount exceeds the cosign threshold.
+    #[inline(always)]
+    pub fn cosigner(
+        &mut self,
+        cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cosigner = cosigner;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.program = program;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -378,6 +592,14 @@ impl<'a, 'b> CloseMarginAccountCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            rent_destination: self.instruction.rent_destination,
+
+            cosigner: self.instruction.cosigner,
+
+            event_authority: self.instruction.event_authority,
+
+            program: self.instruction.program,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -393,6 +615,10 @@ struct CloseMarginAccountCpiBuilderInstruction<'a, 'b> {
     margin_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rent_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -18,6 +18,10 @@ pub struct DepositMarginAccount {
     pub owner: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub event_authority: Option<solana_program::pubkey::Pubkey>,
+
+    pub program: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl DepositMarginAccount {
@@ -33,7 +37,7 @@ impl DepositMarginAccount {
         args: DepositMarginAccountInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.tswap, false,
         ));
@@ -48,6 +52,27 @@ impl DepositMarginAccount {
             self.system_program,
             false,
         ));
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                event_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                program, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&DepositMarginAccountInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -95,12 +120,16 @@ pub struct DepositMarginAccountInstructionArgs {
 ///   1. `[writable]` margin_account
 ///   2. `[writable, signer]` owner
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[optional]` event_authority
+///   5. `[optional]` program
 #[derive(Clone, Debug, Default)]
 pub struct DepositMarginAccountBuilder {
     tswap: Option<solana_program::pubkey::Pubkey>,
     margin_account: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     lamports: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -130,6 +159,21 @@ impl DepositMarginAccountBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(&mut self, program: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.program = program;
+        self
+    }
     #[inline(always)]
     pub fn lamports(&mut self, lamports: u64) -> &mut Self {
         self.lamports = Some(lamports);
@@ -162,6 +206,8 @@ impl DepositMarginAccountBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority,
+            program: self.program,
         };
         let args = DepositMarginAccountInstructionArgs {
             lamports: self.lamports.clone().expect("lamports is not set"),
@@ -180,6 +226,10 @@ pub struct DepositMarginAccountCpiAccounts<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `deposit_margin_account` CPI instruction.
@@ -194,6 +244,10 @@ pub struct DepositMarginAccountCpi<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DepositMarginAccountInstructionArgs,
 }
@@ -210,6 +264,8 @@ impl<'a, 'b> DepositMarginAccountCpi<'a, 'b> {
             margin_account: accounts.margin_account,
             owner: accounts.owner,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -246,7 +302,7 @@ impl<'a, 'b> DepositMarginAccountCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.tswap.key,
             false,
@@ -263,6 +319,28 @@ impl<'a, 'b> DepositMarginAccountCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *event_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -279,12 +357,18 @@ impl<'a, 'b> DepositMarginAccountCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.tswap.clone());
         account_infos.push(self.margin_account.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(event_authority) = self.event_authority {
+            account_infos.push(event_authority.clone());
+        }
+        if let Some(program) = self.program {
+            account_infos.push(program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -305,6 +389,8 @@ impl<'a, 'b> DepositMarginAccountCpi<'a, 'b> {
 ///   1. `[writable]` margin_account
 ///   2. `[writable, signer]` owner
 ///   3. `[]` system_program
+///   4. `[optional]` event_authority
+///   5. `[optional]` program
 #[derive(Clone, Debug)]
 pub struct DepositMarginAccountCpiBuilder<'a, 'b> {
     instruction: Box<DepositMarginAccountCpiBuilderInstruction<'a, 'b>>,
@@ -318,6 +404,8 @@ impl<'a, 'b> DepositMarginAccountCpiBuilder<'a, 'b> {
             margin_account: None,
             owner: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             lamports: None,
             __remaining_accounts: Vec::new(),
         });
@@ -349,6 +437,24 @@ impl<'a, 'b> DepositMarginAccountCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.program = program;
+        self
+    }
     #[inline(always)]
     pub fn lamports(&mut self, lamports: u64) -> &mut Self {
         self.instruction.lamports = Some(lamports);
@@ -418,6 +524,10 @@ impl<'a, 'b> DepositMarginAccountCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self.instruction.event_authority,
+
+            program: self.instruction.program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -434,6 +544,8 @@ struct DepositMarginAccountCpiBuilderInstruction<'a, 'b> {
     margin_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     lamports: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -18,6 +18,10 @@ pub struct InitMarginAccount {
     pub owner: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub event_authority: Option<solana_program::pubkey::Pubkey>,
+
+    pub program: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl InitMarginAccount {
@@ -33,7 +37,7 @@ impl InitMarginAccount {
         args: InitMarginAccountInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.tswap, false,
         ));
@@ -48,6 +52,27 @@ impl InitMarginAccount {
             self.system_program,
             false,
         ));
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                event_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                program, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&InitMarginAccountInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -96,12 +121,16 @@ pub struct InitMarginAccountInstructionArgs {
 ///   1. `[writable]` margin_account
 ///   2. `[writable, signer]` owner
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[optional]` event_authority
+///   5. `[optional]` program
 #[derive(Clone, Debug, Default)]
 pub struct InitMarginAccountBuilder {
     tswap: Option<solana_program::pubkey::Pubkey>,
     margin_account: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     margin_nr: Option<u16>,
     name: Option<[u8; 32]>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -132,6 +161,21 @@ impl InitMarginAccountBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(&mut self, program: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.program = program;
+        self
+    }
     /// `[optional argument, defaults to '0']`
     #[inline(always)]
     pub fn margin_nr(&mut self, margin_nr: u16) -> &mut Self {
@@ -171,6 +215,8 @@ impl InitMarginAccountBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority,
+            program: self.program,
         };
         let args = InitMarginAccountInstructionArgs {
             margin_nr: self.margin_nr.clone().unwrap_or(0),
@@ -193,6 +239,10 @@ pub struct InitMarginAccountCpiAccounts<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `init_margin_account` CPI instruction.
@@ -207,6 +257,10 @@ pub struct InitMarginAccountCpi<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: InitMarginAccountInstructionArgs,
 }
@@ -223,6 +277,8 @@ impl<'a, 'b> InitMarginAccountCpi<'a, 'b> {
             margin_account: accounts.margin_account,
             owner: accounts.owner,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -259,7 +315,7 @@ impl<'a, 'b> InitMarginAccountCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.tswap.key,
             false,
@@ -276,6 +332,28 @@ impl<'a, 'b> InitMarginAccountCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *event_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -292,12 +370,18 @@ impl<'a, 'b> InitMarginAccountCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.tswap.clone());
         account_infos.push(self.margin_account.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(event_authority) = self.event_authority {
+            account_infos.push(event_authority.clone());
+        }
+        if let Some(program) = self.program {
+            account_infos.push(program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -318,6 +402,8 @@ impl<'a, 'b> InitMarginAccountCpi<'a, 'b> {
 ///   1. `[writable]` margin_account
 ///   2. `[writable, signer]` owner
 ///   3. `[]` system_program
+///   4. `[optional]` event_authority
+///   5. `[optional]` program
 #[derive(Clone, Debug)]
 pub struct InitMarginAccountCpiBuilder<'a, 'b> {
     instruction: Box<InitMarginAccountCpiBuilderInstruction<'a, 'b>>,
@@ -331,6 +417,8 @@ impl<'a, 'b> InitMarginAccountCpiBuilder<'a, 'b> {
             margin_account: None,
             owner: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             margin_nr: None,
             name: None,
             __remaining_accounts: Vec::new(),
@@ -363,6 +451,24 @@ impl<'a, 'b> InitMarginAccountCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.program = program;
+        self
+    }
     /// `[optional argument, defaults to '0']`
     #[inline(always)]
     pub fn margin_nr(&mut self, margin_nr: u16) -> &mut Self {
@@ -439,6 +545,10 @@ impl<'a, 'b> InitMarginAccountCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self.instruction.event_authority,
+
+            program: self.instruction.program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -455,6 +565,8 @@ struct InitMarginAccountCpiBuilderInstruction<'a, 'b> {
     margin_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     margin_nr: Option<u16>,
     name: Option<[u8; 32]>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -11,7 +11,7 @@ use borsh::BorshSerialize;
 
 /// Accounts.
 #[derive(Debug)]
-pub struct InitUpdateTswap {
+pub struct InitTswap {
     pub tswap: solana_program::pubkey::Pubkey,
 
     pub fee_vault: solana_program::pubkey::Pubkey,
@@ -22,23 +22,25 @@ pub struct InitUpdateTswap {
 
     pub system_program: solana_program::pubkey::Pubkey,
 
-    pub new_owner: solana_program::pubkey::Pubkey,
+    pub event_authority: Option<solana_program::pubkey::Pubkey>,
+
+    pub program: Option<solana_program::pubkey::Pubkey>,
 }
 
-impl InitUpdateTswap {
+impl InitTswap {
     pub fn instruction(
         &self,
-        args: InitUpdateTswapInstructionArgs,
+        args: InitTswapInstructionArgs,
     ) -> solana_program::instruction::Instruction {
         self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
-        args: InitUpdateTswapInstructionArgs,
+        args: InitTswapInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.tswap, false,
         ));
@@ -57,12 +59,29 @@ impl InitUpdateTswap {
             self.system_program,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.new_owner,
-            true,
-        ));
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                event_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                program, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
-        let mut data = borsh::to_vec(&InitUpdateTswapInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&InitTswapInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
         data.append(&mut args);
 
@@ -76,19 +95,19 @@ impl InitUpdateTswap {
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct InitUpdateTswapInstructionData {
+pub struct InitTswapInstructionData {
     discriminator: [u8; 8],
 }
 
-impl InitUpdateTswapInstructionData {
+impl InitTswapInstructionData {
     pub fn new() -> Self {
         Self {
-            discriminator: [140, 185, 54, 172, 15, 94, 31, 155],
+            discriminator: [23, 40, 66, 36, 249, 61, 97, 195],
         }
     }
 }
 
-impl Default for InitUpdateTswapInstructionData {
+impl Default for InitTswapInstructionData {
     fn default() -> Self {
         Self::new()
     }
@@ -96,11 +115,11 @@ impl Default for InitUpdateTswapInstructionData {
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct InitUpdateTswapInstructionArgs {
+pub struct InitTswapInstructionArgs {
     pub config: TSwapConfig,
 }
 
-/// Instruction builder for `InitUpdateTswap`.
+/// Instruction builder for `InitTswap`.
 ///
 /// ### Accounts:
 ///
@@ -109,20 +128,22 @@ pub struct InitUpdateTswapInstructionArgs {
 ///   2. `[signer]` cosigner
 ///   3. `[writable, signer]` owner
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   5. `[signer]` new_owner
+///   5. `[optional]` event_authority
+///   6. `[optional]` program
 #[derive(Clone, Debug, Default)]
-pub struct InitUpdateTswapBuilder {
+pub struct InitTswapBuilder {
     tswap: Option<solana_program::pubkey::Pubkey>,
     fee_vault: Option<solana_program::pubkey::Pubkey>,
     cosigner: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
-    new_owner: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     config: Option<TSwapConfig>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
-impl InitUpdateTswapBuilder {
+impl InitTswapBuilder {
     pub fn new() -> Self {
         Self::default()
     }
@@ -153,9 +174,19 @@ impl InitUpdateTswapBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
-    pub fn new_owner(&mut self, new_owner: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.new_owner = Some(new_owner);
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(&mut self, program: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.program = program;
         self
     }
     #[inline(always)]
@@ -183,7 +214,7 @@ impl InitUpdateTswapBuilder {
     }
     #[allow(clippy::clone_on_copy)]
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        let accounts = InitUpdateTswap {
+        let accounts = InitTswap {
             tswap: self.tswap.expect("tswap is not set"),
             fee_vault: self.fee_vault.expect("fee_vault is not set"),
             cosigner: self.cosigner.expect("cosigner is not set"),
@@ -191,9 +222,10 @@ impl InitUpdateTswapBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
-            new_owner: self.new_owner.expect("new_owner is not set"),
+            event_authority: self.event_authority,
+            program: self.program,
         };
-        let args = InitUpdateTswapInstructionArgs {
+        let args = InitTswapInstructionArgs {
             config: self.config.clone().expect("config is not set"),
         };
 
@@ -201,8 +233,8 @@ impl InitUpdateTswapBuilder {
     }
 }
 
-/// `init_update_tswap` CPI accounts.
-pub struct InitUpdateTswapCpiAccounts<'a, 'b> {
+/// `init_tswap` CPI accounts.
+pub struct InitTswapCpiAccounts<'a, 'b> {
     pub tswap: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub fee_vault: &'b solana_program::account_info::AccountInfo<'a>,
@@ -213,11 +245,13 @@ pub struct InitUpdateTswapCpiAccounts<'a, 'b> {
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub new_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
-/// `init_update_tswap` CPI instruction.
-pub struct InitUpdateTswapCpi<'a, 'b> {
+/// `init_tswap` CPI instruction.
+pub struct InitTswapCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -231,16 +265,18 @@ pub struct InitUpdateTswapCpi<'a, 'b> {
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub new_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
-    pub __args: InitUpdateTswapInstructionArgs,
+    pub __args: InitTswapInstructionArgs,
 }
 
-impl<'a, 'b> InitUpdateTswapCpi<'a, 'b> {
+impl<'a, 'b> InitTswapCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
-        accounts: InitUpdateTswapCpiAccounts<'a, 'b>,
-        args: InitUpdateTswapInstructionArgs,
+        accounts: InitTswapCpiAccounts<'a, 'b>,
+        args: InitTswapInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -249,7 +285,8 @@ impl<'a, 'b> InitUpdateTswapCpi<'a, 'b> {
             cosigner: accounts.cosigner,
             owner: accounts.owner,
             system_program: accounts.system_program,
-            new_owner: accounts.new_owner,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -286,7 +323,7 @@ impl<'a, 'b> InitUpdateTswapCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.tswap.key,
             false,
@@ -307,10 +344,28 @@ impl<'a, 'b> InitUpdateTswapCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.new_owner.key,
-            true,
-        ));
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *event_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -318,7 +373,7 @@ impl<'a, 'b> InitUpdateTswapCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let mut data = borsh::to_vec(&InitUpdateTswapInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&InitTswapInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&self.__args).unwrap();
         data.append(&mut args);
 
@@ -327,14 +382,19 @@ impl<'a, 'b> InitUpdateTswapCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.tswap.clone());
         account_infos.push(self.fee_vault.clone());
         account_infos.push(self.cosigner.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.system_program.clone());
-        account_infos.push(self.new_owner.clone());
+        if let Some(event_authority) = self.event_authority {
+            account_infos.push(event_authority.clone());
+        }
+        if let Some(program) = self.program {
+            account_infos.push(program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -347,7 +407,7 @@ impl<'a, 'b> InitUpdateTswapCpi<'a, 'b> {
     }
 }
 
-/// Instruction builder for `InitUpdateTswap` via CPI.
+/// Instruction builder for `InitTswap` via CPI.
 ///
 /// ### Accounts:
 ///
@@ -356,22 +416,24 @@ impl<'a, 'b> InitUpdateTswapCpi<'a, 'b> {
 ///   2. `[signer]` cosigner
 ///   3. `[writable, signer]` owner
 ///   4. `[]` system_program
-///   5. `[signer]` new_owner
+///   5. `[optional]` event_authority
+///   6. `[optional]` program
 #[derive(Clone, Debug)]
-pub struct InitUpdateTswapCpiBuilder<'a, 'b> {
-    instruction: Box<InitUpdateTswapCpiBuilderInstruction<'a, 'b>>,
+pub struct InitTswapCpiBuilder<'a, 'b> {
+    instruction: Box<InitTswapCpiBuilderInstruction<'a, 'b>>,
 }
 
-impl<'a, 'b> InitUpdateTswapCpiBuilder<'a, 'b> {
+impl<'a, 'b> InitTswapCpiBuilder<'a, 'b> {
     pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
-        let instruction = Box::new(InitUpdateTswapCpiBuilderInstruction {
+        let instruction = Box::new(InitTswapCpiBuilderInstruction {
             __program: program,
             tswap: None,
             fee_vault: None,
             cosigner: None,
             owner: None,
             system_program: None,
-            new_owner: None,
+            event_authority: None,
+            program: None,
             config: None,
             __remaining_accounts: Vec::new(),
         });
@@ -412,12 +474,22 @@ impl<'a, 'b> InitUpdateTswapCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
-    pub fn new_owner(
+    pub fn event_authority(
         &mut self,
-        new_owner: &'b solana_program::account_info::AccountInfo<'a>,
+        event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.new_owner = Some(new_owner);
+        self.instruction.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.program = program;
         self
     }
     #[inline(always)]
@@ -466,10 +538,10 @@ impl<'a, 'b> InitUpdateTswapCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
-        let args = InitUpdateTswapInstructionArgs {
+        let args = InitTswapInstructionArgs {
             config: self.instruction.config.clone().expect("config is not set"),
         };
-        let instruction = InitUpdateTswapCpi {
+        let instruction = InitTswapCpi {
             __program: self.instruction.__program,
 
             tswap: self.instruction.tswap.expect("tswap is not set"),
@@ -485,7 +557,9 @@ impl<'a, 'b> InitUpdateTswapCpiBuilder<'a, 'b> {
                 .system_program
                 .expect("system_program is not set"),
 
-            new_owner: self.instruction.new_owner.expect("new_owner is not set"),
+            event_authority: self.instruction.event_authority,
+
+            program: self.instruction.program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -496,14 +570,15 @@ impl<'a, 'b> InitUpdateTswapCpiBuilder<'a, 'b> {
 }
 
 #[derive(Clone, Debug)]
-struct InitUpdateTswapCpiBuilderInstruction<'a, 'b> {
+struct InitTswapCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     tswap: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     fee_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    new_owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     config: Option<TSwapConfig>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -5,18 +5,128 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_margin_owner;
+pub(crate) mod r#accept_tswap_owner;
+pub(crate) mod r#add_fee_exemption;
+pub(crate) mod r#approve_allowance;
+pub(crate) mod r#approve_consumer;
+pub(crate) mod r#attach_margin_account_cpi_tamm;
+pub(crate) mod r#attach_margin_account_cpi_tcomp;
+pub(crate) mod r#cancel_withdrawal;
+pub(crate) mod r#capture_hold_cpi_tamm;
+pub(crate) mod r#capture_hold_cpi_tcomp;
+pub(crate) mod r#close_cpi_consumer;
 pub(crate) mod r#close_margin_account;
+pub(crate) mod r#consolidate_margin_accounts;
 pub(crate) mod r#deposit_margin_account;
+pub(crate) mod r#deposit_margin_account_for;
+pub(crate) mod r#deposit_margin_account_token;
+pub(crate) mod r#deposit_margin_accounts;
+pub(crate) mod r#detach_margin_account_cpi_tamm;
+pub(crate) mod r#detach_margin_account_cpi_tcomp;
+pub(crate) mod r#execute_withdrawal;
 pub(crate) mod r#init_margin_account;
-pub(crate) mod r#init_update_tswap;
+pub(crate) mod r#init_margin_account_v2;
+pub(crate) mod r#init_tswap;
+pub(crate) mod r#init_update_cpi_consumer;
+pub(crate) mod r#migrate_margin_account;
+pub(crate) mod r#migrate_tswap;
+pub(crate) mod r#place_hold_cpi_tamm;
+pub(crate) mod r#place_hold_cpi_tcomp;
+pub(crate) mod r#propose_margin_owner;
+pub(crate) mod r#propose_tswap_owner;
+pub(crate) mod r#reconcile_attachments;
+pub(crate) mod r#release_hold;
+pub(crate) mod r#release_reservation;
+pub(crate) mod r#remove_fee_exemption;
+pub(crate) mod r#request_withdrawal;
+pub(crate) mod r#reserve_margin_lamports;
+pub(crate) mod r#reserve_margin_lamports_from_consumer;
+pub(crate) mod r#revoke_allowance;
+pub(crate) mod r#revoke_consumer;
+pub(crate) mod r#set_cosign_threshold;
+pub(crate) mod r#set_cosigner;
+pub(crate) mod r#set_cpi_denied;
+pub(crate) mod r#set_cpi_fee;
+pub(crate) mod r#set_fee_vault;
+pub(crate) mod r#set_fees_enabled;
+pub(crate) mod r#set_guardian;
+pub(crate) mod r#set_hold_expiry;
+pub(crate) mod r#set_margin_cosign_threshold;
+pub(crate) mod r#set_paused;
+pub(crate) mod r#set_rate_limit;
+pub(crate) mod r#set_withdrawal_delay;
+pub(crate) mod r#spend_allowance;
+pub(crate) mod r#transfer_between_margin_accounts;
+pub(crate) mod r#update_tswap_config;
+pub(crate) mod r#withdraw_fees;
 pub(crate) mod r#withdraw_margin_account;
 pub(crate) mod r#withdraw_margin_account_cpi_tamm;
 pub(crate) mod r#withdraw_margin_account_cpi_tcomp;
+pub(crate) mod r#withdraw_margin_account_from_consumer;
+pub(crate) mod r#withdraw_margin_account_token;
+pub(crate) mod r#withdraw_margin_account_token_cpi_tamm;
+pub(crate) mod r#withdraw_margin_account_token_cpi_tcomp;
 
+pub use self::r#accept_margin_owner::*;
+pub use self::r#accept_tswap_owner::*;
+pub use self::r#add_fee_exemption::*;
+pub use self::r#approve_allowance::*;
+pub use self::r#approve_consumer::*;
+pub use self::r#attach_margin_account_cpi_tamm::*;
+pub use self::r#attach_margin_account_cpi_tcomp::*;
+pub use self::r#cancel_withdrawal::*;
+pub use self::r#capture_hold_cpi_tamm::*;
+pub use self::r#capture_hold_cpi_tcomp::*;
+pub use self::r#close_cpi_consumer::*;
 pub use self::r#close_margin_account::*;
+pub use self::r#consolidate_margin_accounts::*;
 pub use self::r#deposit_margin_account::*;
+pub use self::r#deposit_margin_account_for::*;
+pub use self::r#deposit_margin_account_token::*;
+pub use self::r#deposit_margin_accounts::*;
+pub use self::r#detach_margin_account_cpi_tamm::*;
+pub use self::r#detach_margin_account_cpi_tcomp::*;
+pub use self::r#execute_withdrawal::*;
 pub use self::r#init_margin_account::*;
-pub use self::r#init_update_tswap::*;
+pub use self::r#init_margin_account_v2::*;
+pub use self::r#init_tswap::*;
+pub use self::r#init_update_cpi_consumer::*;
+pub use self::r#migrate_margin_account::*;
+pub use self::r#migrate_tswap::*;
+pub use self::r#place_hold_cpi_tamm::*;
+pub use self::r#place_hold_cpi_tcomp::*;
+pub use self::r#propose_margin_owner::*;
+pub use self::r#propose_tswap_owner::*;
+pub use self::r#reconcile_attachments::*;
+pub use self::r#release_hold::*;
+pub use self::r#release_reservation::*;
+pub use self::r#remove_fee_exemption::*;
+pub use self::r#request_withdrawal::*;
+pub use self::r#reserve_margin_lamports::*;
+pub use self::r#reserve_margin_lamports_from_consumer::*;
+pub use self::r#revoke_allowance::*;
+pub use self::r#revoke_consumer::*;
+pub use self::r#set_cosign_threshold::*;
+pub use self::r#set_cosigner::*;
+pub use self::r#set_cpi_denied::*;
+pub use self::r#set_cpi_fee::*;
+pub use self::r#set_fee_vault::*;
+pub use self::r#set_fees_enabled::*;
+pub use self::r#set_guardian::*;
+pub use self::r#set_hold_expiry::*;
+pub use self::r#set_margin_cosign_threshold::*;
+pub use self::r#set_paused::*;
+pub use self::r#set_rate_limit::*;
+pub use self::r#set_withdrawal_delay::*;
+pub use self::r#spend_allowance::*;
+pub use self::r#transfer_between_margin_accounts::*;
+pub use self::r#update_tswap_config::*;
+pub use self::r#withdraw_fees::*;
 pub use self::r#withdraw_margin_account::*;
 pub use self::r#withdraw_margin_account_cpi_tamm::*;
 pub use self::r#withdraw_margin_account_cpi_tcomp::*;
+pub use self::r#withdraw_margin_account_from_consumer::*;
+pub use self::r#withdraw_margin_account_token::*;
+pub use self::r#withdraw_margin_account_token_cpi_tamm::*;
+pub use self::r#withdraw_margin_account_token_cpi_tcomp::*;
@@ -18,6 +18,14 @@ pub struct WithdrawMarginAccount {
     pub owner: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+    /// Required once the amount exceeds the cosign threshold.
+    pub cosigner: Option<solana_program::pubkey::Pubkey>,
+    /// The owner when not passed in.
+    pub destination: Option<solana_program::pubkey::Pubkey>,
+
+    pub event_authority: Option<solana_program::pubkey::Pubkey>,
+
+    pub program: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl WithdrawMarginAccount {
@@ -33,7 +41,7 @@ impl WithdrawMarginAccount {
         args: WithdrawMarginAccountInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.tswap, false,
         ));
@@ -48,6 +56,48 @@ impl WithdrawMarginAccount {
             self.system_program,
             false,
         ));
+        if let Some(cosigner) = self.cosigner {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                cosigner, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                destination,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                event_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                program, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&WithdrawMarginAccountInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -95,12 +145,20 @@ pub struct WithdrawMarginAccountInstructionArgs {
 ///   1. `[writable]` margin_account
 ///   2. `[writable, signer]` owner
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[signer, optional]` cosigner
+///   5. `[writable, optional]` destination
+///   6. `[optional]` event_authority
+///   7. `[optional]` program
 #[derive(Clone, Debug, Default)]
 pub struct WithdrawMarginAccountBuilder {
     tswap: Option<solana_program::pubkey::Pubkey>,
     margin_account: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    cosigner: Option<solana_program::pubkey::Pubkey>,
+    destination: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     lamports: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -130,6 +188,38 @@ impl WithdrawMarginAccountBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Required once the amount exceeds the cosign threshold.
+    #[inline(always)]
+    pub fn cosigner(&mut self, cosigner: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.cosigner = cosigner;
+        self
+    }
+    /// `[optional account]`
+    /// The owner when not passed in.
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.destination = destination;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(&mut self, program: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.program = program;
+        self
+    }
     #[inline(always)]
     pub fn lamports(&mut self, lamports: u64) -> &mut Self {
         self.lamports = Some(lamports);
@@ -162,6 +252,10 @@ impl WithdrawMarginAccountBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            cosigner: self.cosigner,
+            destination: self.destination,
+            event_authority: self.event_authority,
+            program: self.program,
         };
         let args = WithdrawMarginAccountInstructionArgs {
             lamports: self.lamports.clone().expect("lamports is not set"),
@@ -180,6 +274,14 @@ pub struct WithdrawMarginAccountCpiAccounts<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required once the amount exceeds the cosign threshold.
+    pub cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The owner when not passed in.
+    pub destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `withdraw_margin_account` CPI instruction.
@@ -194,6 +296,14 @@ pub struct WithdrawMarginAccountCpi<'a, 'b> {
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required once the amount exceeds the cosign threshold.
+    pub cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The owner when not passed in.
+    pub destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: WithdrawMarginAccountInstructionArgs,
 }
@@ -210,6 +320,10 @@ impl<'a, 'b> WithdrawMarginAccountCpi<'a, 'b> {
             margin_account: accounts.margin_account,
             owner: accounts.owner,
             system_program: accounts.system_program,
+            cosigner: accounts.cosigner,
+            destination: accounts.destination,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -246,7 +360,7 @@ impl<'a, 'b> WithdrawMarginAccountCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.tswap.key,
             false,
@@ -263,6 +377,50 @@ impl<'a, 'b> WithdrawMarginAccountCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(cosigner) = self.cosigner {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *cosigner.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *event_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -279,12 +437,24 @@ impl<'a, 'b> WithdrawMarginAccountCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.tswap.clone());
         account_infos.push(self.margin_account.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(cosigner) = self.cosigner {
+            account_infos.push(cosigner.clone());
+        }
+        if let Some(destination) = self.destination {
+            account_infos.push(destination.clone());
+        }
+        if let Some(event_authority) = self.event_authority {
+            account_infos.push(event_authority.clone());
+        }
+        if let Some(program) = self.program {
+            account_infos.push(program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -305,6 +475,10 @@ impl<'a, 'b> WithdrawMarginAccountCpi<'a, 'b> {
 ///   1. `[writable]` margin_account
 ///   2. `[writable, signer]` owner
 ///   3. `[]` system_program
+///   4. `[signer, optional]` cosigner
+///   5. `[writable, optional]` destination
+///   6. `[optional]` event_authority
+///   7. `[optional]` program
 #[derive(Clone, Debug)]
 pub struct WithdrawMarginAccountCpiBuilder<'a, 'b> {
     instruction: Box<WithdrawMarginAccountCpiBuilderInstruction<'a, 'b>>,
@@ -318,6 +492,10 @@ impl<'a, 'b> WithdrawMarginAccountCpiBuilder<'a, 'b> {
             margin_account: None,
             owner: None,
             system_program: None,
+            cosigner: None,
+            destination: None,
+            event_authority: None,
+            program: None,
             lamports: None,
             __remaining_accounts: Vec::new(),
         });
@@ -349,6 +527,44 @@ impl<'a, 'b> WithdrawMarginAccountCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Required once the amount exceeds the cosign threshold.
+    #[inline(always)]
+    pub fn cosigner(
+        &mut self,
+        cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cosigner = cosigner;
+        self
+    }
+    /// `[optional account]`
+    /// The owner when not passed in.
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.destination = destination;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.program = program;
+        self
+    }
     #[inline(always)]
     pub fn lamports(&mut self, lamports: u64) -> &mut Self {
         self.instruction.lamports = Some(lamports);
@@ -418,6 +634,14 @@ impl<'a, 'b> WithdrawMarginAccountCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            cosigner: self.instruction.cosigner,
+
+            destination: self.instruction.destination,
+
+            event_authority: self.instruction.event_authority,
+
+            program: self.instruction.program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -434,6 +658,10 @@ struct WithdrawMarginAccountCpiBuilderInstruction<'a, 'b> {
     margin_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cosigner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     lamports: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
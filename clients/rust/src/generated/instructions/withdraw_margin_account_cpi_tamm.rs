@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::WithdrawMode;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -20,6 +21,18 @@ pub struct WithdrawMarginAccountCpiTamm {
     pub destination: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub tswap: solana_program::pubkey::Pubkey,
+
+    pub fee_vault: Option<solana_program::pubkey::Pubkey>,
+
+    pub fee_exemption: Option<solana_program::pubkey::Pubkey>,
+
+    pub reservation: Option<solana_program::pubkey::Pubkey>,
+
+    pub event_authority: Option<solana_program::pubkey::Pubkey>,
+
+    pub program: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl WithdrawMarginAccountCpiTamm {
@@ -35,7 +48,7 @@ impl WithdrawMarginAccountCpiTamm {
         args: WithdrawMarginAccountCpiTammInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.margin_account,
             false,
@@ -54,6 +67,62 @@ impl WithdrawMarginAccountCpiTamm {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tswap, false,
+        ));
+        if let Some(fee_vault) = self.fee_vault {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                fee_vault, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(fee_exemption) = self.fee_exemption {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                fee_exemption,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(reservation) = self.reservation {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                reservation,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                event_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                program, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&WithdrawMarginAccountCpiTammInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -93,6 +162,7 @@ pub struct WithdrawMarginAccountCpiTammInstructionArgs {
     pub bump: u8,
     pub pool_id: [u8; 32],
     pub lamports: u64,
+    pub mode: WithdrawMode,
 }
 
 /// Instruction builder for `WithdrawMarginAccountCpiTamm`.
@@ -104,6 +174,12 @@ pub struct WithdrawMarginAccountCpiTammInstructionArgs {
 ///   2. `[]` owner
 ///   3. `[writable]` destination
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[writable]` tswap
+///   6. `[writable, optional]` fee_vault
+///   7. `[optional]` fee_exemption
+///   8. `[writable, optional]` reservation
+///   9. `[optional]` event_authority
+///   10. `[optional]` program
 #[derive(Clone, Debug, Default)]
 pub struct WithdrawMarginAccountCpiTammBuilder {
     margin_account: Option<solana_program::pubkey::Pubkey>,
@@ -111,9 +187,16 @@ pub struct WithdrawMarginAccountCpiTammBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
     destination: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    tswap: Option<solana_program::pubkey::Pubkey>,
+    fee_vault: Option<solana_program::pubkey::Pubkey>,
+    fee_exemption: Option<solana_program::pubkey::Pubkey>,
+    reservation: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     bump: Option<u8>,
     pool_id: Option<[u8; 32]>,
     lamports: Option<u64>,
+    mode: Option<WithdrawMode>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -148,6 +231,50 @@ impl WithdrawMarginAccountCpiTammBuilder {
         self
     }
     #[inline(always)]
+    pub fn tswap(&mut self, tswap: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tswap = Some(tswap);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_vault(&mut self, fee_vault: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.fee_vault = fee_vault;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_exemption(
+        &mut self,
+        fee_exemption: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_exemption = fee_exemption;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn reservation(
+        &mut self,
+        reservation: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.reservation = reservation;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(&mut self, program: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.program = program;
+        self
+    }
+    #[inline(always)]
     pub fn bump(&mut self, bump: u8) -> &mut Self {
         self.bump = Some(bump);
         self
@@ -162,6 +289,12 @@ impl WithdrawMarginAccountCpiTammBuilder {
         self.lamports = Some(lamports);
         self
     }
+    /// `[optional argument, defaults to 'WithdrawMode::Exact']`
+    #[inline(always)]
+    pub fn mode(&mut self, mode: WithdrawMode) -> &mut Self {
+        self.mode = Some(mode);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -190,11 +323,18 @@ impl WithdrawMarginAccountCpiTammBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            tswap: self.tswap.expect("tswap is not set"),
+            fee_vault: self.fee_vault,
+            fee_exemption: self.fee_exemption,
+            reservation: self.reservation,
+            event_authority: self.event_authority,
+            program: self.program,
         };
         let args = WithdrawMarginAccountCpiTammInstructionArgs {
             bump: self.bump.clone().expect("bump is not set"),
             pool_id: self.pool_id.clone().expect("pool_id is not set"),
             lamports: self.lamports.clone().expect("lamports is not set"),
+            mode: self.mode.clone().unwrap_or(WithdrawMode::Exact),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -212,6 +352,18 @@ pub struct WithdrawMarginAccountCpiTammCpiAccounts<'a, 'b> {
     pub destination: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tswap: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub fee_exemption: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub reservation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `withdraw_margin_account_cpi_tamm` CPI instruction.
@@ -228,6 +380,18 @@ pub struct WithdrawMarginAccountCpiTammCpi<'a, 'b> {
     pub destination: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tswap: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub fee_exemption: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub reservation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: WithdrawMarginAccountCpiTammInstructionArgs,
 }
@@ -245,6 +409,12 @@ impl<'a, 'b> WithdrawMarginAccountCpiTammCpi<'a, 'b> {
             owner: accounts.owner,
             destination: accounts.destination,
             system_program: accounts.system_program,
+            tswap: accounts.tswap,
+            fee_vault: accounts.fee_vault,
+            fee_exemption: accounts.fee_exemption,
+            reservation: accounts.reservation,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -281,7 +451,7 @@ impl<'a, 'b> WithdrawMarginAccountCpiTammCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.margin_account.key,
             false,
@@ -302,6 +472,65 @@ impl<'a, 'b> WithdrawMarginAccountCpiTammCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tswap.key,
+            false,
+        ));
+        if let Some(fee_vault) = self.fee_vault {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *fee_vault.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(fee_exemption) = self.fee_exemption {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *fee_exemption.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(reservation) = self.reservation {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *reservation.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *event_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -318,13 +547,29 @@ impl<'a, 'b> WithdrawMarginAccountCpiTammCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.margin_account.clone());
         account_infos.push(self.pool.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.destination.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.tswap.clone());
+        if let Some(fee_vault) = self.fee_vault {
+            account_infos.push(fee_vault.clone());
+        }
+        if let Some(fee_exemption) = self.fee_exemption {
+            account_infos.push(fee_exemption.clone());
+        }
+        if let Some(reservation) = self.reservation {
+            account_infos.push(reservation.clone());
+        }
+        if let Some(event_authority) = self.event_authority {
+            account_infos.push(event_authority.clone());
+        }
+        if let Some(program) = self.program {
+            account_infos.push(program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -346,6 +591,12 @@ impl<'a, 'b> WithdrawMarginAccountCpiTammCpi<'a, 'b> {
 ///   2. `[]` owner
 ///   3. `[writable]` destination
 ///   4. `[]` system_program
+///   5. `[writable]` tswap
+///   6. `[writable, optional]` fee_vault
+///   7. `[optional]` fee_exemption
+///   8. `[writable, optional]` reservation
+///   9. `[optional]` event_authority
+///   10. `[optional]` program
 #[derive(Clone, Debug)]
 pub struct WithdrawMarginAccountCpiTammCpiBuilder<'a, 'b> {
     instruction: Box<WithdrawMarginAccountCpiTammCpiBuilderInstruction<'a, 'b>>,
@@ -360,9 +611,16 @@ impl<'a, 'b> WithdrawMarginAccountCpiTammCpiBuilder<'a, 'b> {
             owner: None,
             destination: None,
             system_program: None,
+            tswap: None,
+            fee_vault: None,
+            fee_exemption: None,
+            reservation: None,
+            event_authority: None,
+            program: None,
             bump: None,
             pool_id: None,
             lamports: None,
+            mode: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -402,6 +660,56 @@ impl<'a, 'b> WithdrawMarginAccountCpiTammCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn tswap(&mut self, tswap: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.tswap = Some(tswap);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_vault(
+        &mut self,
+        fee_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_vault = fee_vault;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_exemption(
+        &mut self,
+        fee_exemption: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_exemption = fee_exemption;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn reservation(
+        &mut self,
+        reservation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.reservation = reservation;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.program = program;
+        self
+    }
+    #[inline(always)]
     pub fn bump(&mut self, bump: u8) -> &mut Self {
         self.instruction.bump = Some(bump);
         self
@@ -416,6 +724,12 @@ impl<'a, 'b> WithdrawMarginAccountCpiTammCpiBuilder<'a, 'b> {
         self.instruction.lamports = Some(lamports);
         self
     }
+    /// `[optional argument, defaults to 'WithdrawMode::Exact']`
+    #[inline(always)]
+    pub fn mode(&mut self, mode: WithdrawMode) -> &mut Self {
+        self.instruction.mode = Some(mode);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -469,6 +783,7 @@ impl<'a, 'b> WithdrawMarginAccountCpiTammCpiBuilder<'a, 'b> {
                 .lamports
                 .clone()
                 .expect("lamports is not set"),
+            mode: self.instruction.mode.clone().unwrap_or(WithdrawMode::Exact),
         };
         let instruction = WithdrawMarginAccountCpiTammCpi {
             __program: self.instruction.__program,
@@ -491,6 +806,18 @@ impl<'a, 'b> WithdrawMarginAccountCpiTammCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            tswap: self.instruction.tswap.expect("tswap is not set"),
+
+            fee_vault: self.instruction.fee_vault,
+
+            fee_exemption: self.instruction.fee_exemption,
+
+            reservation: self.instruction.reservation,
+
+            event_authority: self.instruction.event_authority,
+
+            program: self.instruction.program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -508,9 +835,16 @@ struct WithdrawMarginAccountCpiTammCpiBuilderInstruction<'a, 'b> {
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tswap: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_exemption: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reservation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bump: Option<u8>,
     pool_id: Option<[u8; 32]>,
     lamports: Option<u64>,
+    mode: Option<WithdrawMode>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::WithdrawMode;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -21,6 +22,18 @@ pub struct WithdrawMarginAccountCpiTcomp {
     pub destination: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub tswap: solana_program::pubkey::Pubkey,
+
+    pub fee_vault: Option<solana_program::pubkey::Pubkey>,
+
+    pub fee_exemption: Option<solana_program::pubkey::Pubkey>,
+
+    pub reservation: Option<solana_program::pubkey::Pubkey>,
+
+    pub event_authority: Option<solana_program::pubkey::Pubkey>,
+
+    pub program: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl WithdrawMarginAccountCpiTcomp {
@@ -36,7 +49,7 @@ impl WithdrawMarginAccountCpiTcomp {
         args: WithdrawMarginAccountCpiTcompInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.margin_account,
             false,
@@ -56,6 +69,62 @@ impl WithdrawMarginAccountCpiTcomp {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tswap, false,
+        ));
+        if let Some(fee_vault) = self.fee_vault {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                fee_vault, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(fee_exemption) = self.fee_exemption {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                fee_exemption,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(reservation) = self.reservation {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                reservation,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                event_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                program, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&WithdrawMarginAccountCpiTcompInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -95,6 +164,7 @@ pub struct WithdrawMarginAccountCpiTcompInstructionArgs {
     pub bump: u8,
     pub bid_id: Pubkey,
     pub lamports: u64,
+    pub mode: WithdrawMode,
 }
 
 /// Instruction builder for `WithdrawMarginAccountCpiTcomp`.
@@ -106,6 +176,12 @@ pub struct WithdrawMarginAccountCpiTcompInstructionArgs {
 ///   2. `[]` owner
 ///   3. `[writable]` destination
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[writable]` tswap
+///   6. `[writable, optional]` fee_vault
+///   7. `[optional]` fee_exemption
+///   8. `[writable, optional]` reservation
+///   9. `[optional]` event_authority
+///   10. `[optional]` program
 #[derive(Clone, Debug, Default)]
 pub struct WithdrawMarginAccountCpiTcompBuilder {
     margin_account: Option<solana_program::pubkey::Pubkey>,
@@ -113,9 +189,16 @@ pub struct WithdrawMarginAccountCpiTcompBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
     destination: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    tswap: Option<solana_program::pubkey::Pubkey>,
+    fee_vault: Option<solana_program::pubkey::Pubkey>,
+    fee_exemption: Option<solana_program::pubkey::Pubkey>,
+    reservation: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     bump: Option<u8>,
     bid_id: Option<Pubkey>,
     lamports: Option<u64>,
+    mode: Option<WithdrawMode>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -150,6 +233,50 @@ impl WithdrawMarginAccountCpiTcompBuilder {
         self
     }
     #[inline(always)]
+    pub fn tswap(&mut self, tswap: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tswap = Some(tswap);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_vault(&mut self, fee_vault: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.fee_vault = fee_vault;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_exemption(
+        &mut self,
+        fee_exemption: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_exemption = fee_exemption;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn reservation(
+        &mut self,
+        reservation: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.reservation = reservation;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(&mut self, program: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.program = program;
+        self
+    }
+    #[inline(always)]
     pub fn bump(&mut self, bump: u8) -> &mut Self {
         self.bump = Some(bump);
         self
@@ -164,6 +291,12 @@ impl WithdrawMarginAccountCpiTcompBuilder {
         self.lamports = Some(lamports);
         self
     }
+    /// `[optional argument, defaults to 'WithdrawMode::Exact']`
+    #[inline(always)]
+    pub fn mode(&mut self, mode: WithdrawMode) -> &mut Self {
+        self.mode = Some(mode);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -192,11 +325,18 @@ impl WithdrawMarginAccountCpiTcompBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            tswap: self.tswap.expect("tswap is not set"),
+            fee_vault: self.fee_vault,
+            fee_exemption: self.fee_exemption,
+            reservation: self.reservation,
+            event_authority: self.event_authority,
+            program: self.program,
         };
         let args = WithdrawMarginAccountCpiTcompInstructionArgs {
             bump: self.bump.clone().expect("bump is not set"),
             bid_id: self.bid_id.clone().expect("bid_id is not set"),
             lamports: self.lamports.clone().expect("lamports is not set"),
+            mode: self.mode.clone().unwrap_or(WithdrawMode::Exact),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -214,6 +354,18 @@ pub struct WithdrawMarginAccountCpiTcompCpiAccounts<'a, 'b> {
     pub destination: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tswap: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub fee_exemption: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub reservation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `withdraw_margin_account_cpi_tcomp` CPI instruction.
@@ -230,6 +382,18 @@ pub struct WithdrawMarginAccountCpiTcompCpi<'a, 'b> {
     pub destination: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tswap: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub fee_exemption: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub reservation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: WithdrawMarginAccountCpiTcompInstructionArgs,
 }
@@ -247,6 +411,12 @@ impl<'a, 'b> WithdrawMarginAccountCpiTcompCpi<'a, 'b> {
             owner: accounts.owner,
             destination: accounts.destination,
             system_program: accounts.system_program,
+            tswap: accounts.tswap,
+            fee_vault: accounts.fee_vault,
+            fee_exemption: accounts.fee_exemption,
+            reservation: accounts.reservation,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -283,7 +453,7 @@ impl<'a, 'b> WithdrawMarginAccountCpiTcompCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.margin_account.key,
             false,
@@ -304,6 +474,65 @@ impl<'a, 'b> WithdrawMarginAccountCpiTcompCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tswap.key,
+            false,
+        ));
+        if let Some(fee_vault) = self.fee_vault {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *fee_vault.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(fee_exemption) = self.fee_exemption {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *fee_exemption.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(reservation) = self.reservation {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *reservation.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(event_authority) = self.event_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *event_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
+        if let Some(program) = self.program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::TENSOR_ESCROW_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -320,13 +549,29 @@ impl<'a, 'b> WithdrawMarginAccountCpiTcompCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.margin_account.clone());
         account_infos.push(self.bid_state.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.destination.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.tswap.clone());
+        if let Some(fee_vault) = self.fee_vault {
+            account_infos.push(fee_vault.clone());
+        }
+        if let Some(fee_exemption) = self.fee_exemption {
+            account_infos.push(fee_exemption.clone());
+        }
+        if let Some(reservation) = self.reservation {
+            account_infos.push(reservation.clone());
+        }
+        if let Some(event_authority) = self.event_authority {
+            account_infos.push(event_authority.clone());
+        }
+        if let Some(program) = self.program {
+            account_infos.push(program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -348,6 +593,12 @@ impl<'a, 'b> WithdrawMarginAccountCpiTcompCpi<'a, 'b> {
 ///   2. `[]` owner
 ///   3. `[writable]` destination
 ///   4. `[]` system_program
+///   5. `[writable]` tswap
+///   6. `[writable, optional]` fee_vault
+///   7. `[optional]` fee_exemption
+///   8. `[writable, optional]` reservation
+///   9. `[optional]` event_authority
+///   10. `[optional]` program
 #[derive(Clone, Debug)]
 pub struct WithdrawMarginAccountCpiTcompCpiBuilder<'a, 'b> {
     instruction: Box<WithdrawMarginAccountCpiTcompCpiBuilderInstruction<'a, 'b>>,
@@ -362,9 +613,16 @@ impl<'a, 'b> WithdrawMarginAccountCpiTcompCpiBuilder<'a, 'b> {
             owner: None,
             destination: None,
             system_program: None,
+            tswap: None,
+            fee_vault: None,
+            fee_exemption: None,
+            reservation: None,
+            event_authority: None,
+            program: None,
             bump: None,
             bid_id: None,
             lamports: None,
+            mode: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -407,6 +665,56 @@ impl<'a, 'b> WithdrawMarginAccountCpiTcompCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn tswap(&mut self, tswap: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.tswap = Some(tswap);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_vault(
+        &mut self,
+        fee_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_vault = fee_vault;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn fee_exemption(
+        &mut self,
+        fee_exemption: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_exemption = fee_exemption;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn reservation(
+        &mut self,
+        reservation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.reservation = reservation;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.event_authority = event_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.program = program;
+        self
+    }
+    #[inline(always)]
     pub fn bump(&mut self, bump: u8) -> &mut Self {
         self.instruction.bump = Some(bump);
         self
@@ -421,6 +729,12 @@ impl<'a, 'b> WithdrawMarginAccountCpiTcompCpiBuilder<'a, 'b> {
         self.instruction.lamports = Some(lamports);
         self
     }
+    /// `[optional argument, defaults to 'WithdrawMode::Exact']`
+    #[inline(always)]
+    pub fn mode(&mut self, mode: WithdrawMode) -> &mut Self {
+        self.instruction.mode = Some(mode);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -470,6 +784,7 @@ impl<'a, 'b> WithdrawMarginAccountCpiTcompCpiBuilder<'a, 'b> {
                 .lamports
                 .clone()
                 .expect("lamports is not set"),
+            mode: self.instruction.mode.clone().unwrap_or(WithdrawMode::Exact),
         };
         let instruction = WithdrawMarginAccountCpiTcompCpi {
             __program: self.instruction.__program,
@@ -492,6 +807,18 @@ impl<'a, 'b> WithdrawMarginAccountCpiTcompCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            tswap: self.instruction.tswap.expect("tswap is not set"),
+
+            fee_vault: self.instruction.fee_vault,
+
+            fee_exemption: self.instruction.fee_exemption,
+
+            reservation: self.instruction.reservation,
+
+            event_authority: self.instruction.event_authority,
+
+            program: self.instruction.program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -509,9 +836,16 @@ struct WithdrawMarginAccountCpiTcompCpiBuilderInstruction<'a, 'b> {
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tswap: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_exemption: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reservation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bump: Option<u8>,
     bid_id: Option<Pubkey>,
     lamports: Option<u64>,
+    mode: Option<WithdrawMode>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
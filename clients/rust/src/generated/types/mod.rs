@@ -5,6 +5,14 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#caller;
+pub(crate) mod r#cpi_return_data;
+pub(crate) mod r#rate_limit;
 pub(crate) mod r#t_swap_config;
+pub(crate) mod r#withdraw_mode;
 
+pub use self::r#caller::*;
+pub use self::r#cpi_return_data::*;
+pub use self::r#rate_limit::*;
 pub use self::r#t_swap_config::*;
+pub use self::r#withdraw_mode::*;
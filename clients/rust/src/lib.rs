@@ -1,4 +1,10 @@
+mod events;
 mod generated;
+mod margin;
+mod return_data;
 
+pub use events::*;
 pub use generated::programs::TENSOR_ESCROW_ID as ID;
 pub use generated::*;
+pub use margin::*;
+pub use return_data::*;
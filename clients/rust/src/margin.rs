@@ -0,0 +1,125 @@
+//! Helpers to decode margin accounts regardless of their on-chain layout.
+
+use solana_program::pubkey::Pubkey;
+
+use crate::accounts::{MarginAccount, MarginAccountV2};
+
+/// Discriminator shared by every margin account layout.
+pub const MARGIN_ACCOUNT_DISCRIMINATOR: [u8; 8] = [133, 220, 173, 213, 179, 211, 43, 238];
+
+/// Address of a margin account derived from `[b"margin", owner, margin_nr]`.
+pub fn find_margin_account_v2_pda(owner: &Pubkey, margin_nr: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"margin", owner.as_ref(), &margin_nr.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// Address of a margin account derived from `[b"margin", tswap, owner, margin_nr]`.
+///
+/// `margin_nr` is seeded as little-endian bytes, the same way the program does.
+pub fn find_legacy_margin_account_pda(
+    tswap: &Pubkey,
+    owner: &Pubkey,
+    margin_nr: u16,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"margin",
+            tswap.as_ref(),
+            owner.as_ref(),
+            &margin_nr.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// A margin account decoded from either the V1 (143 bytes) or the V2 layout.
+///
+/// Both layouts share the same discriminator, the version is told apart by the
+/// account size.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionedMarginAccount {
+    V1(MarginAccount),
+    V2(MarginAccountV2),
+}
+
+impl VersionedMarginAccount {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        if data.len() < MarginAccount::LEN || data[..8] != MARGIN_ACCOUNT_DISCRIMINATOR {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "not a margin account",
+            ));
+        }
+
+        if data.len() >= MarginAccountV2::LEN {
+            Ok(Self::V2(MarginAccountV2::from_bytes(data)?))
+        } else {
+            Ok(Self::V1(MarginAccount::from_bytes(data)?))
+        }
+    }
+
+    /// Layout version: V1 accounts predate the version field.
+    pub fn version(&self) -> u8 {
+        match self {
+            Self::V1(_) => 1,
+            Self::V2(account) => account.version,
+        }
+    }
+
+    pub fn owner(&self) -> Pubkey {
+        match self {
+            Self::V1(account) => account.owner,
+            Self::V2(account) => account.owner,
+        }
+    }
+
+    pub fn name(&self) -> [u8; 32] {
+        match self {
+            Self::V1(account) => account.name,
+            Self::V2(account) => account.name,
+        }
+    }
+
+    pub fn nr(&self) -> u16 {
+        match self {
+            Self::V1(account) => account.nr,
+            Self::V2(account) => account.nr,
+        }
+    }
+
+    pub fn bump(&self) -> u8 {
+        match self {
+            Self::V1(account) => account.bump[0],
+            Self::V2(account) => account.bump[0],
+        }
+    }
+
+    /// Number of attached TAMM pools, `None` for V1 accounts which do not track them.
+    pub fn pools_attached(&self) -> Option<u32> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(account) => Some(account.pools_attached),
+        }
+    }
+
+    /// Number of attached TCOMP bids, `None` for V1 accounts which do not track them.
+    pub fn bids_attached(&self) -> Option<u32> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(account) => Some(account.bids_attached),
+        }
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for VersionedMarginAccount {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let data: &[u8] = &(*account_info.data).borrow();
+        Self::from_bytes(data)
+    }
+}
@@ -0,0 +1,39 @@
+//! Decoder for the return data set by the program's CPI entrypoints.
+
+use borsh::BorshDeserialize;
+use solana_program::program::get_return_data;
+
+use crate::types::CpiReturnData;
+
+/// Borsh size of `CpiReturnData`.
+pub const CPI_RETURN_DATA_LEN: usize = 24;
+
+impl CpiReturnData {
+    /// Decodes the return data bytes. The runtime may strip trailing zero bytes, so
+    /// shorter buffers are zero-padded first.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        if data.len() > CPI_RETURN_DATA_LEN {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "return data too long",
+            ));
+        }
+
+        let mut buf = [0u8; CPI_RETURN_DATA_LEN];
+        buf[..data.len()].copy_from_slice(data);
+        Self::deserialize(&mut buf.as_slice())
+    }
+}
+
+/// Return data of the escrow instruction just invoked, `None` when the escrow program
+/// didn't set the last return data.
+///
+/// Call it right after the CPI: any other CPI in between replaces the return data.
+pub fn get_cpi_return_data() -> Option<CpiReturnData> {
+    let (program_id, data) = get_return_data()?;
+    if program_id != crate::ID {
+        return None;
+    }
+
+    CpiReturnData::from_bytes(&data).ok()
+}
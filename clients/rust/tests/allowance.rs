@@ -0,0 +1,218 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use borsh::BorshSerialize;
+use common::*;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::Allowance,
+    errors::TensorEscrowError,
+    find_legacy_margin_account_pda,
+    instructions::{
+        ApproveAllowanceBuilder, CloseMarginAccountBuilder, DepositMarginAccountBuilder,
+        InitMarginAccountBuilder, SpendAllowanceBuilder,
+    },
+};
+
+const ALLOWANCE_DISCRIMINATOR: [u8; 8] = [144, 8, 184, 213, 49, 248, 73, 131];
+
+const GENERATION: u64 = 42;
+/// Slot margin accounts are closed in, after they were created.
+const CLOSE_SLOT: u64 = 100;
+const AMOUNT: u64 = 100_000_000;
+
+struct Setup {
+    program_test: ProgramTest,
+    tswap: Pubkey,
+    owner: Keypair,
+    delegate: Keypair,
+    margin_account: Pubkey,
+}
+
+/// Starts the program with a V2 margin account of `GENERATION`, the delegate is funded
+/// as the fee payer.
+fn setup() -> Setup {
+    let owner = Keypair::new();
+    let delegate = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    program_test.add_account(delegate.pubkey(), system_account(LAMPORTS));
+
+    let margin_account = add_margin_v2(&mut program_test, &owner.pubkey(), 0, |margin| {
+        margin.generation = GENERATION;
+    });
+
+    Setup {
+        program_test,
+        tswap,
+        owner,
+        delegate,
+        margin_account,
+    }
+}
+
+impl Setup {
+    /// Adds an allowance of `AMOUNT` granted on `margin_generation`.
+    fn add_allowance(&mut self, margin_generation: u64) -> Pubkey {
+        let (allowance, bump) = Allowance::find_pda(&self.margin_account, &self.delegate.pubkey());
+        let data = Allowance {
+            discriminator: ALLOWANCE_DISCRIMINATOR,
+            version: 1,
+            bump: [bump],
+            margin_account: self.margin_account,
+            delegate: self.delegate.pubkey(),
+            amount: AMOUNT,
+            expires_at: None,
+            owner: self.owner.pubkey(),
+            margin_generation,
+            reserved: [0; 24],
+        };
+        self.program_test.add_account(
+            allowance,
+            program_account(data.try_to_vec().unwrap(), LAMPORTS),
+        );
+        allowance
+    }
+}
+
+fn spend(setup: &Setup, allowance: Pubkey, destination: Pubkey) -> Instruction {
+    SpendAllowanceBuilder::new()
+        .margin_account(setup.margin_account)
+        .allowance(allowance)
+        .delegate(setup.delegate.pubkey())
+        .owner(setup.owner.pubkey())
+        .destination(destination)
+        .tswap(setup.tswap)
+        .lamports(AMOUNT)
+        .instruction()
+}
+
+#[tokio::test]
+async fn approve_allowance_binds_the_margin_generation() {
+    let setup = setup();
+    let mut context = setup.program_test.start_with_context().await;
+
+    let (allowance, _) = Allowance::find_pda(&setup.margin_account, &setup.delegate.pubkey());
+    let ix = ApproveAllowanceBuilder::new()
+        .margin_account(setup.margin_account)
+        .allowance(allowance)
+        .delegate(setup.delegate.pubkey())
+        .owner(setup.owner.pubkey())
+        .amount(AMOUNT)
+        .instruction();
+    send(&mut context, &[ix], &setup.owner, &[]).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(allowance)
+        .await
+        .unwrap()
+        .unwrap();
+    let allowance = Allowance::from_bytes(&account.data).unwrap();
+    assert_eq!(allowance.margin_generation, GENERATION);
+    assert_eq!(allowance.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn spend_allowance_of_the_same_generation() {
+    let mut setup = setup();
+    let allowance = setup.add_allowance(GENERATION);
+    let destination = Pubkey::new_unique();
+    let ix = spend(&setup, allowance, destination);
+
+    let mut context = setup.program_test.start_with_context().await;
+    send(&mut context, &[ix], &setup.delegate, &[])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        LAMPORTS - AMOUNT
+    );
+    assert_eq!(balance(&mut context, destination).await, AMOUNT);
+}
+
+#[tokio::test]
+async fn spend_allowance_of_a_closed_margin_account_fails() {
+    let mut setup = setup();
+    // Granted on the margin account previously at this address.
+    let allowance = setup.add_allowance(GENERATION - 1);
+    let ix = spend(&setup, allowance, Pubkey::new_unique());
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = send(&mut context, &[ix], &setup.delegate, &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::StaleGrant as u32);
+    assert_eq!(balance(&mut context, setup.margin_account).await, LAMPORTS);
+}
+
+#[tokio::test]
+async fn spend_allowance_after_the_margin_account_is_recreated_fails() {
+    let owner = Keypair::new();
+    let delegate = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    program_test.add_account(delegate.pubkey(), system_account(LAMPORTS));
+
+    let (margin_account, _) = find_legacy_margin_account_pda(&tswap, &owner.pubkey(), 0);
+    let (allowance, _) = Allowance::find_pda(&margin_account, &delegate.pubkey());
+    let init_ixs = [
+        InitMarginAccountBuilder::new()
+            .tswap(tswap)
+            .margin_account(margin_account)
+            .owner(owner.pubkey())
+            .margin_nr(0)
+            .instruction(),
+        DepositMarginAccountBuilder::new()
+            .tswap(tswap)
+            .margin_account(margin_account)
+            .owner(owner.pubkey())
+            .lamports(AMOUNT)
+            .instruction(),
+    ];
+    let approve_ix = ApproveAllowanceBuilder::new()
+        .margin_account(margin_account)
+        .allowance(allowance)
+        .delegate(delegate.pubkey())
+        .owner(owner.pubkey())
+        .amount(AMOUNT)
+        .instruction();
+    let close_ix = CloseMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .instruction();
+    let spend_ix = SpendAllowanceBuilder::new()
+        .margin_account(margin_account)
+        .allowance(allowance)
+        .delegate(delegate.pubkey())
+        .owner(owner.pubkey())
+        .destination(delegate.pubkey())
+        .tswap(tswap)
+        .lamports(AMOUNT)
+        .instruction();
+
+    let mut context = program_test.start_with_context().await;
+    let mut ixs = init_ixs.to_vec();
+    ixs.push(approve_ix);
+    send(&mut context, &ixs, &owner, &[]).await.unwrap();
+
+    // The allowance outlives the margin account it was granted on
+    context.warp_to_slot(CLOSE_SLOT).unwrap();
+    send(&mut context, &[close_ix], &owner, &[]).await.unwrap();
+    context.warp_to_slot(CLOSE_SLOT + 1).unwrap();
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    send(&mut context, &init_ixs, &owner, &[]).await.unwrap();
+
+    let err = send(&mut context, &[spend_ix], &delegate, &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::StaleGrant as u32);
+}
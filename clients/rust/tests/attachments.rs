@@ -0,0 +1,299 @@
+#![cfg(feature = "test-sbf")]
+
+//! Pools and bids attaching themselves to margin accounts, called through the stand-in callers.
+
+mod common;
+
+use borsh::BorshSerialize;
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::{Attachment, MarginAccountV2},
+    find_margin_account_v2_pda,
+    instructions::{
+        AttachMarginAccountCpiTammBuilder, AttachMarginAccountCpiTcompBuilder,
+        DetachMarginAccountCpiTammBuilder, DetachMarginAccountCpiTcompBuilder,
+    },
+    types::Caller,
+};
+
+// anchor_lang::error::ErrorCode::ConstraintHasOne
+const CONSTRAINT_HAS_ONE: u32 = 2001;
+// tensor_vipers::VipersError::IntegerOverflow
+const INTEGER_OVERFLOW: u32 = 1103;
+
+const ATTACHMENT_DISCRIMINATOR: [u8; 8] = [255, 150, 79, 28, 206, 74, 113, 70];
+
+struct Setup {
+    program_test: ProgramTest,
+    owner: Keypair,
+    tswap: Pubkey,
+    /// Margin account 0 of the owner, the tests create it once the program runs.
+    margin_account: Pubkey,
+}
+
+/// Starts the program with a V1 TSwap.
+fn setup() -> Setup {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    add_callers(&mut program_test);
+
+    let (margin_account, _) = find_margin_account_v2_pda(&owner.pubkey(), 0);
+
+    Setup {
+        program_test,
+        owner,
+        tswap,
+        margin_account,
+    }
+}
+
+impl Setup {
+    /// A pool of `owner` attached to the margin account.
+    fn add_pool(&mut self, owner: Pubkey) -> (Pubkey, u8, [u8; 32]) {
+        add_pool(
+            &mut self.program_test,
+            owner,
+            Pubkey::default(),
+            self.margin_account,
+        )
+    }
+
+    /// A bid of `owner` attached to the margin account.
+    fn add_bid(&mut self, owner: Pubkey) -> (Pubkey, u8, Pubkey) {
+        let bid = Bid {
+            quantity: 1,
+            filled_quantity: 0,
+            currency: None,
+            margin: self.margin_account,
+        };
+        add_bid(&mut self.program_test, owner, bid)
+    }
+
+    async fn start(self) -> (ProgramTestContext, Keypair, Pubkey) {
+        let mut context = self.program_test.start_with_context().await;
+        init_margin_account(&mut context, self.tswap, &self.owner, 0, 0)
+            .await
+            .unwrap();
+        (context, self.owner, self.margin_account)
+    }
+}
+
+fn attach_pool(
+    margin_account: Pubkey,
+    owner: Pubkey,
+    payer: Pubkey,
+    pool: (Pubkey, u8, [u8; 32]),
+) -> Instruction {
+    let (pool, bump, pool_id) = pool;
+    AttachMarginAccountCpiTammBuilder::new()
+        .margin_account(margin_account)
+        .pool(pool)
+        .owner(owner)
+        .attachment(Attachment::find_pda(&margin_account, &pool).0)
+        .payer(payer)
+        .bump(bump)
+        .pool_id(pool_id)
+        .instruction()
+}
+
+fn detach_pool(
+    margin_account: Pubkey,
+    owner: Pubkey,
+    rent_payer: Pubkey,
+    pool: (Pubkey, u8, [u8; 32]),
+) -> Instruction {
+    let (pool, bump, pool_id) = pool;
+    DetachMarginAccountCpiTammBuilder::new()
+        .margin_account(margin_account)
+        .pool(pool)
+        .owner(owner)
+        .attachment(Attachment::find_pda(&margin_account, &pool).0)
+        .rent_payer(rent_payer)
+        .bump(bump)
+        .pool_id(pool_id)
+        .instruction()
+}
+
+fn attach_bid(
+    margin_account: Pubkey,
+    owner: Pubkey,
+    payer: Pubkey,
+    bid: (Pubkey, u8, Pubkey),
+) -> Instruction {
+    let (bid_state, bump, bid_id) = bid;
+    AttachMarginAccountCpiTcompBuilder::new()
+        .margin_account(margin_account)
+        .bid_state(bid_state)
+        .owner(owner)
+        .attachment(Attachment::find_pda(&margin_account, &bid_state).0)
+        .payer(payer)
+        .bump(bump)
+        .bid_id(bid_id)
+        .instruction()
+}
+
+fn detach_bid(
+    margin_account: Pubkey,
+    owner: Pubkey,
+    rent_payer: Pubkey,
+    bid: (Pubkey, u8, Pubkey),
+) -> Instruction {
+    let (bid_state, bump, bid_id) = bid;
+    DetachMarginAccountCpiTcompBuilder::new()
+        .margin_account(margin_account)
+        .bid_state(bid_state)
+        .owner(owner)
+        .attachment(Attachment::find_pda(&margin_account, &bid_state).0)
+        .rent_payer(rent_payer)
+        .bump(bump)
+        .bid_id(bid_id)
+        .instruction()
+}
+
+async fn margin(context: &mut ProgramTestContext, margin_account: Pubkey) -> MarginAccountV2 {
+    MarginAccountV2::from_bytes(&account_data(context, margin_account).await).unwrap()
+}
+
+async fn exists(context: &mut ProgramTestContext, address: Pubkey) -> bool {
+    context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .is_some()
+}
+
+#[tokio::test]
+async fn pools_attach_and_detach() {
+    let mut setup = setup();
+    let owner = setup.owner.pubkey();
+    let pool = setup.add_pool(owner);
+    let other_pool = setup.add_pool(owner);
+    let (mut context, owner_keypair, margin_account) = setup.start().await;
+
+    for pool in [pool, other_pool] {
+        let ix = attach_pool(margin_account, owner, owner, pool);
+        call_through(&mut context, &owner_keypair, tamm_id(), ix)
+            .await
+            .unwrap();
+    }
+    assert_eq!(margin(&mut context, margin_account).await.pools_attached, 2);
+    let attachment = Attachment::find_pda(&margin_account, &pool.0).0;
+    let data = Attachment::from_bytes(&account_data(&mut context, attachment).await).unwrap();
+    assert_eq!(data.caller, Caller::TAmm);
+    assert_eq!(data.rent_payer, owner);
+
+    let ix = detach_pool(margin_account, owner, owner, pool);
+    call_through(&mut context, &owner_keypair, tamm_id(), ix)
+        .await
+        .unwrap();
+
+    let margin = margin(&mut context, margin_account).await;
+    assert_eq!(margin.pools_attached, 1);
+    assert_eq!(margin.bids_attached, 0);
+    assert!(!exists(&mut context, attachment).await);
+}
+
+#[tokio::test]
+async fn bids_attach_and_detach() {
+    let mut setup = setup();
+    let owner = setup.owner.pubkey();
+    let bid = setup.add_bid(owner);
+    let (mut context, owner_keypair, margin_account) = setup.start().await;
+
+    let ix = attach_bid(margin_account, owner, owner, bid);
+    call_through(&mut context, &owner_keypair, tcomp_id(), ix)
+        .await
+        .unwrap();
+    assert_eq!(margin(&mut context, margin_account).await.bids_attached, 1);
+
+    let ix = detach_bid(margin_account, owner, owner, bid);
+    call_through(&mut context, &owner_keypair, tcomp_id(), ix)
+        .await
+        .unwrap();
+
+    let margin = margin(&mut context, margin_account).await;
+    assert_eq!(margin.bids_attached, 0);
+    assert_eq!(margin.pools_attached, 0);
+    let attachment = Attachment::find_pda(&margin_account, &bid.0).0;
+    assert!(!exists(&mut context, attachment).await);
+}
+
+#[tokio::test]
+async fn pool_of_another_owner_cannot_attach() {
+    let mut setup = setup();
+    // signs with its own seeds, which name its owner and not the margin account's
+    let attacker = Pubkey::new_unique();
+    let pool = setup.add_pool(attacker);
+    let (mut context, owner_keypair, margin_account) = setup.start().await;
+
+    let ix = attach_pool(margin_account, attacker, owner_keypair.pubkey(), pool);
+    let err = call_through(&mut context, &owner_keypair, tamm_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, CONSTRAINT_HAS_ONE);
+    assert_eq!(margin(&mut context, margin_account).await.pools_attached, 0);
+}
+
+#[tokio::test]
+async fn bid_of_another_owner_cannot_attach() {
+    let mut setup = setup();
+    let attacker = Pubkey::new_unique();
+    let bid = setup.add_bid(attacker);
+    let (mut context, owner_keypair, margin_account) = setup.start().await;
+
+    let ix = attach_bid(margin_account, attacker, owner_keypair.pubkey(), bid);
+    let err = call_through(&mut context, &owner_keypair, tcomp_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, CONSTRAINT_HAS_ONE);
+    assert_eq!(margin(&mut context, margin_account).await.bids_attached, 0);
+}
+
+#[tokio::test]
+async fn detach_below_zero_fails() {
+    // An attachment the counter missed can't be reached through the instructions, so both
+    // are injected.
+    let owner = Keypair::new();
+    let (mut program_test, _) = program_test(&owner);
+    add_callers(&mut program_test);
+    let margin_account = add_margin_v2(&mut program_test, &owner.pubkey(), 0, |_| {});
+    let pool = add_pool(
+        &mut program_test,
+        owner.pubkey(),
+        Pubkey::default(),
+        margin_account,
+    );
+    let (attachment, bump) = Attachment::find_pda(&margin_account, &pool.0);
+    let data = Attachment {
+        discriminator: ATTACHMENT_DISCRIMINATOR,
+        version: 1,
+        bump: [bump],
+        margin_account,
+        caller_account: pool.0,
+        caller: Caller::TAmm,
+        rent_payer: owner.pubkey(),
+        reserved: [0; 32],
+    };
+    program_test.add_account(
+        attachment,
+        program_account(data.try_to_vec().unwrap(), LAMPORTS),
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let ix = detach_pool(margin_account, owner.pubkey(), owner.pubkey(), pool);
+    let err = call_through(&mut context, &owner, tamm_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, INTEGER_OVERFLOW);
+    assert!(exists(&mut context, attachment).await);
+}
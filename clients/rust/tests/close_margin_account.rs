@@ -0,0 +1,253 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use borsh::BorshSerialize;
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::{Attachment, MarginAccount, MarginAccountV2},
+    errors::TensorEscrowError,
+    find_legacy_margin_account_pda,
+    instructions::{
+        CloseMarginAccountBuilder, InitMarginAccountBuilder, MigrateMarginAccountBuilder,
+        ReconcileAttachmentsBuilder,
+    },
+    types::Caller,
+    MARGIN_ACCOUNT_DISCRIMINATOR,
+};
+
+const ATTACHMENT_DISCRIMINATOR: [u8; 8] = [255, 150, 79, 28, 206, 74, 113, 70];
+
+/// Slot the tests close in, margin accounts are created before it.
+const CLOSE_SLOT: u64 = 100;
+
+async fn close(
+    context: &mut ProgramTestContext,
+    tswap: Pubkey,
+    margin_account: Pubkey,
+    owner: &Keypair,
+) -> Result<(), BanksClientError> {
+    context.warp_to_slot(CLOSE_SLOT).unwrap();
+
+    let ix = CloseMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .instruction();
+    send(context, &[ix], owner, &[]).await
+}
+
+/// V1 margin accounts can't be created anymore.
+fn add_margin_v1(program_test: &mut ProgramTest, tswap: Pubkey, owner: &Keypair) -> Pubkey {
+    let (margin_account, bump) = find_legacy_margin_account_pda(&tswap, &owner.pubkey(), 0);
+    let data = MarginAccount {
+        discriminator: MARGIN_ACCOUNT_DISCRIMINATOR,
+        owner: owner.pubkey(),
+        name: [0; 32],
+        nr: 0,
+        bump: [bump],
+        pools_attached: 0,
+        reserved: [0; 64],
+    };
+    program_test.add_account(
+        margin_account,
+        program_account(data.try_to_vec().unwrap(), LAMPORTS),
+    );
+    margin_account
+}
+
+async fn migrate(
+    context: &mut ProgramTestContext,
+    tswap: Pubkey,
+    margin_account: Pubkey,
+    owner: &Keypair,
+) {
+    let ix = MigrateMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .payer(owner.pubkey())
+        .instruction();
+    send(context, &[ix], owner, &[]).await.unwrap();
+}
+
+fn reconcile(margin_account: Pubkey, owner: Pubkey) -> Instruction {
+    ReconcileAttachmentsBuilder::new()
+        .margin_account(margin_account)
+        .owner(owner)
+        .instruction()
+}
+
+#[tokio::test]
+async fn close_margin_account_v2() {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    let margin_account = add_margin_v2(&mut program_test, &owner.pubkey(), 0, |_| {});
+
+    let mut context = program_test.start_with_context().await;
+    close(&mut context, tswap, margin_account, &owner)
+        .await
+        .unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(margin_account)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
+
+#[tokio::test]
+async fn close_margin_account_with_attached_pool_fails() {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    let margin_account = add_margin_v2(&mut program_test, &owner.pubkey(), 0, |margin| {
+        margin.pools_attached = 1;
+    });
+
+    // State left behind by attach_margin_account_cpi_tamm.
+    let pool = Pubkey::new_unique();
+    let (attachment, attachment_bump) = Attachment::find_pda(&margin_account, &pool);
+    let data = Attachment {
+        discriminator: ATTACHMENT_DISCRIMINATOR,
+        version: 1,
+        bump: [attachment_bump],
+        margin_account,
+        caller_account: pool,
+        caller: Caller::TAmm,
+        rent_payer: owner.pubkey(),
+        reserved: [0; 32],
+    };
+    program_test.add_account(
+        attachment,
+        program_account(data.try_to_vec().unwrap(), LAMPORTS),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let err = close(&mut context, tswap, margin_account, &owner)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::MarginInUse as u32);
+}
+
+#[tokio::test]
+async fn close_margin_account_v1_fails_until_migrated() {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    let margin_account = add_margin_v1(&mut program_test, tswap, &owner);
+
+    let mut context = program_test.start_with_context().await;
+    let err = close(&mut context, tswap, margin_account, &owner)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::MarginNotMigrated as u32);
+}
+
+#[tokio::test]
+async fn close_margin_account_in_its_creation_slot_fails() {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    let margin_account = add_margin_v2(&mut program_test, &owner.pubkey(), 0, |margin| {
+        margin.generation = CLOSE_SLOT;
+    });
+
+    let mut context = program_test.start_with_context().await;
+    let err = close(&mut context, tswap, margin_account, &owner)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::MarginClosedTooSoon as u32);
+}
+
+#[tokio::test]
+async fn close_legacy_family_margin_account_in_its_creation_slot_fails() {
+    let owner = Keypair::new();
+    let (program_test, tswap) = program_test(&owner);
+    let (margin_account, _) = find_legacy_margin_account_pda(&tswap, &owner.pubkey(), 0);
+
+    let init_ix = InitMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .margin_nr(0)
+        .instruction();
+    let close_ix = CloseMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .instruction();
+
+    let mut context = program_test.start_with_context().await;
+    let err = send(&mut context, &[init_ix, close_ix], &owner, &[])
+        .await
+        .unwrap_err();
+
+    // the close, after the init
+    assert_instruction_error(err, 1, TensorEscrowError::MarginClosedTooSoon as u32);
+}
+
+#[tokio::test]
+async fn close_migrated_margin_account_fails_until_reconciled() {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    let margin_account = add_margin_v1(&mut program_test, tswap, &owner);
+
+    let mut context = program_test.start_with_context().await;
+    migrate(&mut context, tswap, margin_account, &owner).await;
+
+    let err = close(&mut context, tswap, margin_account, &owner)
+        .await
+        .unwrap_err();
+    assert_custom_error(err, TensorEscrowError::UntrackedAttachments as u32);
+
+    let close_ix = CloseMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .instruction();
+    send(
+        &mut context,
+        &[reconcile(margin_account, owner.pubkey()), close_ix],
+        &owner,
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(margin_account)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
+
+#[tokio::test]
+async fn reconcile_attachments_by_non_owner_fails() {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    let margin_account = add_margin_v1(&mut program_test, tswap, &owner);
+
+    let mut context = program_test.start_with_context().await;
+    migrate(&mut context, tswap, margin_account, &owner).await;
+
+    let attacker = Keypair::new();
+    let ix = reconcile(margin_account, attacker.pubkey());
+    let err = send(&mut context, &[ix], &owner, &[&attacker])
+        .await
+        .unwrap_err();
+
+    // has_one = owner
+    assert_custom_error(err, 2001);
+    let margin =
+        MarginAccountV2::from_bytes(&account_data(&mut context, margin_account).await).unwrap();
+    assert!(margin.untracked_attachments);
+}
@@ -0,0 +1,243 @@
+//! TAMM and TCOMP check the shared escrow themselves before calling in, so the escrow's own
+//! pool and bid checks are exercised with a stand-in program at their addresses. It signs
+//! for whatever pool or bid state it is handed, like a compromised TAMM or TCOMP would.
+//! A third stand-in plays a registered cpi consumer the same way.
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair};
+use std::str::FromStr;
+
+use super::{account, send, LAMPORTS};
+
+pub const TAMM_POOL_DISCRIMINATOR: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
+pub const TCOMP_BID_STATE_DISCRIMINATOR: [u8; 8] = [155, 197, 5, 97, 189, 60, 8, 183];
+
+/// Price of a single fill of a stand-in bid.
+pub const BID_AMOUNT: u64 = 100_000_000;
+
+/// Seed prefix and discriminator the stand-in consumer is registered with.
+pub const CONSUMER_SEED_PREFIX: &[u8] = b"consumer";
+pub const CONSUMER_SIGNER_DISCRIMINATOR: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+pub fn tamm_id() -> Pubkey {
+    Pubkey::from_str("TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg").unwrap()
+}
+
+pub fn tcomp_id() -> Pubkey {
+    Pubkey::from_str("TCMPhJdwDryooaGtiocG1u3xcYbRpiJzb283XfCZsDp").unwrap()
+}
+
+pub fn consumer_id() -> Pubkey {
+    Pubkey::new_from_array([7; 32])
+}
+
+/// Forwards the escrow instruction in `data` to the escrow program (first account), signing
+/// for the account at `signer` (followed by the owner) with the bump and id from the
+/// instruction args.
+fn forward_signed(
+    seed: &[u8],
+    signer: usize,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let bump = data[8];
+    let id = &data[9..41];
+    let owner = accounts[signer + 1].key;
+    let signer = accounts[signer].key;
+
+    let ix = Instruction {
+        program_id: *accounts[0].key,
+        accounts: accounts[1..]
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: *account.key,
+                is_signer: account.is_signer || account.key == signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+    invoke_signed(&ix, accounts, &[&[seed, owner.as_ref(), id, &[bump]]])
+}
+
+pub fn fake_tamm(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    forward_signed(b"pool", 2, accounts, data)
+}
+
+pub fn fake_tcomp(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    forward_signed(b"bid_state", 2, accounts, data)
+}
+
+/// Forwards `withdraw_margin_account_cpi`, whose signer comes after the cpi consumer, the
+/// margin account and the approval.
+pub fn fake_consumer(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    forward_signed(CONSUMER_SEED_PREFIX, 4, accounts, data)
+}
+
+/// Puts the stand-in callers at the TAMM, TCOMP and consumer addresses.
+pub fn add_callers(program_test: &mut ProgramTest) {
+    program_test.add_builtin_program("fake_tamm", tamm_id(), processor!(fake_tamm).unwrap());
+    program_test.add_builtin_program("fake_tcomp", tcomp_id(), processor!(fake_tcomp).unwrap());
+    program_test.add_builtin_program(
+        "fake_consumer",
+        consumer_id(),
+        processor!(fake_consumer).unwrap(),
+    );
+}
+
+/// Prefix of a TAMM pool, up to `shared_escrow`.
+pub fn tamm_pool(
+    owner: Pubkey,
+    pool_id: [u8; 32],
+    bump: u8,
+    currency: Pubkey,
+    margin: Pubkey,
+) -> Vec<u8> {
+    let mut data = TAMM_POOL_DISCRIMINATOR.to_vec();
+    // version, bump, pool_id, created_at, updated_at, expiry
+    (1u8, [bump], pool_id, 0i64, 0i64, 0i64)
+        .serialize(&mut data)
+        .unwrap();
+    // owner, whitelist, rent_payer, currency
+    (owner, Pubkey::new_unique(), owner, currency)
+        .serialize(&mut data)
+        .unwrap();
+    // amount, price_offset, nfts_held, taker_sell_count, taker_buy_count,
+    // accumulated_mm_profit, shared_escrow
+    (0u64, 0i32, 0u32, 0u32, 0u32, 0u64, margin)
+        .serialize(&mut data)
+        .unwrap();
+    data
+}
+
+pub struct Bid {
+    pub quantity: u32,
+    pub filled_quantity: u32,
+    pub currency: Option<Pubkey>,
+    pub margin: Pubkey,
+}
+
+/// Prefix of a TCOMP bid state, up to `margin`.
+pub fn tcomp_bid_state(owner: Pubkey, bid_id: Pubkey, bump: u8, bid: Bid) -> Vec<u8> {
+    let mut data = TCOMP_BID_STATE_DISCRIMINATOR.to_vec();
+    // version, bump, owner, bid_id, target, target_id, field, field_id
+    (
+        1u8,
+        [bump],
+        owner,
+        bid_id,
+        0u8,
+        Pubkey::new_unique(),
+        None::<u8>,
+        None::<Pubkey>,
+    )
+        .serialize(&mut data)
+        .unwrap();
+    // quantity, filled_quantity, amount, currency, expiry, private_taker, maker_broker, margin
+    (
+        bid.quantity,
+        bid.filled_quantity,
+        BID_AMOUNT,
+        bid.currency,
+        0i64,
+        None::<Pubkey>,
+        None::<Pubkey>,
+        Some(bid.margin),
+    )
+        .serialize(&mut data)
+        .unwrap();
+    data
+}
+
+/// Adds a pool of `owner` attached to `margin`, returns it with its bump and id.
+pub fn add_pool(
+    program_test: &mut ProgramTest,
+    owner: Pubkey,
+    currency: Pubkey,
+    margin: Pubkey,
+) -> (Pubkey, u8, [u8; 32]) {
+    let pool_id = Pubkey::new_unique().to_bytes();
+    let (pool, bump) =
+        Pubkey::find_program_address(&[b"pool", owner.as_ref(), pool_id.as_ref()], &tamm_id());
+    program_test.add_account(
+        pool,
+        account(
+            tamm_pool(owner, pool_id, bump, currency, margin),
+            LAMPORTS,
+            tamm_id(),
+        ),
+    );
+    (pool, bump, pool_id)
+}
+
+/// Adds a bid of `owner`, returns it with its bump and id.
+pub fn add_bid(program_test: &mut ProgramTest, owner: Pubkey, bid: Bid) -> (Pubkey, u8, Pubkey) {
+    let bid_id = Pubkey::new_unique();
+    let (bid_state, bump) = Pubkey::find_program_address(
+        &[b"bid_state", owner.as_ref(), bid_id.as_ref()],
+        &tcomp_id(),
+    );
+    program_test.add_account(
+        bid_state,
+        account(
+            tcomp_bid_state(owner, bid_id, bump, bid),
+            LAMPORTS,
+            tcomp_id(),
+        ),
+    );
+    (bid_state, bump, bid_id)
+}
+
+/// Adds the stand-in consumer's signer PDA for `owner`, returns it with its bump and id.
+pub fn add_consumer_signer(
+    program_test: &mut ProgramTest,
+    owner: Pubkey,
+) -> (Pubkey, u8, [u8; 32]) {
+    let id = Pubkey::new_unique().to_bytes();
+    let (signer, bump) = Pubkey::find_program_address(
+        &[CONSUMER_SEED_PREFIX, owner.as_ref(), id.as_ref()],
+        &consumer_id(),
+    );
+    program_test.add_account(
+        signer,
+        account(
+            CONSUMER_SIGNER_DISCRIMINATOR.to_vec(),
+            LAMPORTS,
+            consumer_id(),
+        ),
+    );
+    (signer, bump, id)
+}
+
+/// Wraps `escrow_ix` in an instruction of the stand-in `caller`.
+pub fn caller_instruction(caller: Pubkey, escrow_ix: Instruction) -> Instruction {
+    let mut accounts = vec![AccountMeta::new_readonly(tensor_escrow::ID, false)];
+    accounts.extend(escrow_ix.accounts.into_iter().map(|mut meta| {
+        // the stand-in signs for the pool, bid state or consumer PDA
+        meta.is_signer = false;
+        meta
+    }));
+    Instruction {
+        program_id: caller,
+        accounts,
+        data: escrow_ix.data,
+    }
+}
+
+/// Sends `escrow_ix` through the stand-in `caller`.
+pub async fn call_through(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    caller: Pubkey,
+    escrow_ix: Instruction,
+) -> Result<(), BanksClientError> {
+    let ix = caller_instruction(caller, escrow_ix);
+    send(context, &[ix], payer, &[]).await
+}
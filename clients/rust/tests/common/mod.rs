@@ -0,0 +1,219 @@
+//! Fixtures shared by the program tests. `add_*` injects account state directly, `init_*`
+//! goes through the instructions that create it.
+
+#![allow(dead_code)]
+
+mod callers;
+
+#[allow(unused_imports)]
+pub use callers::*;
+
+use assert_matches::assert_matches;
+use borsh::BorshSerialize;
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use tensor_escrow::{
+    accounts::{MarginAccountV2, TSwap},
+    find_margin_account_v2_pda,
+    instructions::{DepositMarginAccountBuilder, InitMarginAccountV2Builder, InitTswapBuilder},
+    types::TSwapConfig,
+    MARGIN_ACCOUNT_DISCRIMINATOR,
+};
+
+pub const TSWAP_DISCRIMINATOR: [u8; 8] = [169, 211, 171, 36, 219, 189, 79, 188];
+
+pub const LAMPORTS: u64 = 1_000_000_000;
+
+/// Account of `owner` holding `data`.
+pub fn account(data: Vec<u8>, lamports: u64, owner: Pubkey) -> Account {
+    Account {
+        lamports,
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Account of the escrow program holding `data`.
+pub fn program_account(data: Vec<u8>, lamports: u64) -> Account {
+    account(data, lamports, tensor_escrow::ID)
+}
+
+pub fn system_account(lamports: u64) -> Account {
+    Account::new(lamports, 0, &solana_sdk::system_program::ID)
+}
+
+pub fn margin_v2(owner: Pubkey, bump: u8) -> MarginAccountV2 {
+    MarginAccountV2 {
+        discriminator: MARGIN_ACCOUNT_DISCRIMINATOR,
+        owner,
+        name: [0; 32],
+        nr: 0,
+        bump: [bump],
+        deprecated_pools_attached: 0,
+        reserved: [0; 64],
+        version: 2,
+        pools_attached: 0,
+        bids_attached: 0,
+        withdrawal_delay: 0,
+        pending_withdrawal_delay: 0,
+        withdrawal_delay_unlock_at: 0,
+        pending_withdrawals: 0,
+        original_owner: Pubkey::default(),
+        pending_owner: Pubkey::default(),
+        cosign_threshold: None,
+        cpi_denied: 0,
+        reserved_lamports: 0,
+        held_lamports: 0,
+        generation: 0,
+        untracked_attachments: false,
+        reserved_v2: [0; 69],
+    }
+}
+
+/// Adds margin account `nr` of `owner`, funded with `LAMPORTS`.
+pub fn add_margin_v2(
+    program_test: &mut ProgramTest,
+    owner: &Pubkey,
+    nr: u16,
+    edit: impl FnOnce(&mut MarginAccountV2),
+) -> Pubkey {
+    let (margin_account, bump) = find_margin_account_v2_pda(owner, nr);
+    let mut data = margin_v2(*owner, bump);
+    data.nr = nr;
+    edit(&mut data);
+    program_test.add_account(
+        margin_account,
+        program_account(data.try_to_vec().unwrap(), LAMPORTS),
+    );
+    margin_account
+}
+
+/// Starts the program without a TSwap, the owner is funded as the fee payer.
+pub fn program_test_without_tswap(owner: &Keypair) -> ProgramTest {
+    let mut program_test = ProgramTest::new("escrow_program", tensor_escrow::ID, None);
+    program_test.add_account(owner.pubkey(), system_account(LAMPORTS));
+    program_test
+}
+
+/// Starts the program with a V1 TSwap, the owner is funded as the fee payer.
+pub fn program_test(owner: &Keypair) -> (ProgramTest, Pubkey) {
+    let mut program_test = program_test_without_tswap(owner);
+
+    let (tswap, bump) = TSwap::find_pda();
+    let data = TSwap {
+        discriminator: TSWAP_DISCRIMINATOR,
+        version: 1,
+        bump: [bump],
+        config: TSwapConfig { fee_bps: 0 },
+        owner: Pubkey::new_unique(),
+        fee_vault: Pubkey::new_unique(),
+        cosigner: Pubkey::new_unique(),
+    };
+    program_test.add_account(tswap, program_account(data.try_to_vec().unwrap(), LAMPORTS));
+
+    (program_test, tswap)
+}
+
+/// Creates a V2 TSwap of `owner` charging `fee_bps`, `owner` is also the cosigner.
+pub async fn init_tswap(
+    context: &mut ProgramTestContext,
+    owner: &Keypair,
+    fee_vault: Pubkey,
+    fee_bps: u16,
+) -> Result<Pubkey, BanksClientError> {
+    let (tswap, _) = TSwap::find_pda();
+    let ix = InitTswapBuilder::new()
+        .tswap(tswap)
+        .fee_vault(fee_vault)
+        .cosigner(owner.pubkey())
+        .owner(owner.pubkey())
+        .config(TSwapConfig { fee_bps })
+        .instruction();
+    send(context, &[ix], owner, &[]).await?;
+    Ok(tswap)
+}
+
+/// Creates margin account `nr` of `owner` and deposits `lamports` on top of its rent.
+pub async fn init_margin_account(
+    context: &mut ProgramTestContext,
+    tswap: Pubkey,
+    owner: &Keypair,
+    nr: u16,
+    lamports: u64,
+) -> Result<Pubkey, BanksClientError> {
+    let (margin_account, _) = find_margin_account_v2_pda(&owner.pubkey(), nr);
+    let mut ixs = vec![InitMarginAccountV2Builder::new()
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .margin_nr(nr)
+        .instruction()];
+    if lamports > 0 {
+        ixs.push(
+            DepositMarginAccountBuilder::new()
+                .tswap(tswap)
+                .margin_account(margin_account)
+                .owner(owner.pubkey())
+                .lamports(lamports)
+                .instruction(),
+        );
+    }
+    send(context, &ixs, owner, &[]).await?;
+    Ok(margin_account)
+}
+
+/// The account's data, fails if it doesn't exist.
+pub async fn account_data(context: &mut ProgramTestContext, address: Pubkey) -> Vec<u8> {
+    context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .expect("account not found")
+        .data
+}
+
+pub async fn send(
+    context: &mut ProgramTestContext,
+    ixs: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn balance(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    context.banks_client.get_balance(address).await.unwrap()
+}
+
+/// Fails unless the first instruction failed with custom error `expected`.
+pub fn assert_custom_error(err: BanksClientError, expected: u32) {
+    assert_instruction_error(err, 0, expected);
+}
+
+/// Fails unless instruction `index` failed with custom error `expected`.
+pub fn assert_instruction_error(err: BanksClientError, index: u8, expected: u32) {
+    assert_matches!(
+        err,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            i,
+            InstructionError::Custom(code),
+        )) if i == index && code == expected
+    );
+}
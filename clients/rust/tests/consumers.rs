@@ -0,0 +1,256 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::ConsumerApproval,
+    errors::TensorEscrowError,
+    instructions::{
+        ApproveConsumerBuilder, CloseCpiConsumerBuilder, CloseMarginAccountBuilder,
+        InitUpdateCpiConsumerBuilder, RevokeConsumerBuilder,
+        WithdrawMarginAccountFromConsumerBuilder,
+    },
+};
+
+// anchor_lang::error::ErrorCode::AccountNotInitialized
+const ACCOUNT_NOT_INITIALIZED: u32 = 3012;
+
+const WITHDRAWAL: u64 = LAMPORTS / 10;
+
+/// Slot the margin account is closed in, it is created before it.
+const CLOSE_SLOT: u64 = 100;
+
+struct Setup {
+    owner: Keypair,
+    /// The consumer's PDA for the owner, with its bump and id.
+    signer: (Pubkey, u8, [u8; 32]),
+    destination: Pubkey,
+}
+
+/// Starts the program without a TSwap, the tests create it with the owner as program owner.
+fn setup() -> (ProgramTest, Setup) {
+    let owner = Keypair::new();
+    let mut program_test = program_test_without_tswap(&owner);
+    add_callers(&mut program_test);
+
+    let signer = add_consumer_signer(&mut program_test, owner.pubkey());
+    let destination = Pubkey::new_unique();
+    program_test.add_account(destination, system_account(LAMPORTS));
+
+    let setup = Setup {
+        owner,
+        signer,
+        destination,
+    };
+    (program_test, setup)
+}
+
+fn cpi_consumer(program: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"cpi_consumer", program.as_ref()], &tensor_escrow::ID).0
+}
+
+fn register(tswap: Pubkey, owner: Pubkey, program: Pubkey) -> Instruction {
+    InitUpdateCpiConsumerBuilder::new()
+        .tswap(tswap)
+        .cpi_consumer(cpi_consumer(program))
+        .consumer_program(program)
+        .owner(owner)
+        .seed_prefix(CONSUMER_SEED_PREFIX.to_vec())
+        .signer_discriminator(CONSUMER_SIGNER_DISCRIMINATOR)
+        .instruction()
+}
+
+fn approve(margin_account: Pubkey, owner: Pubkey) -> Instruction {
+    ApproveConsumerBuilder::new()
+        .margin_account(margin_account)
+        .cpi_consumer(cpi_consumer(consumer_id()))
+        .consumer_approval(ConsumerApproval::find_pda(&margin_account, &consumer_id()).0)
+        .owner(owner)
+        .instruction()
+}
+
+impl Setup {
+    /// Creates the TSwap and a funded margin account.
+    async fn init(&self, context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+        let tswap = init_tswap(context, &self.owner, Pubkey::new_unique(), 0)
+            .await
+            .unwrap();
+        let margin_account = init_margin_account(context, tswap, &self.owner, 0, LAMPORTS / 2)
+            .await
+            .unwrap();
+        (tswap, margin_account)
+    }
+
+    /// Creates the TSwap and a funded margin account, registers the stand-in consumer and
+    /// approves it on the margin account.
+    async fn init_approved(&self, context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+        let (tswap, margin_account) = self.init(context).await;
+        let owner = self.owner.pubkey();
+        send(
+            context,
+            &[
+                register(tswap, owner, consumer_id()),
+                approve(margin_account, owner),
+            ],
+            &self.owner,
+            &[],
+        )
+        .await
+        .unwrap();
+        (tswap, margin_account)
+    }
+
+    async fn withdraw(
+        &self,
+        context: &mut ProgramTestContext,
+        tswap: Pubkey,
+        margin_account: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let (signer, bump, id) = self.signer;
+        let ix = WithdrawMarginAccountFromConsumerBuilder::new()
+            .cpi_consumer(cpi_consumer(consumer_id()))
+            .margin_account(margin_account)
+            .consumer_approval(ConsumerApproval::find_pda(&margin_account, &consumer_id()).0)
+            .signer(signer)
+            .owner(self.owner.pubkey())
+            .destination(self.destination)
+            .tswap(tswap)
+            .bump(bump)
+            .id(id)
+            .lamports(WITHDRAWAL)
+            .instruction();
+        call_through(context, &self.owner, consumer_id(), ix).await
+    }
+}
+
+#[tokio::test]
+async fn consumer_withdrawal() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let (tswap, margin_account) = setup.init_approved(&mut context).await;
+    let margin_before = balance(&mut context, margin_account).await;
+
+    setup
+        .withdraw(&mut context, tswap, margin_account)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, margin_account).await,
+        margin_before - WITHDRAWAL
+    );
+    assert_eq!(
+        balance(&mut context, setup.destination).await,
+        LAMPORTS + WITHDRAWAL
+    );
+}
+
+#[tokio::test]
+async fn withdrawal_by_unregistered_consumer_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let (tswap, margin_account) = setup.init(&mut context).await;
+
+    // nothing to approve either
+    let ix = approve(margin_account, setup.owner.pubkey());
+    let err = send(&mut context, &[ix], &setup.owner, &[])
+        .await
+        .unwrap_err();
+    assert_custom_error(err, ACCOUNT_NOT_INITIALIZED);
+
+    let err = setup
+        .withdraw(&mut context, tswap, margin_account)
+        .await
+        .unwrap_err();
+    assert_custom_error(err, ACCOUNT_NOT_INITIALIZED);
+}
+
+#[tokio::test]
+async fn withdrawal_after_the_approval_is_revoked_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let (tswap, margin_account) = setup.init_approved(&mut context).await;
+
+    let ix = RevokeConsumerBuilder::new()
+        .margin_account(margin_account)
+        .consumer_approval(ConsumerApproval::find_pda(&margin_account, &consumer_id()).0)
+        .owner(setup.owner.pubkey())
+        .instruction();
+    send(&mut context, &[ix], &setup.owner, &[]).await.unwrap();
+
+    let err = setup
+        .withdraw(&mut context, tswap, margin_account)
+        .await
+        .unwrap_err();
+    assert_custom_error(err, ACCOUNT_NOT_INITIALIZED);
+}
+
+#[tokio::test]
+async fn withdrawal_after_the_consumer_is_closed_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let (tswap, margin_account) = setup.init_approved(&mut context).await;
+
+    let ix = CloseCpiConsumerBuilder::new()
+        .tswap(tswap)
+        .cpi_consumer(cpi_consumer(consumer_id()))
+        .owner(setup.owner.pubkey())
+        .instruction();
+    send(&mut context, &[ix], &setup.owner, &[]).await.unwrap();
+
+    let err = setup
+        .withdraw(&mut context, tswap, margin_account)
+        .await
+        .unwrap_err();
+    assert_custom_error(err, ACCOUNT_NOT_INITIALIZED);
+}
+
+#[tokio::test]
+async fn withdrawal_from_a_recreated_margin_account_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let (tswap, margin_account) = setup.init_approved(&mut context).await;
+
+    context.warp_to_slot(CLOSE_SLOT).unwrap();
+    let ix = CloseMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(setup.owner.pubkey())
+        .instruction();
+    send(&mut context, &[ix], &setup.owner, &[]).await.unwrap();
+    context.warp_to_slot(CLOSE_SLOT + 1).unwrap();
+    // the same transaction as the first init
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    init_margin_account(&mut context, tswap, &setup.owner, 0, LAMPORTS / 2)
+        .await
+        .unwrap();
+
+    // the approval was given to the closed account
+    let err = setup
+        .withdraw(&mut context, tswap, margin_account)
+        .await
+        .unwrap_err();
+    assert_custom_error(err, TensorEscrowError::StaleGrant as u32);
+}
+
+#[tokio::test]
+async fn registering_tamm_or_tcomp_as_consumer_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let (tswap, _) = setup.init(&mut context).await;
+
+    for program in [tamm_id(), tcomp_id()] {
+        let ix = register(tswap, setup.owner.pubkey(), program);
+        let err = send(&mut context, &[ix], &setup.owner, &[])
+            .await
+            .unwrap_err();
+        assert_custom_error(err, TensorEscrowError::ReservedCpiConsumer as u32);
+    }
+}
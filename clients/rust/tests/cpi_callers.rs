@@ -0,0 +1,351 @@
+#![cfg(feature = "test-sbf")]
+
+//! Pool and bid checks on TAMM and TCOMP withdrawals, called through the stand-in callers.
+
+mod common;
+
+use assert_matches::assert_matches;
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{processor, tokio, ProgramTest};
+use solana_sdk::{
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use tensor_escrow::{
+    accounts::MarginAccountV2,
+    errors::TensorEscrowError,
+    find_margin_account_v2_pda,
+    instructions::{WithdrawMarginAccountCpiTammBuilder, WithdrawMarginAccountCpiTcompBuilder},
+    types::CpiReturnData,
+};
+
+struct Setup {
+    program_test: ProgramTest,
+    owner: Keypair,
+    tswap: Pubkey,
+    margin_account: Pubkey,
+    other_margin_account: Pubkey,
+    destination: Pubkey,
+}
+
+/// Starts the program with a V1 TSwap and two funded V2 margin accounts of the same owner.
+fn setup() -> Setup {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    add_callers(&mut program_test);
+
+    let margin_account = add_margin_v2(&mut program_test, &owner.pubkey(), 0, |_| {});
+    let other_margin_account = add_margin_v2(&mut program_test, &owner.pubkey(), 1, |_| {});
+
+    let destination = Pubkey::new_unique();
+    program_test.add_account(destination, system_account(LAMPORTS));
+
+    Setup {
+        program_test,
+        owner,
+        tswap,
+        margin_account,
+        other_margin_account,
+        destination,
+    }
+}
+
+impl Setup {
+    fn add_pool(&mut self, currency: Pubkey, margin: Pubkey) -> (Pubkey, u8, [u8; 32]) {
+        add_pool(
+            &mut self.program_test,
+            self.owner.pubkey(),
+            currency,
+            margin,
+        )
+    }
+
+    fn add_bid(&mut self, bid: Bid) -> (Pubkey, u8, Pubkey) {
+        add_bid(&mut self.program_test, self.owner.pubkey(), bid)
+    }
+}
+
+fn tamm_withdraw(
+    setup: &Setup,
+    margin_account: Pubkey,
+    pool: (Pubkey, u8, [u8; 32]),
+    lamports: u64,
+) -> Instruction {
+    let (pool, bump, pool_id) = pool;
+    WithdrawMarginAccountCpiTammBuilder::new()
+        .margin_account(margin_account)
+        .pool(pool)
+        .owner(setup.owner.pubkey())
+        .destination(setup.destination)
+        .tswap(setup.tswap)
+        .bump(bump)
+        .pool_id(pool_id)
+        .lamports(lamports)
+        .instruction()
+}
+
+fn tcomp_withdraw(
+    setup: &Setup,
+    margin_account: Pubkey,
+    bid: (Pubkey, u8, Pubkey),
+    lamports: u64,
+) -> Instruction {
+    let (bid_state, bump, bid_id) = bid;
+    WithdrawMarginAccountCpiTcompBuilder::new()
+        .margin_account(margin_account)
+        .bid_state(bid_state)
+        .owner(setup.owner.pubkey())
+        .destination(setup.destination)
+        .tswap(setup.tswap)
+        .bump(bump)
+        .bid_id(bid_id)
+        .lamports(lamports)
+        .instruction()
+}
+
+#[tokio::test]
+async fn pool_withdraws_from_its_margin_account() {
+    let mut setup = setup();
+    let pool = setup.add_pool(Pubkey::default(), setup.margin_account);
+    let ix = tamm_withdraw(&setup, setup.margin_account, pool, BID_AMOUNT);
+
+    let mut context = setup.program_test.start_with_context().await;
+    call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        LAMPORTS - BID_AMOUNT
+    );
+    assert_eq!(
+        balance(&mut context, setup.destination).await,
+        LAMPORTS + BID_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn pool_withdraws_from_a_created_margin_account() {
+    let mut setup = setup();
+    let (margin_account, _) = find_margin_account_v2_pda(&setup.owner.pubkey(), 2);
+    let pool = setup.add_pool(Pubkey::default(), margin_account);
+    let ix = tamm_withdraw(&setup, margin_account, pool, BID_AMOUNT);
+
+    let mut context = setup.program_test.start_with_context().await;
+    init_margin_account(&mut context, setup.tswap, &setup.owner, 2, 2 * BID_AMOUNT)
+        .await
+        .unwrap();
+    let margin_before = balance(&mut context, margin_account).await;
+    call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, margin_account).await,
+        margin_before - BID_AMOUNT
+    );
+    assert_eq!(
+        balance(&mut context, setup.destination).await,
+        LAMPORTS + BID_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn pool_withdrawal_returns_the_post_balance() {
+    let mut setup = setup();
+    let (margin_account, _) = find_margin_account_v2_pda(&setup.owner.pubkey(), 2);
+    let pool = setup.add_pool(Pubkey::default(), margin_account);
+    let escrow_ix = tamm_withdraw(&setup, margin_account, pool, BID_AMOUNT);
+
+    let mut context = setup.program_test.start_with_context().await;
+    init_margin_account(&mut context, setup.tswap, &setup.owner, 2, 2 * BID_AMOUNT)
+        .await
+        .unwrap();
+    let margin_before = balance(&mut context, margin_account).await;
+
+    // simulated to read the return data
+    let ix = caller_instruction(tamm_id(), escrow_ix);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&setup.owner.pubkey()),
+        &[&setup.owner],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+    assert_matches!(simulation.result, Some(Ok(())));
+
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, tensor_escrow::ID);
+    assert_eq!(
+        CpiReturnData::from_bytes(&return_data.data).unwrap(),
+        CpiReturnData {
+            amount: BID_AMOUNT,
+            balance: margin_before - BID_AMOUNT,
+            rent: Rent::default().minimum_balance(MarginAccountV2::LEN),
+        }
+    );
+}
+
+#[tokio::test]
+async fn pool_cannot_withdraw_from_another_margin_account() {
+    let mut setup = setup();
+    let pool = setup.add_pool(Pubkey::default(), setup.margin_account);
+    let ix = tamm_withdraw(&setup, setup.other_margin_account, pool, BID_AMOUNT);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::MarginMismatch as u32);
+    assert_eq!(
+        balance(&mut context, setup.other_margin_account).await,
+        LAMPORTS
+    );
+}
+
+#[tokio::test]
+async fn token_pool_cannot_withdraw_lamports() {
+    let mut setup = setup();
+    let pool = setup.add_pool(Pubkey::new_unique(), setup.margin_account);
+    let ix = tamm_withdraw(&setup, setup.margin_account, pool, BID_AMOUNT);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::CurrencyMismatch as u32);
+}
+
+#[tokio::test]
+async fn impostor_program_cannot_sign_for_a_pool() {
+    let mut setup = setup();
+    let impostor = Pubkey::new_unique();
+    setup
+        .program_test
+        .add_builtin_program("impostor", impostor, processor!(fake_tamm).unwrap());
+
+    // Same seeds as a TAMM pool, derived from the impostor.
+    let pool_id = Pubkey::new_unique().to_bytes();
+    let owner = setup.owner.pubkey();
+    let (pool, bump) =
+        Pubkey::find_program_address(&[b"pool", owner.as_ref(), pool_id.as_ref()], &impostor);
+    setup.program_test.add_account(
+        pool,
+        account(
+            tamm_pool(
+                owner,
+                pool_id,
+                bump,
+                Pubkey::default(),
+                setup.margin_account,
+            ),
+            LAMPORTS,
+            impostor,
+        ),
+    );
+    let ix = tamm_withdraw(
+        &setup,
+        setup.margin_account,
+        (pool, bump, pool_id),
+        BID_AMOUNT,
+    );
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = call_through(&mut context, &setup.owner, impostor, ix)
+        .await
+        .unwrap_err();
+
+    // anchor's ConstraintSeeds
+    assert_custom_error(err, 2006);
+    assert_eq!(balance(&mut context, setup.margin_account).await, LAMPORTS);
+}
+
+#[tokio::test]
+async fn bid_withdraws_a_fill_from_its_margin_account() {
+    let mut setup = setup();
+    // TCOMP counts the fill before calling in, so the last fill sees nothing left to fill.
+    let bid = setup.add_bid(Bid {
+        quantity: 2,
+        filled_quantity: 2,
+        currency: None,
+        margin: setup.margin_account,
+    });
+    let ix = tcomp_withdraw(&setup, setup.margin_account, bid, BID_AMOUNT);
+
+    let mut context = setup.program_test.start_with_context().await;
+    call_through(&mut context, &setup.owner, tcomp_id(), ix)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        LAMPORTS - BID_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn bid_cannot_withdraw_from_another_margin_account() {
+    let mut setup = setup();
+    let bid = setup.add_bid(Bid {
+        quantity: 1,
+        filled_quantity: 0,
+        currency: None,
+        margin: setup.margin_account,
+    });
+    let ix = tcomp_withdraw(&setup, setup.other_margin_account, bid, BID_AMOUNT);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = call_through(&mut context, &setup.owner, tcomp_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::MarginMismatch as u32);
+    assert_eq!(
+        balance(&mut context, setup.other_margin_account).await,
+        LAMPORTS
+    );
+}
+
+#[tokio::test]
+async fn bid_cannot_withdraw_more_than_its_price() {
+    let mut setup = setup();
+    let bid = setup.add_bid(Bid {
+        quantity: 5,
+        filled_quantity: 0,
+        currency: None,
+        margin: setup.margin_account,
+    });
+    let ix = tcomp_withdraw(&setup, setup.margin_account, bid, BID_AMOUNT + 1);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = call_through(&mut context, &setup.owner, tcomp_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::AmountExceedsBid as u32);
+    assert_eq!(balance(&mut context, setup.margin_account).await, LAMPORTS);
+}
+
+#[tokio::test]
+async fn token_bid_cannot_withdraw_lamports() {
+    let mut setup = setup();
+    let bid = setup.add_bid(Bid {
+        quantity: 1,
+        filled_quantity: 0,
+        currency: Some(Pubkey::new_unique()),
+        margin: setup.margin_account,
+    });
+    let ix = tcomp_withdraw(&setup, setup.margin_account, bid, BID_AMOUNT);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = call_through(&mut context, &setup.owner, tcomp_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::CurrencyMismatch as u32);
+}
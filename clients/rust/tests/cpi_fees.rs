@@ -0,0 +1,182 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use borsh::BorshSerialize;
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::TSwap,
+    errors::TensorEscrowError,
+    find_margin_account_v2_pda,
+    instructions::{
+        MigrateTswapBuilder, SetFeesEnabledBuilder, WithdrawMarginAccountCpiTammBuilder,
+    },
+    types::TSwapConfig,
+};
+
+// Mirrors the program's `MAX_FEE_BPS`
+const MAX_FEE_BPS: u16 = 1_000;
+const FEE_BPS: u16 = 100;
+
+struct Setup {
+    owner: Keypair,
+    fee_vault: Pubkey,
+    margin_account: Pubkey,
+    pool: (Pubkey, u8, [u8; 32]),
+}
+
+/// Starts the program with a SOL pool on margin account 0 of the owner, which the tests
+/// create once the program runs.
+fn setup() -> (ProgramTest, Setup) {
+    let owner = Keypair::new();
+    let mut program_test = program_test_without_tswap(&owner);
+    add_callers(&mut program_test);
+
+    let fee_vault = Pubkey::new_unique();
+    program_test.add_account(fee_vault, system_account(LAMPORTS));
+
+    let (margin_account, _) = find_margin_account_v2_pda(&owner.pubkey(), 0);
+    let pool = add_pool(
+        &mut program_test,
+        owner.pubkey(),
+        Pubkey::default(),
+        margin_account,
+    );
+
+    let setup = Setup {
+        owner,
+        fee_vault,
+        margin_account,
+        pool,
+    };
+    (program_test, setup)
+}
+
+async fn enable_fees(context: &mut ProgramTestContext, owner: &Keypair, tswap: Pubkey) {
+    let ix = SetFeesEnabledBuilder::new()
+        .tswap(tswap)
+        .owner(owner.pubkey())
+        .fees_enabled(true)
+        .instruction();
+    send(context, &[ix], owner, &[]).await.unwrap();
+}
+
+fn tamm_withdraw(setup: &Setup, tswap: Pubkey, fee_vault: Option<Pubkey>) -> Instruction {
+    let (pool, bump, pool_id) = setup.pool;
+    WithdrawMarginAccountCpiTammBuilder::new()
+        .margin_account(setup.margin_account)
+        .pool(pool)
+        .owner(setup.owner.pubkey())
+        .destination(setup.owner.pubkey())
+        .tswap(tswap)
+        .fee_vault(fee_vault)
+        .bump(bump)
+        .pool_id(pool_id)
+        .lamports(BID_AMOUNT)
+        .instruction()
+}
+
+#[tokio::test]
+async fn tamm_withdrawal_pays_the_fee_on_top() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+
+    let tswap = init_tswap(&mut context, &setup.owner, setup.fee_vault, FEE_BPS)
+        .await
+        .unwrap();
+    enable_fees(&mut context, &setup.owner, tswap).await;
+    init_margin_account(&mut context, tswap, &setup.owner, 0, LAMPORTS)
+        .await
+        .unwrap();
+    let margin_before = balance(&mut context, setup.margin_account).await;
+
+    let ix = tamm_withdraw(&setup, tswap, Some(setup.fee_vault));
+    call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap();
+
+    let fee = BID_AMOUNT * u64::from(FEE_BPS) / 10_000;
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before - BID_AMOUNT - fee
+    );
+    assert_eq!(balance(&mut context, setup.fee_vault).await, LAMPORTS + fee);
+}
+
+#[tokio::test]
+async fn tamm_withdrawal_without_the_fee_vault_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+
+    let tswap = init_tswap(&mut context, &setup.owner, setup.fee_vault, FEE_BPS)
+        .await
+        .unwrap();
+    enable_fees(&mut context, &setup.owner, tswap).await;
+    init_margin_account(&mut context, tswap, &setup.owner, 0, LAMPORTS)
+        .await
+        .unwrap();
+    let margin_before = balance(&mut context, setup.margin_account).await;
+
+    let ix = tamm_withdraw(&setup, tswap, None);
+    let err = call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::FeeVaultMissing as u32);
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before
+    );
+}
+
+#[tokio::test]
+async fn fee_bps_of_a_v1_tswap_is_capped() {
+    let (mut program_test, setup) = setup();
+
+    // V1 configs were never validated, and V1 TSwaps can't be created anymore.
+    let (tswap, bump) = TSwap::find_pda();
+    let data = TSwap {
+        discriminator: TSWAP_DISCRIMINATOR,
+        version: 1,
+        bump: [bump],
+        config: TSwapConfig {
+            fee_bps: 5 * MAX_FEE_BPS,
+        },
+        owner: setup.owner.pubkey(),
+        fee_vault: setup.fee_vault,
+        cosigner: setup.owner.pubkey(),
+    };
+    program_test.add_account(tswap, program_account(data.try_to_vec().unwrap(), LAMPORTS));
+    let mut context = program_test.start_with_context().await;
+
+    let migrate_ix = MigrateTswapBuilder::new()
+        .tswap(tswap)
+        .owner(setup.owner.pubkey())
+        .instruction();
+    send(&mut context, &[migrate_ix], &setup.owner, &[])
+        .await
+        .unwrap();
+    enable_fees(&mut context, &setup.owner, tswap).await;
+    init_margin_account(&mut context, tswap, &setup.owner, 0, LAMPORTS)
+        .await
+        .unwrap();
+    let margin_before = balance(&mut context, setup.margin_account).await;
+
+    let ix = tamm_withdraw(&setup, tswap, Some(setup.fee_vault));
+    call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap();
+
+    let fee = BID_AMOUNT * u64::from(MAX_FEE_BPS) / 10_000;
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before - BID_AMOUNT - fee
+    );
+    assert_eq!(balance(&mut context, setup.fee_vault).await, LAMPORTS + fee);
+}
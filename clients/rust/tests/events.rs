@@ -0,0 +1,402 @@
+use borsh::BorshSerialize;
+use solana_program::{hash::hash, pubkey::Pubkey};
+use tensor_escrow::{types::Caller, *};
+
+/// Anchor's discriminator for the event named `name`.
+fn discriminator(name: &str) -> [u8; 8] {
+    hash(format!("event:{name}").as_bytes()).to_bytes()[..8]
+        .try_into()
+        .unwrap()
+}
+
+/// Checks the event's discriminator and that it decodes both from a `Program data:` log
+/// entry and from the self-CPI instruction data.
+macro_rules! decode_test {
+    ($test:ident, $variant:ident, $event:ident { $($fields:tt)* }) => {
+        #[test]
+        fn $test() {
+            assert_eq!($event::DISCRIMINATOR, discriminator(stringify!($event)));
+
+            let event = $event { $($fields)* };
+            let mut data = $event::DISCRIMINATOR.to_vec();
+            event.serialize(&mut data).unwrap();
+
+            assert_eq!(
+                EscrowEvent::from_bytes(&data).unwrap(),
+                EscrowEvent::$variant(event.clone())
+            );
+
+            let ix_data = [&EVENT_IX_TAG_LE[..], &data].concat();
+            assert_eq!(
+                EscrowEvent::from_bytes(&ix_data).unwrap(),
+                EscrowEvent::$variant(event)
+            );
+        }
+    };
+}
+
+#[test]
+fn event_ix_tag_matches_anchor() {
+    // anchor reads the hash prefix as a big-endian u64 and writes it little-endian
+    let mut tag: [u8; 8] = hash(b"anchor:event").to_bytes()[..8].try_into().unwrap();
+    tag.reverse();
+    assert_eq!(EVENT_IX_TAG_LE, tag);
+}
+
+#[test]
+fn unknown_discriminator_fails() {
+    let mut data = [0u8; 8].to_vec();
+    Pubkey::new_unique().serialize(&mut data).unwrap();
+
+    assert!(EscrowEvent::from_bytes(&data).is_err());
+}
+
+#[test]
+fn missing_discriminator_fails() {
+    assert!(EscrowEvent::from_bytes(&InitMarginEvent::DISCRIMINATOR[..4]).is_err());
+    assert!(EscrowEvent::from_bytes(&EVENT_IX_TAG_LE).is_err());
+}
+
+#[test]
+fn truncated_payload_fails() {
+    let mut data = DepositEvent::DISCRIMINATOR.to_vec();
+    Pubkey::new_unique().serialize(&mut data).unwrap();
+
+    assert!(EscrowEvent::from_bytes(&data).is_err());
+}
+
+decode_test!(
+    init_margin,
+    InitMargin,
+    InitMarginEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        nr: 3,
+        version: 2,
+    }
+);
+
+decode_test!(
+    migrate_margin,
+    MigrateMargin,
+    MigrateMarginEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        payer: Pubkey::new_unique(),
+        version: 2,
+    }
+);
+
+decode_test!(
+    reconcile_attachments,
+    ReconcileAttachments,
+    ReconcileAttachmentsEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+    }
+);
+
+decode_test!(
+    close_margin,
+    CloseMargin,
+    CloseMarginEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        amount: 1_000,
+        rent_destination: Pubkey::new_unique(),
+    }
+);
+
+decode_test!(
+    deposit,
+    Deposit,
+    DepositEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        mint: Some(Pubkey::new_unique()),
+        amount: 1_000,
+        post_balance: 5_000,
+    }
+);
+
+decode_test!(
+    withdraw,
+    Withdraw,
+    WithdrawEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        caller: Caller::CpiConsumer,
+        caller_program: Some(Pubkey::new_unique()),
+        caller_account: None,
+        mint: None,
+        amount: 1_000,
+        post_balance: 4_000,
+        destination: Pubkey::new_unique(),
+    }
+);
+
+decode_test!(
+    attach,
+    Attach,
+    AttachEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        caller: Caller::Tcomp,
+        caller_account: Pubkey::new_unique(),
+        attached: true,
+        pools_attached: 1,
+        bids_attached: 2,
+    }
+);
+
+decode_test!(
+    allowance,
+    Allowance,
+    AllowanceEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        delegate: Pubkey::new_unique(),
+        amount: 1_000,
+        expires_at: Some(1_700_000_000),
+    }
+);
+
+decode_test!(
+    withdrawal_delay,
+    WithdrawalDelay,
+    WithdrawalDelayEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        withdrawal_delay: 60,
+        pending_withdrawal_delay: 30,
+        withdrawal_delay_unlock_at: 1_700_000_000,
+    }
+);
+
+decode_test!(
+    withdrawal_request,
+    WithdrawalRequest,
+    WithdrawalRequestEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        request_nr: 7,
+        amount: 1_000,
+        unlock_at: 1_700_000_000,
+        cancelled: false,
+    }
+);
+
+decode_test!(
+    margin_transfer,
+    MarginTransfer,
+    MarginTransferEvent {
+        owner: Pubkey::new_unique(),
+        source: Pubkey::new_unique(),
+        destination: Pubkey::new_unique(),
+        amount: 1_000,
+        source_post_balance: 2_000,
+        destination_post_balance: 3_000,
+    }
+);
+
+decode_test!(
+    owner_transfer,
+    OwnerTransfer,
+    OwnerTransferEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        new_owner: Pubkey::new_unique(),
+        accepted: true,
+    }
+);
+
+decode_test!(
+    pause,
+    Pause,
+    PauseEvent {
+        tswap: Pubkey::new_unique(),
+        authority: Pubkey::new_unique(),
+        guardian: Pubkey::new_unique(),
+        paused: 0b101,
+    }
+);
+
+decode_test!(
+    rate_limit,
+    RateLimit,
+    RateLimitEvent {
+        caller: Caller::TAmm,
+        caller_program: None,
+        limit: 1_000,
+        window: 3_600,
+    }
+);
+
+decode_test!(
+    rate_limit_exceeded,
+    RateLimitExceeded,
+    RateLimitExceededEvent {
+        margin: Pubkey::new_unique(),
+        caller: Caller::CpiConsumer,
+        caller_program: Some(Pubkey::new_unique()),
+        amount: 600,
+        used: 500,
+        limit: 1_000,
+        window: 3_600,
+    }
+);
+
+decode_test!(
+    fee,
+    Fee,
+    FeeEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        caller: Caller::Tcomp,
+        caller_program: None,
+        amount: 10,
+        fee_vault: Pubkey::new_unique(),
+    }
+);
+
+decode_test!(
+    fee_config,
+    FeeConfig,
+    FeeConfigEvent {
+        fees_enabled: true,
+        caller: Some(Caller::CpiConsumer),
+        caller_program: Some(Pubkey::new_unique()),
+        fee_bps: Some(25),
+    }
+);
+
+decode_test!(
+    fee_exemption,
+    FeeExemption,
+    FeeExemptionEvent {
+        owner: Pubkey::new_unique(),
+        exempt: true,
+    }
+);
+
+decode_test!(
+    withdraw_fees,
+    WithdrawFees,
+    WithdrawFeesEvent {
+        fee_vault: Pubkey::new_unique(),
+        amount: 1_000,
+        destination: Pubkey::new_unique(),
+    }
+);
+
+decode_test!(
+    cosign_threshold,
+    CosignThreshold,
+    CosignThresholdEvent {
+        margin: None,
+        owner: Pubkey::new_unique(),
+        threshold: Some(1_000),
+    }
+);
+
+decode_test!(
+    deposit_for,
+    DepositFor,
+    DepositForEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        payer: Pubkey::new_unique(),
+        amount: 1_000,
+        post_balance: 2_000,
+    }
+);
+
+decode_test!(
+    cpi_denied,
+    CpiDenied,
+    CpiDeniedEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        cpi_denied: 0b11,
+    }
+);
+
+decode_test!(
+    reservation,
+    Reservation,
+    ReservationEvent {
+        margin: Pubkey::new_unique(),
+        caller_account: Pubkey::new_unique(),
+        amount: 1_000,
+        reserved_lamports: 3_000,
+    }
+);
+
+decode_test!(
+    hold,
+    Hold,
+    HoldEvent {
+        margin: Pubkey::new_unique(),
+        hold: Pubkey::new_unique(),
+        caller: Caller::TAmm,
+        caller_account: Pubkey::new_unique(),
+        amount: 1_000,
+        captured: 400,
+        expiry_slot: 12_345,
+        held_lamports: 600,
+    }
+);
+
+decode_test!(
+    hold_expiry,
+    HoldExpiry,
+    HoldExpiryEvent {
+        owner: Pubkey::new_unique(),
+        hold_expiry_slots: 150,
+    }
+);
+
+decode_test!(
+    tswap_update,
+    TSwapUpdate,
+    TSwapUpdateEvent {
+        tswap: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        fee_vault: Pubkey::new_unique(),
+        cosigner: Pubkey::new_unique(),
+        fee_bps: 150,
+        pending_owner: Pubkey::new_unique(),
+    }
+);
+
+decode_test!(
+    cpi_consumer,
+    CpiConsumer,
+    CpiConsumerEvent {
+        program_id: Pubkey::new_unique(),
+        seed_prefix: b"pool".to_vec(),
+        signer_discriminator: [1, 2, 3, 4, 5, 6, 7, 8],
+        registered: true,
+    }
+);
+
+decode_test!(
+    cpi_consumer_update,
+    CpiConsumerUpdate,
+    CpiConsumerUpdateEvent {
+        program_id: Pubkey::new_unique(),
+        seed_prefix: b"bid_state".to_vec(),
+        signer_discriminator: [8, 7, 6, 5, 4, 3, 2, 1],
+    }
+);
+
+decode_test!(
+    consumer_approval,
+    ConsumerApproval,
+    ConsumerApprovalEvent {
+        margin: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        consumer_program: Pubkey::new_unique(),
+        approved: false,
+    }
+);
@@ -0,0 +1,346 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::{Hold, MarginAccountV2},
+    errors::TensorEscrowError,
+    find_margin_account_v2_pda,
+    instructions::{
+        CaptureHoldCpiTammBuilder, CaptureHoldCpiTcompBuilder, PlaceHoldCpiTammBuilder,
+        PlaceHoldCpiTcompBuilder, SetFeesEnabledBuilder, WithdrawMarginAccountBuilder,
+    },
+};
+
+// Mirror the program's constants
+const WITHDRAW_ALL: u64 = u64::MAX;
+const DEFAULT_HOLD_EXPIRY_SLOTS: u64 = 216_000;
+
+const FEE_BPS: u16 = 100;
+/// Deposited on top of the margin account's rent.
+const DEPOSIT: u64 = 5 * BID_AMOUNT;
+
+struct Setup {
+    owner: Keypair,
+    fee_vault: Pubkey,
+    margin_account: Pubkey,
+    pool: (Pubkey, u8, [u8; 32]),
+    /// Two fills left.
+    bid: (Pubkey, u8, Pubkey),
+    destination: Pubkey,
+}
+
+/// Starts the program with a SOL pool and a SOL bid on margin account 0 of the owner,
+/// which the tests create once the program runs.
+fn setup() -> (ProgramTest, Setup) {
+    let owner = Keypair::new();
+    let mut program_test = program_test_without_tswap(&owner);
+    add_callers(&mut program_test);
+
+    let fee_vault = Pubkey::new_unique();
+    program_test.add_account(fee_vault, system_account(LAMPORTS));
+    let destination = Pubkey::new_unique();
+    program_test.add_account(destination, system_account(LAMPORTS));
+
+    let (margin_account, _) = find_margin_account_v2_pda(&owner.pubkey(), 0);
+    let pool = add_pool(
+        &mut program_test,
+        owner.pubkey(),
+        Pubkey::default(),
+        margin_account,
+    );
+    let bid = add_bid(
+        &mut program_test,
+        owner.pubkey(),
+        Bid {
+            quantity: 3,
+            filled_quantity: 1,
+            currency: None,
+            margin: margin_account,
+        },
+    );
+
+    let setup = Setup {
+        owner,
+        fee_vault,
+        margin_account,
+        pool,
+        bid,
+        destination,
+    };
+    (program_test, setup)
+}
+
+impl Setup {
+    /// Creates a TSwap charging `FEE_BPS` and the margin account holding `DEPOSIT`.
+    async fn init(&self, context: &mut ProgramTestContext) -> Pubkey {
+        let tswap = init_tswap(context, &self.owner, self.fee_vault, FEE_BPS)
+            .await
+            .unwrap();
+        let ix = SetFeesEnabledBuilder::new()
+            .tswap(tswap)
+            .owner(self.owner.pubkey())
+            .fees_enabled(true)
+            .instruction();
+        send(context, &[ix], &self.owner, &[]).await.unwrap();
+        init_margin_account(context, tswap, &self.owner, 0, DEPOSIT)
+            .await
+            .unwrap();
+        tswap
+    }
+
+    fn tamm_hold(&self, hold_id: [u8; 32]) -> Pubkey {
+        Hold::find_pda(&self.margin_account, &self.pool.0, hold_id).0
+    }
+
+    fn tcomp_hold(&self, hold_id: [u8; 32]) -> Pubkey {
+        Hold::find_pda(&self.margin_account, &self.bid.0, hold_id).0
+    }
+
+    fn place_tamm_hold(&self, tswap: Pubkey, hold_id: [u8; 32], lamports: u64) -> Instruction {
+        let (pool, bump, pool_id) = self.pool;
+        PlaceHoldCpiTammBuilder::new()
+            .margin_account(self.margin_account)
+            .pool(pool)
+            .owner(self.owner.pubkey())
+            .hold(self.tamm_hold(hold_id))
+            .payer(self.owner.pubkey())
+            .tswap(tswap)
+            .bump(bump)
+            .pool_id(pool_id)
+            .hold_id(hold_id)
+            .lamports(lamports)
+            .instruction()
+    }
+
+    fn place_tcomp_hold(&self, tswap: Pubkey, hold_id: [u8; 32], lamports: u64) -> Instruction {
+        let (bid_state, bump, bid_id) = self.bid;
+        PlaceHoldCpiTcompBuilder::new()
+            .margin_account(self.margin_account)
+            .bid_state(bid_state)
+            .owner(self.owner.pubkey())
+            .hold(self.tcomp_hold(hold_id))
+            .payer(self.owner.pubkey())
+            .tswap(tswap)
+            .bump(bump)
+            .bid_id(bid_id)
+            .hold_id(hold_id)
+            .lamports(lamports)
+            .instruction()
+    }
+
+    fn capture_tamm_hold(&self, tswap: Pubkey, hold_id: [u8; 32], lamports: u64) -> Instruction {
+        let (pool, bump, pool_id) = self.pool;
+        CaptureHoldCpiTammBuilder::new()
+            .margin_account(self.margin_account)
+            .pool(pool)
+            .owner(self.owner.pubkey())
+            .hold(self.tamm_hold(hold_id))
+            .destination(self.destination)
+            .rent_payer(self.owner.pubkey())
+            .tswap(tswap)
+            .fee_vault(Some(self.fee_vault))
+            .bump(bump)
+            .pool_id(pool_id)
+            .lamports(lamports)
+            .instruction()
+    }
+
+    fn capture_tcomp_hold(&self, tswap: Pubkey, hold_id: [u8; 32], lamports: u64) -> Instruction {
+        let (bid_state, bump, bid_id) = self.bid;
+        CaptureHoldCpiTcompBuilder::new()
+            .margin_account(self.margin_account)
+            .bid_state(bid_state)
+            .owner(self.owner.pubkey())
+            .hold(self.tcomp_hold(hold_id))
+            .destination(self.destination)
+            .rent_payer(self.owner.pubkey())
+            .tswap(tswap)
+            .fee_vault(Some(self.fee_vault))
+            .bump(bump)
+            .bid_id(bid_id)
+            .lamports(lamports)
+            .instruction()
+    }
+
+    async fn tamm(
+        &self,
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+    ) -> Result<(), BanksClientError> {
+        call_through(context, &self.owner, tamm_id(), ix).await
+    }
+
+    async fn tcomp(
+        &self,
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+    ) -> Result<(), BanksClientError> {
+        call_through(context, &self.owner, tcomp_id(), ix).await
+    }
+
+    async fn held_lamports(&self, context: &mut ProgramTestContext) -> u64 {
+        let data = account_data(context, self.margin_account).await;
+        MarginAccountV2::from_bytes(&data).unwrap().held_lamports
+    }
+}
+
+fn fee(lamports: u64) -> u64 {
+    lamports * u64::from(FEE_BPS) / 10_000
+}
+
+#[tokio::test]
+async fn tamm_hold_is_taken_out_of_the_available_balance() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = setup.init(&mut context).await;
+    let margin_before = balance(&mut context, setup.margin_account).await;
+
+    let hold_id = Pubkey::new_unique().to_bytes();
+    let ix = setup.place_tamm_hold(tswap, hold_id, 3 * BID_AMOUNT);
+    setup.tamm(&mut context, ix).await.unwrap();
+
+    // nothing moves until the hold is captured
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before
+    );
+    assert_eq!(setup.held_lamports(&mut context).await, 3 * BID_AMOUNT);
+
+    let ix = setup.place_tamm_hold(tswap, Pubkey::new_unique().to_bytes(), 3 * BID_AMOUNT);
+    let err = setup.tamm(&mut context, ix).await.unwrap_err();
+    assert_custom_error(err, TensorEscrowError::InsufficientAvailableBalance as u32);
+
+    let ix = WithdrawMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(setup.margin_account)
+        .owner(setup.owner.pubkey())
+        .lamports(3 * BID_AMOUNT)
+        .instruction();
+    let err = send(&mut context, &[ix], &setup.owner, &[])
+        .await
+        .unwrap_err();
+    assert_custom_error(err, TensorEscrowError::InsufficientAvailableBalance as u32);
+    assert_eq!(setup.held_lamports(&mut context).await, 3 * BID_AMOUNT);
+}
+
+#[tokio::test]
+async fn tcomp_hold_is_capped_by_the_rest_of_the_bid() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = setup.init(&mut context).await;
+
+    let ix = setup.place_tcomp_hold(tswap, Pubkey::new_unique().to_bytes(), 2 * BID_AMOUNT + 1);
+    let err = setup.tcomp(&mut context, ix).await.unwrap_err();
+    assert_custom_error(err, TensorEscrowError::AmountExceedsBid as u32);
+
+    let ix = setup.place_tcomp_hold(tswap, Pubkey::new_unique().to_bytes(), 2 * BID_AMOUNT);
+    setup.tcomp(&mut context, ix).await.unwrap();
+    assert_eq!(setup.held_lamports(&mut context).await, 2 * BID_AMOUNT);
+}
+
+#[tokio::test]
+async fn partial_capture_returns_the_rest_of_the_hold() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = setup.init(&mut context).await;
+
+    let hold_id = Pubkey::new_unique().to_bytes();
+    let ix = setup.place_tamm_hold(tswap, hold_id, 3 * BID_AMOUNT);
+    setup.tamm(&mut context, ix).await.unwrap();
+    let margin_before = balance(&mut context, setup.margin_account).await;
+
+    let ix = setup.capture_tamm_hold(tswap, hold_id, BID_AMOUNT);
+    setup.tamm(&mut context, ix).await.unwrap();
+
+    // the fee comes on top of the captured lamports
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before - BID_AMOUNT - fee(BID_AMOUNT)
+    );
+    assert_eq!(
+        balance(&mut context, setup.destination).await,
+        LAMPORTS + BID_AMOUNT
+    );
+    assert_eq!(
+        balance(&mut context, setup.fee_vault).await,
+        LAMPORTS + fee(BID_AMOUNT)
+    );
+    assert_eq!(setup.held_lamports(&mut context).await, 0);
+
+    let hold = context
+        .banks_client
+        .get_account(setup.tamm_hold(hold_id))
+        .await
+        .unwrap();
+    assert!(hold.is_none());
+}
+
+#[tokio::test]
+async fn capture_all_of_a_hold() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = setup.init(&mut context).await;
+
+    // TCOMP captures at most one fill at a time
+    let hold_id = Pubkey::new_unique().to_bytes();
+    let ix = setup.place_tcomp_hold(tswap, hold_id, BID_AMOUNT);
+    setup.tcomp(&mut context, ix).await.unwrap();
+    let margin_before = balance(&mut context, setup.margin_account).await;
+
+    let ix = setup.capture_tcomp_hold(tswap, hold_id, WITHDRAW_ALL);
+    setup.tcomp(&mut context, ix).await.unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before - BID_AMOUNT - fee(BID_AMOUNT)
+    );
+    assert_eq!(
+        balance(&mut context, setup.destination).await,
+        LAMPORTS + BID_AMOUNT
+    );
+    assert_eq!(setup.held_lamports(&mut context).await, 0);
+}
+
+#[tokio::test]
+async fn capture_over_the_hold_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = setup.init(&mut context).await;
+
+    let hold_id = Pubkey::new_unique().to_bytes();
+    let ix = setup.place_tamm_hold(tswap, hold_id, BID_AMOUNT);
+    setup.tamm(&mut context, ix).await.unwrap();
+
+    let ix = setup.capture_tamm_hold(tswap, hold_id, BID_AMOUNT + 1);
+    let err = setup.tamm(&mut context, ix).await.unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::AmountExceedsHold as u32);
+    assert_eq!(setup.held_lamports(&mut context).await, BID_AMOUNT);
+}
+
+#[tokio::test]
+async fn capture_after_expiry_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = setup.init(&mut context).await;
+
+    let hold_id = Pubkey::new_unique().to_bytes();
+    let ix = setup.place_tamm_hold(tswap, hold_id, BID_AMOUNT);
+    setup.tamm(&mut context, ix).await.unwrap();
+
+    context
+        .warp_to_slot(DEFAULT_HOLD_EXPIRY_SLOTS + 100)
+        .unwrap();
+    let ix = setup.capture_tamm_hold(tswap, hold_id, BID_AMOUNT);
+    let err = setup.tamm(&mut context, ix).await.unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::HoldExpired as u32);
+}
@@ -0,0 +1,107 @@
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use tensor_escrow::{
+    accounts::{MarginAccount, MarginAccountV2},
+    VersionedMarginAccount, MARGIN_ACCOUNT_DISCRIMINATOR,
+};
+
+fn margin_v1() -> MarginAccount {
+    MarginAccount {
+        discriminator: MARGIN_ACCOUNT_DISCRIMINATOR,
+        owner: Pubkey::new_unique(),
+        name: [7; 32],
+        nr: 3,
+        bump: [254],
+        pools_attached: 2,
+        reserved: [0; 64],
+    }
+}
+
+fn margin_v2() -> MarginAccountV2 {
+    MarginAccountV2 {
+        discriminator: MARGIN_ACCOUNT_DISCRIMINATOR,
+        owner: Pubkey::new_unique(),
+        name: [7; 32],
+        nr: 3,
+        bump: [254],
+        deprecated_pools_attached: 0,
+        reserved: [0; 64],
+        version: 2,
+        pools_attached: 4,
+        bids_attached: 5,
+        withdrawal_delay: 60,
+        pending_withdrawal_delay: 0,
+        withdrawal_delay_unlock_at: 0,
+        pending_withdrawals: 1_000,
+        original_owner: Pubkey::default(),
+        pending_owner: Pubkey::default(),
+        cosign_threshold: Some(10_000),
+        cpi_denied: 0b1,
+        reserved_lamports: 2_000,
+        held_lamports: 3_000,
+        generation: 42,
+        untracked_attachments: false,
+        reserved_v2: [0; 69],
+    }
+}
+
+#[test]
+fn decode_v1() {
+    let margin = margin_v1();
+    let data = margin.try_to_vec().unwrap();
+    assert_eq!(data.len(), MarginAccount::LEN);
+
+    let decoded = VersionedMarginAccount::from_bytes(&data).unwrap();
+    assert_eq!(decoded, VersionedMarginAccount::V1(margin.clone()));
+    assert_eq!(decoded.version(), 1);
+    assert_eq!(decoded.owner(), margin.owner);
+    assert_eq!(decoded.name(), margin.name);
+    assert_eq!(decoded.nr(), margin.nr);
+    assert_eq!(decoded.bump(), margin.bump[0]);
+    assert_eq!(decoded.pools_attached(), None);
+    assert_eq!(decoded.bids_attached(), None);
+}
+
+#[test]
+fn decode_v2() {
+    let margin = margin_v2();
+    let data = margin.try_to_vec().unwrap();
+    assert_eq!(data.len(), MarginAccountV2::LEN);
+
+    let decoded = VersionedMarginAccount::from_bytes(&data).unwrap();
+    assert_eq!(decoded, VersionedMarginAccount::V2(margin.clone()));
+    assert_eq!(decoded.version(), 2);
+    assert_eq!(decoded.owner(), margin.owner);
+    assert_eq!(decoded.name(), margin.name);
+    assert_eq!(decoded.nr(), margin.nr);
+    assert_eq!(decoded.bump(), margin.bump[0]);
+    assert_eq!(decoded.pools_attached(), Some(4));
+    assert_eq!(decoded.bids_attached(), Some(5));
+}
+
+#[test]
+fn v2_starts_with_the_v1_layout() {
+    let margin = margin_v2();
+    let data = margin.try_to_vec().unwrap();
+
+    let v1 = MarginAccount::from_bytes(&data[..MarginAccount::LEN]).unwrap();
+    assert_eq!(v1.owner, margin.owner);
+    assert_eq!(v1.nr, margin.nr);
+    assert_eq!(v1.bump, margin.bump);
+}
+
+#[test]
+fn wrong_discriminator_fails() {
+    let mut data = margin_v1().try_to_vec().unwrap();
+    data[0] ^= 1;
+
+    assert!(VersionedMarginAccount::from_bytes(&data).is_err());
+}
+
+#[test]
+fn truncated_account_fails() {
+    let data = margin_v1().try_to_vec().unwrap();
+
+    assert!(VersionedMarginAccount::from_bytes(&data[..MarginAccount::LEN - 1]).is_err());
+    assert!(VersionedMarginAccount::from_bytes(&[]).is_err());
+}
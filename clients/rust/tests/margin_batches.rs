@@ -0,0 +1,252 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::MarginAccountV2,
+    errors::TensorEscrowError,
+    find_margin_account_v2_pda,
+    instructions::{ConsolidateMarginAccountsBuilder, DepositMarginAccountsBuilder},
+};
+
+const AMOUNT: u64 = 100_000_000;
+
+struct Setup {
+    program_test: ProgramTest,
+    tswap: Pubkey,
+    owner: Keypair,
+    margin_accounts: [Pubkey; 3],
+}
+
+/// Starts the program with three V2 margin accounts of the same owner.
+fn setup() -> Setup {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    let margin_accounts =
+        [0, 1, 2].map(|nr| add_margin_v2(&mut program_test, &owner.pubkey(), nr, |_| {}));
+
+    Setup {
+        program_test,
+        tswap,
+        owner,
+        margin_accounts,
+    }
+}
+
+/// What consolidating moves out of a source margin account: everything but its rent.
+fn consolidated() -> u64 {
+    LAMPORTS - Rent::default().minimum_balance(MarginAccountV2::LEN)
+}
+
+fn deposit(setup: &Setup, margin_accounts: &[Pubkey], amounts: Vec<u64>) -> Instruction {
+    DepositMarginAccountsBuilder::new()
+        .owner(setup.owner.pubkey())
+        .tswap(setup.tswap)
+        .amounts(amounts)
+        .add_remaining_accounts(
+            &margin_accounts
+                .iter()
+                .map(|margin_account| AccountMeta::new(*margin_account, false))
+                .collect::<Vec<_>>(),
+        )
+        .instruction()
+}
+
+fn consolidate(setup: &Setup, target: Pubkey, sources: &[Pubkey]) -> Instruction {
+    ConsolidateMarginAccountsBuilder::new()
+        .target_margin_account(target)
+        .owner(setup.owner.pubkey())
+        .add_remaining_accounts(
+            &sources
+                .iter()
+                .map(|source| AccountMeta::new(*source, false))
+                .collect::<Vec<_>>(),
+        )
+        .instruction()
+}
+
+#[tokio::test]
+async fn deposit_margin_accounts() {
+    let setup = setup();
+    let [first, second, _] = setup.margin_accounts;
+    let ix = deposit(&setup, &[first, second], vec![AMOUNT, 2 * AMOUNT]);
+
+    let mut context = setup.program_test.start_with_context().await;
+    send(&mut context, &[ix], &setup.owner, &[]).await.unwrap();
+
+    assert_eq!(balance(&mut context, first).await, LAMPORTS + AMOUNT);
+    assert_eq!(balance(&mut context, second).await, LAMPORTS + 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn deposit_into_and_consolidate_created_margin_accounts() {
+    let setup = setup();
+    // next to the fixture's accounts 0 to 2
+    let nrs = [3, 4, 5];
+    let [target, first, second] =
+        nrs.map(|nr| find_margin_account_v2_pda(&setup.owner.pubkey(), nr).0);
+    let ixs = [
+        deposit(&setup, &[first, second], vec![AMOUNT, 2 * AMOUNT]),
+        consolidate(&setup, target, &[first, second]),
+    ];
+
+    let mut context = setup.program_test.start_with_context().await;
+    for nr in nrs {
+        init_margin_account(&mut context, setup.tswap, &setup.owner, nr, 0)
+            .await
+            .unwrap();
+    }
+    let rent = balance(&mut context, target).await;
+    send(&mut context, &ixs, &setup.owner, &[]).await.unwrap();
+
+    assert_eq!(balance(&mut context, target).await, rent + 3 * AMOUNT);
+    assert_eq!(balance(&mut context, first).await, rent);
+    assert_eq!(balance(&mut context, second).await, rent);
+}
+
+#[tokio::test]
+async fn deposit_margin_accounts_with_mismatched_counts_fails() {
+    let setup = setup();
+    let [first, second, _] = setup.margin_accounts;
+    let ix = deposit(&setup, &[first, second], vec![AMOUNT]);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = send(&mut context, &[ix], &setup.owner, &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::MarginCountMismatch as u32);
+}
+
+#[tokio::test]
+async fn deposit_margin_accounts_twice_into_one_fails() {
+    let setup = setup();
+    let [first, _, _] = setup.margin_accounts;
+    let ix = deposit(&setup, &[first, first], vec![AMOUNT, AMOUNT]);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = send(&mut context, &[ix], &setup.owner, &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::DuplicateMarginAccount as u32);
+}
+
+#[tokio::test]
+async fn deposit_margin_accounts_of_another_owner_fails() {
+    let mut setup = setup();
+    let [first, _, _] = setup.margin_accounts;
+    let foreign = add_margin_v2(&mut setup.program_test, &Pubkey::new_unique(), 0, |_| {});
+    let ix = deposit(&setup, &[first, foreign], vec![AMOUNT, AMOUNT]);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = send(&mut context, &[ix], &setup.owner, &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::BadOwner as u32);
+}
+
+#[tokio::test]
+async fn consolidate_margin_accounts() {
+    let setup = setup();
+    let [target, first, second] = setup.margin_accounts;
+    let ix = consolidate(&setup, target, &[first, second]);
+
+    let mut context = setup.program_test.start_with_context().await;
+    send(&mut context, &[ix], &setup.owner, &[]).await.unwrap();
+
+    assert_eq!(
+        balance(&mut context, target).await,
+        LAMPORTS + 2 * consolidated()
+    );
+    assert_eq!(
+        balance(&mut context, first).await,
+        LAMPORTS - consolidated()
+    );
+    assert_eq!(
+        balance(&mut context, second).await,
+        LAMPORTS - consolidated()
+    );
+}
+
+#[tokio::test]
+async fn consolidate_margin_accounts_with_a_source_twice_fails() {
+    let setup = setup();
+    let [target, first, _] = setup.margin_accounts;
+    let ix = consolidate(&setup, target, &[first, first]);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = send(&mut context, &[ix], &setup.owner, &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::DuplicateMarginAccount as u32);
+}
+
+#[tokio::test]
+async fn consolidate_margin_accounts_of_another_owner_fails() {
+    let mut setup = setup();
+    let [target, first, _] = setup.margin_accounts;
+    let foreign = add_margin_v2(&mut setup.program_test, &Pubkey::new_unique(), 0, |_| {});
+    let ix = consolidate(&setup, target, &[first, foreign]);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = send(&mut context, &[ix], &setup.owner, &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::BadOwner as u32);
+}
+
+#[tokio::test]
+async fn consolidate_margin_accounts_from_a_longer_delay_fails() {
+    let mut setup = setup();
+    let [target, first, _] = setup.margin_accounts;
+    let delayed = add_margin_v2(
+        &mut setup.program_test,
+        &setup.owner.pubkey(),
+        3,
+        |margin| {
+            margin.withdrawal_delay = 3_600;
+        },
+    );
+    let ix = consolidate(&setup, target, &[first, delayed]);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = send(&mut context, &[ix], &setup.owner, &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::WithdrawalDelayed as u32);
+}
+
+#[tokio::test]
+async fn consolidate_margin_accounts_from_a_tighter_cosign_threshold_fails() {
+    let mut setup = setup();
+    let [target, first, _] = setup.margin_accounts;
+    let cosigned = add_margin_v2(
+        &mut setup.program_test,
+        &setup.owner.pubkey(),
+        3,
+        |margin| {
+            margin.cosign_threshold = Some(AMOUNT);
+        },
+    );
+    let ix = consolidate(&setup, target, &[first, cosigned]);
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = send(&mut context, &[ix], &setup.owner, &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::CosignRequired as u32);
+}
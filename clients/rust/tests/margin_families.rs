@@ -0,0 +1,193 @@
+#![cfg(feature = "test-sbf")]
+
+//! Margin accounts of the V2 family (`[b"margin", owner, nr]`) next to the legacy family
+//! (`[b"margin", tswap, owner, nr]`).
+
+mod common;
+
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::MarginAccountV2,
+    find_legacy_margin_account_pda, find_margin_account_v2_pda,
+    instructions::{
+        AcceptMarginOwnerBuilder, DepositMarginAccountBuilder, InitMarginAccountBuilder,
+        InitMarginAccountV2Builder, ProposeMarginOwnerBuilder, WithdrawMarginAccountBuilder,
+    },
+};
+
+// anchor_lang::error::ErrorCode::ConstraintSeeds
+const CONSTRAINT_SEEDS: u32 = 2006;
+
+const NR: u16 = 3;
+
+/// Starts the program with a V1 TSwap.
+async fn setup() -> (ProgramTestContext, Keypair, Pubkey) {
+    let owner = Keypair::new();
+    let (program_test, tswap) = program_test(&owner);
+    let context = program_test.start_with_context().await;
+    (context, owner, tswap)
+}
+
+/// Creates legacy margin account `nr` of `owner`.
+async fn init_legacy(
+    context: &mut ProgramTestContext,
+    tswap: Pubkey,
+    owner: &Keypair,
+    nr: u16,
+) -> Pubkey {
+    let (margin_account, _) = find_legacy_margin_account_pda(&tswap, &owner.pubkey(), nr);
+    let ix = InitMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .margin_nr(nr)
+        .name([0; 32])
+        .instruction();
+    send(context, &[ix], owner, &[]).await.unwrap();
+    margin_account
+}
+
+fn deposit(tswap: Pubkey, margin_account: Pubkey, owner: Pubkey, lamports: u64) -> Instruction {
+    DepositMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner)
+        .lamports(lamports)
+        .instruction()
+}
+
+fn withdraw(tswap: Pubkey, margin_account: Pubkey, owner: Pubkey, lamports: u64) -> Instruction {
+    WithdrawMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner)
+        .lamports(lamports)
+        .instruction()
+}
+
+async fn margin(context: &mut ProgramTestContext, margin_account: Pubkey) -> MarginAccountV2 {
+    MarginAccountV2::from_bytes(&account_data(context, margin_account).await).unwrap()
+}
+
+#[tokio::test]
+async fn v2_margin_account_is_derived_without_tswap() {
+    let (mut context, owner, tswap) = setup().await;
+
+    let margin_account = init_margin_account(&mut context, tswap, &owner, NR, 0)
+        .await
+        .unwrap();
+
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"margin", owner.pubkey().as_ref(), &NR.to_le_bytes()],
+        &tensor_escrow::ID,
+    );
+    assert_eq!(margin_account, expected);
+    assert_eq!(
+        find_margin_account_v2_pda(&owner.pubkey(), NR),
+        (expected, bump)
+    );
+    let data = margin(&mut context, margin_account).await;
+    assert_eq!(data.owner, owner.pubkey());
+    assert_eq!(data.nr, NR);
+    assert_eq!(data.bump, [bump]);
+}
+
+#[tokio::test]
+async fn v2_init_at_a_legacy_address_fails() {
+    let (mut context, owner, tswap) = setup().await;
+
+    let (legacy, _) = find_legacy_margin_account_pda(&tswap, &owner.pubkey(), NR);
+    let ix = InitMarginAccountV2Builder::new()
+        .margin_account(legacy)
+        .owner(owner.pubkey())
+        .margin_nr(NR)
+        .instruction();
+    let err = send(&mut context, &[ix], &owner, &[]).await.unwrap_err();
+
+    assert_custom_error(err, CONSTRAINT_SEEDS);
+}
+
+#[tokio::test]
+async fn both_families_can_use_the_same_nr() {
+    let (mut context, owner, tswap) = setup().await;
+    let legacy = init_legacy(&mut context, tswap, &owner, NR).await;
+    let margin_account = init_margin_account(&mut context, tswap, &owner, NR, 0)
+        .await
+        .unwrap();
+    assert_ne!(legacy, margin_account);
+
+    let legacy_before = balance(&mut context, legacy).await;
+    let margin_before = balance(&mut context, margin_account).await;
+    let ixs = [
+        deposit(tswap, legacy, owner.pubkey(), LAMPORTS / 4),
+        deposit(tswap, margin_account, owner.pubkey(), LAMPORTS / 2),
+        withdraw(tswap, margin_account, owner.pubkey(), LAMPORTS / 8),
+    ];
+    send(&mut context, &ixs, &owner, &[]).await.unwrap();
+
+    // neither account moves the other's balance
+    assert_eq!(
+        balance(&mut context, legacy).await,
+        legacy_before + LAMPORTS / 4
+    );
+    assert_eq!(
+        balance(&mut context, margin_account).await,
+        margin_before + LAMPORTS / 2 - LAMPORTS / 8
+    );
+    assert_eq!(margin(&mut context, legacy).await.nr, NR);
+    assert_eq!(margin(&mut context, margin_account).await.nr, NR);
+}
+
+#[tokio::test]
+async fn handed_off_accounts_of_both_families_stay_usable() {
+    let (mut context, owner, tswap) = setup().await;
+    let new_owner = Keypair::new();
+    let ix = solana_program::system_instruction::transfer(
+        &owner.pubkey(),
+        &new_owner.pubkey(),
+        LAMPORTS / 10,
+    );
+    send(&mut context, &[ix], &owner, &[]).await.unwrap();
+
+    let legacy = init_legacy(&mut context, tswap, &owner, NR).await;
+    let margin_account = init_margin_account(&mut context, tswap, &owner, NR, 0)
+        .await
+        .unwrap();
+
+    for margin_account in [legacy, margin_account] {
+        let ixs = [
+            deposit(tswap, margin_account, owner.pubkey(), LAMPORTS / 4),
+            ProposeMarginOwnerBuilder::new()
+                .margin_account(margin_account)
+                .owner(owner.pubkey())
+                .new_owner(new_owner.pubkey())
+                .instruction(),
+            AcceptMarginOwnerBuilder::new()
+                .margin_account(margin_account)
+                .new_owner(new_owner.pubkey())
+                .instruction(),
+        ];
+        send(&mut context, &ixs, &owner, &[&new_owner])
+            .await
+            .unwrap();
+
+        let data = margin(&mut context, margin_account).await;
+        assert_eq!(data.owner, new_owner.pubkey());
+        assert_eq!(data.original_owner, owner.pubkey());
+
+        // the address no longer derives from the owner, only from the original one
+        let before = balance(&mut context, margin_account).await;
+        let ix = withdraw(tswap, margin_account, new_owner.pubkey(), LAMPORTS / 8);
+        send(&mut context, &[ix], &new_owner, &[]).await.unwrap();
+        assert_eq!(
+            balance(&mut context, margin_account).await,
+            before - LAMPORTS / 8
+        );
+    }
+}
@@ -0,0 +1,245 @@
+#![cfg(feature = "test-sbf")]
+
+//! The owner and guardian pausing deposits, owner withdrawals and CPI withdrawals.
+
+mod common;
+
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    errors::TensorEscrowError,
+    find_margin_account_v2_pda,
+    instructions::{
+        DepositMarginAccountBuilder, SetGuardianBuilder, SetPausedBuilder,
+        WithdrawMarginAccountBuilder, WithdrawMarginAccountCpiTammBuilder,
+    },
+};
+
+// Mirror the program's `PAUSE_*` flags
+const PAUSE_DEPOSITS: u8 = 1;
+const PAUSE_OWNER_WITHDRAWALS: u8 = 2;
+const PAUSE_TAMM_CPI: u8 = 4;
+
+const AMOUNT: u64 = LAMPORTS / 10;
+
+struct Setup {
+    owner: Keypair,
+    guardian: Keypair,
+    margin_account: Pubkey,
+    pool: (Pubkey, u8, [u8; 32]),
+    destination: Pubkey,
+}
+
+/// Starts the program without a TSwap, with a SOL pool on margin account 0 of the owner.
+fn setup() -> (ProgramTest, Setup) {
+    let owner = Keypair::new();
+    let guardian = Keypair::new();
+    let mut program_test = program_test_without_tswap(&owner);
+    add_callers(&mut program_test);
+    program_test.add_account(guardian.pubkey(), system_account(LAMPORTS));
+
+    let destination = Pubkey::new_unique();
+    program_test.add_account(destination, system_account(LAMPORTS));
+
+    let (margin_account, _) = find_margin_account_v2_pda(&owner.pubkey(), 0);
+    let pool = add_pool(
+        &mut program_test,
+        owner.pubkey(),
+        Pubkey::default(),
+        margin_account,
+    );
+
+    let setup = Setup {
+        owner,
+        guardian,
+        margin_account,
+        pool,
+        destination,
+    };
+    (program_test, setup)
+}
+
+impl Setup {
+    /// Creates the TSwap with the guardian and a funded margin account.
+    async fn init(&self, context: &mut ProgramTestContext) -> Pubkey {
+        let tswap = init_tswap(context, &self.owner, Pubkey::new_unique(), 0)
+            .await
+            .unwrap();
+        let ix = SetGuardianBuilder::new()
+            .tswap(tswap)
+            .owner(self.owner.pubkey())
+            .guardian(self.guardian.pubkey())
+            .instruction();
+        send(context, &[ix], &self.owner, &[]).await.unwrap();
+        init_margin_account(context, tswap, &self.owner, 0, LAMPORTS / 2)
+            .await
+            .unwrap();
+        tswap
+    }
+
+    fn deposit(&self, tswap: Pubkey) -> Instruction {
+        DepositMarginAccountBuilder::new()
+            .tswap(tswap)
+            .margin_account(self.margin_account)
+            .owner(self.owner.pubkey())
+            .lamports(AMOUNT)
+            .instruction()
+    }
+
+    fn withdraw(&self, tswap: Pubkey) -> Instruction {
+        WithdrawMarginAccountBuilder::new()
+            .tswap(tswap)
+            .margin_account(self.margin_account)
+            .owner(self.owner.pubkey())
+            .lamports(AMOUNT)
+            .instruction()
+    }
+
+    fn tamm_withdraw(&self, tswap: Pubkey) -> Instruction {
+        let (pool, bump, pool_id) = self.pool;
+        WithdrawMarginAccountCpiTammBuilder::new()
+            .margin_account(self.margin_account)
+            .pool(pool)
+            .owner(self.owner.pubkey())
+            .destination(self.destination)
+            .tswap(tswap)
+            .bump(bump)
+            .pool_id(pool_id)
+            .lamports(AMOUNT)
+            .instruction()
+    }
+}
+
+fn set_paused(tswap: Pubkey, authority: Pubkey, paused: u8) -> Instruction {
+    SetPausedBuilder::new()
+        .tswap(tswap)
+        .authority(authority)
+        .paused(paused)
+        .instruction()
+}
+
+#[tokio::test]
+async fn guardian_pauses_deposits_and_owner_withdrawals() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = setup.init(&mut context).await;
+
+    let ix = set_paused(
+        tswap,
+        setup.guardian.pubkey(),
+        PAUSE_DEPOSITS | PAUSE_OWNER_WITHDRAWALS,
+    );
+    send(&mut context, &[ix], &setup.guardian, &[])
+        .await
+        .unwrap();
+
+    for ix in [setup.deposit(tswap), setup.withdraw(tswap)] {
+        let err = send(&mut context, &[ix], &setup.owner, &[])
+            .await
+            .unwrap_err();
+        assert_custom_error(err, TensorEscrowError::Paused as u32);
+    }
+
+    // TAMM withdrawals were left open
+    call_through(
+        &mut context,
+        &setup.owner,
+        tamm_id(),
+        setup.tamm_withdraw(tswap),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        balance(&mut context, setup.destination).await,
+        LAMPORTS + AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn pausing_tamm_leaves_owner_withdrawals_open() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = setup.init(&mut context).await;
+
+    let ix = set_paused(tswap, setup.guardian.pubkey(), PAUSE_TAMM_CPI);
+    send(&mut context, &[ix], &setup.guardian, &[])
+        .await
+        .unwrap();
+
+    let err = call_through(
+        &mut context,
+        &setup.owner,
+        tamm_id(),
+        setup.tamm_withdraw(tswap),
+    )
+    .await
+    .unwrap_err();
+    assert_custom_error(err, TensorEscrowError::Paused as u32);
+
+    let margin_before = balance(&mut context, setup.margin_account).await;
+    send(&mut context, &[setup.withdraw(tswap)], &setup.owner, &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before - AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn only_the_owner_can_unpause() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = setup.init(&mut context).await;
+
+    let ix = set_paused(tswap, setup.guardian.pubkey(), PAUSE_DEPOSITS);
+    send(&mut context, &[ix], &setup.guardian, &[])
+        .await
+        .unwrap();
+
+    let ix = set_paused(tswap, setup.guardian.pubkey(), 0);
+    let err = send(&mut context, &[ix], &setup.guardian, &[])
+        .await
+        .unwrap_err();
+    assert_custom_error(err, TensorEscrowError::OnlyOwnerCanUnpause as u32);
+
+    let ix = set_paused(tswap, setup.owner.pubkey(), 0);
+    send(&mut context, &[ix], &setup.owner, &[]).await.unwrap();
+    send(&mut context, &[setup.deposit(tswap)], &setup.owner, &[])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn strangers_cannot_pause_or_set_the_guardian() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = setup.init(&mut context).await;
+
+    // the guardian can pause, but not hand on its role
+    let ix = SetGuardianBuilder::new()
+        .tswap(tswap)
+        .owner(setup.guardian.pubkey())
+        .guardian(Pubkey::new_unique())
+        .instruction();
+    let err = send(&mut context, &[ix], &setup.guardian, &[])
+        .await
+        .unwrap_err();
+    assert_custom_error(err, TensorEscrowError::BadOwner as u32);
+
+    let stranger = Keypair::new();
+    let ix = set_paused(tswap, stranger.pubkey(), PAUSE_DEPOSITS);
+    let err = send(&mut context, &[ix], &setup.owner, &[&stranger])
+        .await
+        .unwrap_err();
+    assert_custom_error(err, TensorEscrowError::BadOwner as u32);
+
+    send(&mut context, &[setup.deposit(tswap)], &setup.owner, &[])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,242 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use borsh::BorshSerialize;
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::{TSwap, TSwapV2},
+    errors::TensorEscrowError,
+    find_margin_account_v2_pda,
+    instructions::{SetRateLimitBuilder, WithdrawMarginAccountCpiTammBuilder},
+    types::{Caller, RateLimit, TSwapConfig},
+};
+
+const LIMIT: u64 = 1_000_000_000;
+const WINDOW: u32 = 3_600;
+
+fn rate_limit() -> RateLimit {
+    RateLimit {
+        limit: 0,
+        window: 0,
+        window_start: 0,
+        current: 0,
+        previous: 0,
+    }
+}
+
+/// Starts the program with a TSwap of `owner`, migrated to V2 when `v2` is set.
+fn setup(owner: &Keypair, v2: bool) -> (ProgramTest, Pubkey) {
+    let mut program_test = ProgramTest::new("escrow_program", tensor_escrow::ID, None);
+    program_test.add_account(owner.pubkey(), system_account(LAMPORTS));
+
+    let (tswap, bump) = TSwap::find_pda();
+    let data = if v2 {
+        TSwapV2 {
+            discriminator: TSWAP_DISCRIMINATOR,
+            version: 2,
+            bump: [bump],
+            config: TSwapConfig { fee_bps: 0 },
+            owner: owner.pubkey(),
+            fee_vault: Pubkey::new_unique(),
+            cosigner: Pubkey::new_unique(),
+            pending_owner: Pubkey::default(),
+            guardian: Pubkey::default(),
+            paused: 0,
+            tamm_rate_limit: rate_limit(),
+            tcomp_rate_limit: rate_limit(),
+            fees_enabled: false,
+            tamm_fee_bps: None,
+            tcomp_fee_bps: None,
+            cosign_threshold: None,
+            hold_expiry_slots: 0,
+            reserved: [0; 95],
+        }
+        .try_to_vec()
+    } else {
+        TSwap {
+            discriminator: TSWAP_DISCRIMINATOR,
+            version: 1,
+            bump: [bump],
+            config: TSwapConfig { fee_bps: 0 },
+            owner: owner.pubkey(),
+            fee_vault: Pubkey::new_unique(),
+            cosigner: Pubkey::new_unique(),
+        }
+        .try_to_vec()
+    };
+    program_test.add_account(tswap, program_account(data.unwrap(), LAMPORTS));
+
+    (program_test, tswap)
+}
+
+#[tokio::test]
+async fn set_rate_limit() {
+    let owner = Keypair::new();
+    let (program_test, tswap) = setup(&owner, true);
+    let mut context = program_test.start_with_context().await;
+
+    let ix = SetRateLimitBuilder::new()
+        .tswap(tswap)
+        .owner(owner.pubkey())
+        .caller(Caller::Tcomp)
+        .limit(LIMIT)
+        .window(WINDOW)
+        .instruction();
+    send(&mut context, &[ix], &owner, &[]).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(tswap)
+        .await
+        .unwrap()
+        .unwrap();
+    let data = TSwapV2::from_bytes(&account.data).unwrap();
+    assert_eq!(data.tcomp_rate_limit.limit, LIMIT);
+    assert_eq!(data.tcomp_rate_limit.window, WINDOW);
+    assert_eq!(data.tamm_rate_limit, rate_limit());
+}
+
+#[tokio::test]
+async fn set_rate_limit_on_v1_tswap_fails() {
+    let owner = Keypair::new();
+    let (program_test, tswap) = setup(&owner, false);
+    let mut context = program_test.start_with_context().await;
+
+    let ix = SetRateLimitBuilder::new()
+        .tswap(tswap)
+        .owner(owner.pubkey())
+        .caller(Caller::TAmm)
+        .limit(LIMIT)
+        .window(WINDOW)
+        .instruction();
+    let err = send(&mut context, &[ix], &owner, &[]).await.unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::TSwapNotMigrated as u32);
+}
+
+struct PoolSetup {
+    owner: Keypair,
+    margin_account: Pubkey,
+    pool: (Pubkey, u8, [u8; 32]),
+}
+
+/// Starts the program with a SOL pool on margin account 0 of the owner, TSwap and the margin
+/// account are created once the program runs.
+fn pool_setup() -> (ProgramTest, PoolSetup) {
+    let owner = Keypair::new();
+    let mut program_test = program_test_without_tswap(&owner);
+    add_callers(&mut program_test);
+
+    let (margin_account, _) = find_margin_account_v2_pda(&owner.pubkey(), 0);
+    let pool = add_pool(
+        &mut program_test,
+        owner.pubkey(),
+        Pubkey::default(),
+        margin_account,
+    );
+
+    let setup = PoolSetup {
+        owner,
+        margin_account,
+        pool,
+    };
+    (program_test, setup)
+}
+
+async fn init_accounts(context: &mut ProgramTestContext, setup: &PoolSetup) -> Pubkey {
+    let tswap = init_tswap(context, &setup.owner, Pubkey::new_unique(), 0)
+        .await
+        .unwrap();
+    init_margin_account(context, tswap, &setup.owner, 0, LAMPORTS)
+        .await
+        .unwrap();
+    tswap
+}
+
+fn tamm_withdraw(setup: &PoolSetup, tswap: Pubkey) -> Instruction {
+    let (pool, bump, pool_id) = setup.pool;
+    WithdrawMarginAccountCpiTammBuilder::new()
+        .margin_account(setup.margin_account)
+        .pool(pool)
+        .owner(setup.owner.pubkey())
+        .destination(setup.owner.pubkey())
+        .tswap(tswap)
+        .bump(bump)
+        .pool_id(pool_id)
+        .lamports(BID_AMOUNT)
+        .instruction()
+}
+
+#[tokio::test]
+async fn tamm_withdrawals_over_the_limit_fail() {
+    let (program_test, setup) = pool_setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = init_accounts(&mut context, &setup).await;
+
+    let set_limit_ix = SetRateLimitBuilder::new()
+        .tswap(tswap)
+        .owner(setup.owner.pubkey())
+        .caller(Caller::TAmm)
+        .limit(BID_AMOUNT)
+        .window(WINDOW)
+        .instruction();
+    send(&mut context, &[set_limit_ix], &setup.owner, &[])
+        .await
+        .unwrap();
+
+    call_through(
+        &mut context,
+        &setup.owner,
+        tamm_id(),
+        tamm_withdraw(&setup, tswap),
+    )
+    .await
+    .unwrap();
+    let margin_before = balance(&mut context, setup.margin_account).await;
+
+    // Same window, the whole limit is used up
+    context.get_new_latest_blockhash().await.unwrap();
+    let err = call_through(
+        &mut context,
+        &setup.owner,
+        tamm_id(),
+        tamm_withdraw(&setup, tswap),
+    )
+    .await
+    .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::RateLimitExceeded as u32);
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before
+    );
+}
+
+#[tokio::test]
+async fn tamm_withdrawal_without_tswap_fails() {
+    let (program_test, setup) = pool_setup();
+    let mut context = program_test.start_with_context().await;
+    let tswap = init_accounts(&mut context, &setup).await;
+    let margin_before = balance(&mut context, setup.margin_account).await;
+
+    // Like a caller built against the old interface, which stops after the system program
+    let mut ix = tamm_withdraw(&setup, tswap);
+    ix.accounts.truncate(5);
+    let err = call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap_err();
+
+    // anchor's AccountNotEnoughKeys
+    assert_custom_error(err, 3005);
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before
+    );
+}
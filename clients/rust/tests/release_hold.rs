@@ -0,0 +1,208 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use borsh::BorshSerialize;
+use common::*;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::{Hold, MarginAccountV2},
+    errors::TensorEscrowError,
+    instructions::{ReleaseHoldBuilder, WithdrawMarginAccountBuilder},
+    types::Caller,
+};
+
+const HOLD_DISCRIMINATOR: [u8; 8] = [110, 65, 238, 142, 146, 91, 196, 171];
+
+const AMOUNT: u64 = 100_000_000;
+const EXPIRY_SLOT: u64 = 100;
+
+struct Setup {
+    program_test: ProgramTest,
+    tswap: Pubkey,
+    owner: Keypair,
+    /// Stands in for the TAMM pool or TCOMP bid that placed the hold.
+    caller_account: Keypair,
+    /// Anyone else, pays for the transactions that aren't the owner's.
+    anyone: Keypair,
+    rent_payer: Pubkey,
+    margin_account: Pubkey,
+    hold: Pubkey,
+}
+
+/// Starts the program with a V2 margin account holding `AMOUNT` for a hold expiring
+/// after `EXPIRY_SLOT`.
+fn setup() -> Setup {
+    let owner = Keypair::new();
+    let caller_account = Keypair::new();
+    let anyone = Keypair::new();
+    let rent_payer = Pubkey::new_unique();
+    let (mut program_test, tswap) = program_test(&owner);
+    program_test.add_account(caller_account.pubkey(), system_account(LAMPORTS));
+    program_test.add_account(anyone.pubkey(), system_account(LAMPORTS));
+    program_test.add_account(rent_payer, system_account(LAMPORTS));
+
+    let margin_account = add_margin_v2(&mut program_test, &owner.pubkey(), 0, |margin| {
+        margin.held_lamports = AMOUNT;
+    });
+
+    let hold_id = Pubkey::new_unique().to_bytes();
+    let (hold, bump) = Hold::find_pda(&margin_account, &caller_account.pubkey(), hold_id);
+    let data = Hold {
+        discriminator: HOLD_DISCRIMINATOR,
+        version: 1,
+        bump: [bump],
+        margin_account,
+        caller_account: caller_account.pubkey(),
+        caller: Caller::TAmm,
+        hold_id,
+        rent_payer,
+        amount: AMOUNT,
+        expiry_slot: EXPIRY_SLOT,
+        reserved: [0; 32],
+    };
+    program_test.add_account(hold, program_account(data.try_to_vec().unwrap(), LAMPORTS));
+
+    Setup {
+        program_test,
+        tswap,
+        owner,
+        caller_account,
+        anyone,
+        rent_payer,
+        margin_account,
+        hold,
+    }
+}
+
+fn release(setup: &Setup, authority: Option<Pubkey>) -> Instruction {
+    ReleaseHoldBuilder::new()
+        .margin_account(setup.margin_account)
+        .hold(setup.hold)
+        .authority(authority)
+        .rent_payer(setup.rent_payer)
+        .instruction()
+}
+
+async fn held_lamports(context: &mut ProgramTestContext, margin_account: Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(margin_account)
+        .await
+        .unwrap()
+        .unwrap();
+    MarginAccountV2::from_bytes(&account.data)
+        .unwrap()
+        .held_lamports
+}
+
+async fn assert_released(
+    context: &mut ProgramTestContext,
+    margin_account: Pubkey,
+    hold: Pubkey,
+    rent_payer: Pubkey,
+) {
+    assert_eq!(held_lamports(context, margin_account).await, 0);
+    assert!(context
+        .banks_client
+        .get_account(hold)
+        .await
+        .unwrap()
+        .is_none());
+    // refunded the hold's rent
+    assert_eq!(balance(context, rent_payer).await, 2 * LAMPORTS);
+}
+
+#[tokio::test]
+async fn release_hold_by_its_caller() {
+    let setup = setup();
+    let ix = release(&setup, Some(setup.caller_account.pubkey()));
+
+    let mut context = setup.program_test.start_with_context().await;
+    send(&mut context, &[ix], &setup.caller_account, &[])
+        .await
+        .unwrap();
+
+    assert_released(
+        &mut context,
+        setup.margin_account,
+        setup.hold,
+        setup.rent_payer,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn release_unexpired_hold_by_anyone_fails() {
+    let setup = setup();
+    let ix = release(&setup, Some(setup.anyone.pubkey()));
+
+    let mut context = setup.program_test.start_with_context().await;
+    let err = send(&mut context, &[ix], &setup.anyone, &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::HoldNotExpired as u32);
+    assert_eq!(
+        held_lamports(&mut context, setup.margin_account).await,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn release_expired_hold_by_anyone() {
+    let setup = setup();
+    let ix = release(&setup, None);
+
+    let mut context = setup.program_test.start_with_context().await;
+    context.warp_to_slot(EXPIRY_SLOT + 1).unwrap();
+    send(&mut context, &[ix], &setup.anyone, &[]).await.unwrap();
+
+    assert_released(
+        &mut context,
+        setup.margin_account,
+        setup.hold,
+        setup.rent_payer,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn expired_hold_stays_locked_until_released() {
+    let setup = setup();
+    let available = LAMPORTS - Rent::default().minimum_balance(MarginAccountV2::LEN);
+    let withdraw = WithdrawMarginAccountBuilder::new()
+        .tswap(setup.tswap)
+        .margin_account(setup.margin_account)
+        .owner(setup.owner.pubkey())
+        .lamports(available)
+        .instruction();
+    let release = release(&setup, None);
+
+    let mut context = setup.program_test.start_with_context().await;
+    context.warp_to_slot(EXPIRY_SLOT + 1).unwrap();
+
+    let err = send(
+        &mut context,
+        std::slice::from_ref(&withdraw),
+        &setup.owner,
+        &[],
+    )
+    .await
+    .unwrap_err();
+    assert_custom_error(err, TensorEscrowError::InsufficientAvailableBalance as u32);
+
+    send(&mut context, &[release, withdraw], &setup.owner, &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        LAMPORTS - available
+    );
+}
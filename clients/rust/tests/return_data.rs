@@ -0,0 +1,52 @@
+use borsh::BorshSerialize;
+use tensor_escrow::{types::CpiReturnData, CPI_RETURN_DATA_LEN};
+
+fn return_data() -> CpiReturnData {
+    CpiReturnData {
+        amount: 1_000,
+        balance: 5_000,
+        rent: 1_500,
+    }
+}
+
+#[test]
+fn decode_return_data() {
+    let data = return_data().try_to_vec().unwrap();
+    assert_eq!(data.len(), CPI_RETURN_DATA_LEN);
+
+    assert_eq!(CpiReturnData::from_bytes(&data).unwrap(), return_data());
+}
+
+#[test]
+fn decode_return_data_without_trailing_zeros() {
+    // token withdrawals leave `rent` at 0, which the runtime strips
+    let expected = CpiReturnData {
+        rent: 0,
+        ..return_data()
+    };
+    let data = expected.try_to_vec().unwrap();
+    let stripped = &data[..data.iter().rposition(|byte| *byte != 0).unwrap() + 1];
+    assert!(stripped.len() < CPI_RETURN_DATA_LEN);
+
+    assert_eq!(CpiReturnData::from_bytes(stripped).unwrap(), expected);
+}
+
+#[test]
+fn decode_empty_return_data() {
+    assert_eq!(
+        CpiReturnData::from_bytes(&[]).unwrap(),
+        CpiReturnData {
+            amount: 0,
+            balance: 0,
+            rent: 0,
+        }
+    );
+}
+
+#[test]
+fn too_long_return_data_fails() {
+    let mut data = return_data().try_to_vec().unwrap();
+    data.push(1);
+
+    assert!(CpiReturnData::from_bytes(&data).is_err());
+}
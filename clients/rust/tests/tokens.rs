@@ -0,0 +1,451 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    errors::TensorEscrowError,
+    find_margin_account_v2_pda,
+    instructions::{
+        DepositMarginAccountTokenBuilder, WithdrawMarginAccountTokenBuilder,
+        WithdrawMarginAccountTokenCpiTammBuilder, WithdrawMarginAccountTokenCpiTcompBuilder,
+    },
+};
+
+const TOKEN_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+// Mirrors the program's `WITHDRAW_ALL`
+const WITHDRAW_ALL: u64 = u64::MAX;
+
+// spl_token::error::TokenError::InsufficientFunds
+const INSUFFICIENT_FUNDS: u32 = 1;
+// anchor_lang::error::ErrorCode
+const CONSTRAINT_ASSOCIATED: u32 = 2009;
+const CONSTRAINT_TOKEN_MINT: u32 = 2014;
+
+/// Tokens the owner starts with.
+const TOKENS: u64 = 1_000;
+const DEPOSIT: u64 = 600;
+
+fn ata(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Packed `spl_token::state::Mint` without authorities.
+fn mint_data() -> Vec<u8> {
+    let mut data = vec![0; 82];
+    // mint_authority: COption::None (36), then supply, decimals (0) and is_initialized
+    data[36..44].copy_from_slice(&TOKENS.to_le_bytes());
+    data[45] = 1;
+    data
+}
+
+/// Packed, initialized `spl_token::state::Account`.
+fn token_account_data(mint: Pubkey, owner: Pubkey, amount: u64) -> Vec<u8> {
+    let mut data = vec![0; 165];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    // delegate: COption::None (36), then AccountState::Initialized; is_native,
+    // delegated_amount and close_authority are all unset
+    data[108] = 1;
+    data
+}
+
+fn add_mint(program_test: &mut ProgramTest) -> Pubkey {
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, account(mint_data(), LAMPORTS, TOKEN_PROGRAM_ID));
+    mint
+}
+
+/// Adds a token account of `owner` holding `amount`, at the associated address.
+fn add_ata(program_test: &mut ProgramTest, owner: Pubkey, mint: Pubkey, amount: u64) -> Pubkey {
+    let address = ata(&owner, &mint);
+    program_test.add_account(
+        address,
+        account(
+            token_account_data(mint, owner, amount),
+            LAMPORTS,
+            TOKEN_PROGRAM_ID,
+        ),
+    );
+    address
+}
+
+async fn token_balance(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    let data = account_data(context, address).await;
+    u64::from_le_bytes(data[64..72].try_into().unwrap())
+}
+
+struct Setup {
+    owner: Keypair,
+    tswap: Pubkey,
+    mint: Pubkey,
+    margin_account: Pubkey,
+    margin_ta: Pubkey,
+    owner_ta: Pubkey,
+    /// Token account of a seller, where TAMM and TCOMP pay out to.
+    seller_ta: Pubkey,
+}
+
+/// Starts the program with a mint the owner holds `TOKENS` of, margin account 0 of the
+/// owner is left to the tests to create.
+fn setup() -> (ProgramTest, Setup) {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    add_callers(&mut program_test);
+
+    let mint = add_mint(&mut program_test);
+    let owner_ta = add_ata(&mut program_test, owner.pubkey(), mint, TOKENS);
+    let seller_ta = add_ata(&mut program_test, Pubkey::new_unique(), mint, 0);
+    let (margin_account, _) = find_margin_account_v2_pda(&owner.pubkey(), 0);
+
+    let setup = Setup {
+        tswap,
+        mint,
+        margin_account,
+        margin_ta: ata(&margin_account, &mint),
+        owner_ta,
+        seller_ta,
+        owner,
+    };
+    (program_test, setup)
+}
+
+impl Setup {
+    fn deposit(&self, amount: u64) -> Instruction {
+        DepositMarginAccountTokenBuilder::new()
+            .tswap(self.tswap)
+            .margin_account(self.margin_account)
+            .margin_ta(self.margin_ta)
+            .owner_ta(self.owner_ta)
+            .mint(self.mint)
+            .owner(self.owner.pubkey())
+            .amount(amount)
+            .instruction()
+    }
+
+    fn withdraw(&self, amount: u64) -> Instruction {
+        WithdrawMarginAccountTokenBuilder::new()
+            .tswap(self.tswap)
+            .margin_account(self.margin_account)
+            .margin_ta(self.margin_ta)
+            .owner_ta(self.owner_ta)
+            .mint(self.mint)
+            .owner(self.owner.pubkey())
+            .amount(amount)
+            .instruction()
+    }
+
+    /// Creates the margin account and deposits `amount` tokens into it.
+    async fn init_with_deposit(&self, context: &mut ProgramTestContext, amount: u64) {
+        init_margin_account(context, self.tswap, &self.owner, 0, 0)
+            .await
+            .unwrap();
+        send(context, &[self.deposit(amount)], &self.owner, &[])
+            .await
+            .unwrap();
+    }
+
+    async fn send(
+        &self,
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+    ) -> Result<(), BanksClientError> {
+        send(context, &[ix], &self.owner, &[]).await
+    }
+}
+
+#[tokio::test]
+async fn deposit_and_withdraw_tokens() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+
+    setup.init_with_deposit(&mut context, DEPOSIT).await;
+    assert_eq!(token_balance(&mut context, setup.margin_ta).await, DEPOSIT);
+    assert_eq!(
+        token_balance(&mut context, setup.owner_ta).await,
+        TOKENS - DEPOSIT
+    );
+
+    setup
+        .send(&mut context, setup.withdraw(DEPOSIT / 3))
+        .await
+        .unwrap();
+    assert_eq!(
+        token_balance(&mut context, setup.margin_ta).await,
+        DEPOSIT - DEPOSIT / 3
+    );
+
+    setup
+        .send(&mut context, setup.withdraw(WITHDRAW_ALL))
+        .await
+        .unwrap();
+    assert_eq!(token_balance(&mut context, setup.margin_ta).await, 0);
+    assert_eq!(token_balance(&mut context, setup.owner_ta).await, TOKENS);
+}
+
+#[tokio::test]
+async fn deposit_more_tokens_than_held_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    init_margin_account(&mut context, setup.tswap, &setup.owner, 0, 0)
+        .await
+        .unwrap();
+
+    let err = setup
+        .send(&mut context, setup.deposit(TOKENS + 1))
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, INSUFFICIENT_FUNDS);
+    assert_eq!(token_balance(&mut context, setup.owner_ta).await, TOKENS);
+}
+
+#[tokio::test]
+async fn withdraw_more_tokens_than_deposited_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    setup.init_with_deposit(&mut context, DEPOSIT).await;
+
+    let err = setup
+        .send(&mut context, setup.withdraw(DEPOSIT + 1))
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, INSUFFICIENT_FUNDS);
+    assert_eq!(token_balance(&mut context, setup.margin_ta).await, DEPOSIT);
+}
+
+#[tokio::test]
+async fn deposit_from_token_account_of_another_mint_fails() {
+    let (mut program_test, mut setup) = setup();
+    let other_mint = add_mint(&mut program_test);
+    setup.owner_ta = add_ata(&mut program_test, setup.owner.pubkey(), other_mint, TOKENS);
+    let mut context = program_test.start_with_context().await;
+    init_margin_account(&mut context, setup.tswap, &setup.owner, 0, 0)
+        .await
+        .unwrap();
+
+    let err = setup
+        .send(&mut context, setup.deposit(DEPOSIT))
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, CONSTRAINT_TOKEN_MINT);
+}
+
+#[tokio::test]
+async fn withdraw_from_vault_of_another_mint_fails() {
+    let (mut program_test, setup) = setup();
+    let other_mint = add_mint(&mut program_test);
+    let mut context = program_test.start_with_context().await;
+    setup.init_with_deposit(&mut context, DEPOSIT).await;
+
+    // the vault of `mint` isn't the associated token account for `other_mint`
+    let mut ix = setup.withdraw(DEPOSIT);
+    ix.accounts[4].pubkey = other_mint;
+    ix.accounts[3].pubkey = ata(&setup.owner.pubkey(), &other_mint);
+    let err = setup.send(&mut context, ix).await.unwrap_err();
+
+    assert_custom_error(err, CONSTRAINT_ASSOCIATED);
+    assert_eq!(token_balance(&mut context, setup.margin_ta).await, DEPOSIT);
+}
+
+fn tamm_withdraw(setup: &Setup, pool: (Pubkey, u8, [u8; 32]), amount: u64) -> Instruction {
+    let (pool, bump, pool_id) = pool;
+    WithdrawMarginAccountTokenCpiTammBuilder::new()
+        .margin_account(setup.margin_account)
+        .pool(pool)
+        .owner(setup.owner.pubkey())
+        .margin_ta(setup.margin_ta)
+        .destination_ta(setup.seller_ta)
+        .mint(setup.mint)
+        .tswap(setup.tswap)
+        .bump(bump)
+        .pool_id(pool_id)
+        .amount(amount)
+        .instruction()
+}
+
+fn tcomp_withdraw(setup: &Setup, bid: (Pubkey, u8, Pubkey), amount: u64) -> Instruction {
+    let (bid_state, bump, bid_id) = bid;
+    WithdrawMarginAccountTokenCpiTcompBuilder::new()
+        .margin_account(setup.margin_account)
+        .bid_state(bid_state)
+        .owner(setup.owner.pubkey())
+        .margin_ta(setup.margin_ta)
+        .destination_ta(setup.seller_ta)
+        .mint(setup.mint)
+        .tswap(setup.tswap)
+        .bump(bump)
+        .bid_id(bid_id)
+        .amount(amount)
+        .instruction()
+}
+
+#[tokio::test]
+async fn tamm_token_withdrawal() {
+    let (mut program_test, setup) = setup();
+    let pool = add_pool(
+        &mut program_test,
+        setup.owner.pubkey(),
+        setup.mint,
+        setup.margin_account,
+    );
+    let mut context = program_test.start_with_context().await;
+    setup.init_with_deposit(&mut context, DEPOSIT).await;
+
+    let ix = tamm_withdraw(&setup, pool, DEPOSIT / 3);
+    call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, setup.seller_ta).await,
+        DEPOSIT / 3
+    );
+    assert_eq!(
+        token_balance(&mut context, setup.margin_ta).await,
+        DEPOSIT - DEPOSIT / 3
+    );
+}
+
+#[tokio::test]
+async fn tamm_token_withdrawal_over_the_balance_fails() {
+    let (mut program_test, setup) = setup();
+    let pool = add_pool(
+        &mut program_test,
+        setup.owner.pubkey(),
+        setup.mint,
+        setup.margin_account,
+    );
+    let mut context = program_test.start_with_context().await;
+    setup.init_with_deposit(&mut context, DEPOSIT).await;
+
+    let ix = tamm_withdraw(&setup, pool, DEPOSIT + 1);
+    let err = call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap_err();
+    assert_custom_error(err, INSUFFICIENT_FUNDS);
+
+    // WITHDRAW_ALL is only honoured for the owner
+    let ix = tamm_withdraw(&setup, pool, WITHDRAW_ALL);
+    let err = call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap_err();
+    assert_custom_error(err, INSUFFICIENT_FUNDS);
+
+    assert_eq!(token_balance(&mut context, setup.margin_ta).await, DEPOSIT);
+}
+
+#[tokio::test]
+async fn tamm_token_withdrawal_for_sol_pool_fails() {
+    let (mut program_test, setup) = setup();
+    let pool = add_pool(
+        &mut program_test,
+        setup.owner.pubkey(),
+        Pubkey::default(),
+        setup.margin_account,
+    );
+    let mut context = program_test.start_with_context().await;
+    setup.init_with_deposit(&mut context, DEPOSIT).await;
+
+    let ix = tamm_withdraw(&setup, pool, DEPOSIT);
+    let err = call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::CurrencyMismatch as u32);
+}
+
+#[tokio::test]
+async fn tcomp_token_withdrawal() {
+    let (mut program_test, setup) = setup();
+    let bid = add_bid(
+        &mut program_test,
+        setup.owner.pubkey(),
+        Bid {
+            quantity: 1,
+            filled_quantity: 0,
+            currency: Some(setup.mint),
+            margin: setup.margin_account,
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+    setup.init_with_deposit(&mut context, DEPOSIT).await;
+
+    let ix = tcomp_withdraw(&setup, bid, DEPOSIT);
+    call_through(&mut context, &setup.owner, tcomp_id(), ix)
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut context, setup.seller_ta).await, DEPOSIT);
+    assert_eq!(token_balance(&mut context, setup.margin_ta).await, 0);
+}
+
+#[tokio::test]
+async fn tcomp_token_withdrawal_over_the_bid_fails() {
+    let (mut program_test, setup) = setup();
+    let bid = add_bid(
+        &mut program_test,
+        setup.owner.pubkey(),
+        Bid {
+            quantity: 1,
+            filled_quantity: 0,
+            currency: Some(setup.mint),
+            margin: setup.margin_account,
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+    setup.init_with_deposit(&mut context, DEPOSIT).await;
+
+    // WITHDRAW_ALL is only honoured for the owner, TCOMP is held to the bid amount
+    let ix = tcomp_withdraw(&setup, bid, WITHDRAW_ALL);
+    let err = call_through(&mut context, &setup.owner, tcomp_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::AmountExceedsBid as u32);
+    assert_eq!(token_balance(&mut context, setup.margin_ta).await, DEPOSIT);
+}
+
+#[tokio::test]
+async fn tcomp_token_withdrawal_to_account_of_another_mint_fails() {
+    let (mut program_test, mut setup) = setup();
+    let bid = add_bid(
+        &mut program_test,
+        setup.owner.pubkey(),
+        Bid {
+            quantity: 1,
+            filled_quantity: 0,
+            currency: Some(setup.mint),
+            margin: setup.margin_account,
+        },
+    );
+    let other_mint = add_mint(&mut program_test);
+    let other_seller_ta = add_ata(&mut program_test, Pubkey::new_unique(), other_mint, 0);
+    let mut context = program_test.start_with_context().await;
+    setup.init_with_deposit(&mut context, DEPOSIT).await;
+
+    setup.seller_ta = other_seller_ta;
+    let ix = tcomp_withdraw(&setup, bid, DEPOSIT);
+    let err = call_through(&mut context, &setup.owner, tcomp_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, CONSTRAINT_TOKEN_MINT);
+}
@@ -0,0 +1,259 @@
+#![cfg(feature = "test-sbf")]
+
+//! Program owner updates to the TSwap, each through its own instruction.
+
+mod common;
+
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::{TSwap, TSwapV2},
+    errors::TensorEscrowError,
+    instructions::{
+        AcceptTswapOwnerBuilder, InitTswapBuilder, ProposeTswapOwnerBuilder, SetCosignerBuilder,
+        SetFeeVaultBuilder, UpdateTswapConfigBuilder,
+    },
+    types::TSwapConfig,
+};
+
+// Mirrors the program's MAX_FEE_BPS.
+const MAX_FEE_BPS: u16 = 1000;
+
+/// Starts the program with the TSwap of a funded owner, and a funded stranger.
+async fn setup() -> (ProgramTestContext, Keypair, Keypair, Pubkey) {
+    let owner = Keypair::new();
+    let stranger = Keypair::new();
+    let mut program_test = program_test_without_tswap(&owner);
+    program_test.add_account(stranger.pubkey(), system_account(LAMPORTS));
+
+    let mut context = program_test.start_with_context().await;
+    let tswap = init_tswap(&mut context, &owner, Pubkey::new_unique(), 0)
+        .await
+        .unwrap();
+    (context, owner, stranger, tswap)
+}
+
+async fn tswap_v2(context: &mut ProgramTestContext, tswap: Pubkey) -> TSwapV2 {
+    TSwapV2::from_bytes(&account_data(context, tswap).await).unwrap()
+}
+
+fn update_config(tswap: Pubkey, owner: Pubkey, fee_bps: u16) -> Instruction {
+    UpdateTswapConfigBuilder::new()
+        .tswap(tswap)
+        .owner(owner)
+        .config(TSwapConfig { fee_bps })
+        .instruction()
+}
+
+#[tokio::test]
+async fn init_tswap_sets_the_owner_cosigner_and_fee_vault() {
+    let owner = Keypair::new();
+    let cosigner = Keypair::new();
+    let fee_vault = Pubkey::new_unique();
+    let mut context = program_test_without_tswap(&owner)
+        .start_with_context()
+        .await;
+
+    let (tswap, _) = TSwap::find_pda();
+    let ix = InitTswapBuilder::new()
+        .tswap(tswap)
+        .fee_vault(fee_vault)
+        .cosigner(cosigner.pubkey())
+        .owner(owner.pubkey())
+        .config(TSwapConfig { fee_bps: 50 })
+        .instruction();
+    send(&mut context, &[ix], &owner, &[&cosigner])
+        .await
+        .unwrap();
+
+    let data = tswap_v2(&mut context, tswap).await;
+    assert_eq!(data.owner, owner.pubkey());
+    assert_eq!(data.cosigner, cosigner.pubkey());
+    assert_eq!(data.fee_vault, fee_vault);
+    assert_eq!(data.config.fee_bps, 50);
+    assert_eq!(data.pending_owner, Pubkey::default());
+}
+
+#[tokio::test]
+async fn init_tswap_twice_fails() {
+    let (mut context, _, stranger, _) = setup().await;
+
+    // the TSwap already exists, so nobody can take it over with a second init
+    let err = init_tswap(&mut context, &stranger, Pubkey::new_unique(), 0)
+        .await
+        .unwrap_err();
+
+    // system_program's AccountAlreadyInUse
+    assert_custom_error(err, 0);
+}
+
+#[tokio::test]
+async fn init_tswap_over_the_max_fee_fails() {
+    let owner = Keypair::new();
+    let mut context = program_test_without_tswap(&owner)
+        .start_with_context()
+        .await;
+
+    let err = init_tswap(&mut context, &owner, Pubkey::new_unique(), MAX_FEE_BPS + 1)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::FeeBpsTooHigh as u32);
+}
+
+#[tokio::test]
+async fn update_tswap_config() {
+    let (mut context, owner, _, tswap) = setup().await;
+
+    let ix = update_config(tswap, owner.pubkey(), MAX_FEE_BPS);
+    send(&mut context, &[ix], &owner, &[]).await.unwrap();
+
+    assert_eq!(
+        tswap_v2(&mut context, tswap).await.config.fee_bps,
+        MAX_FEE_BPS
+    );
+}
+
+#[tokio::test]
+async fn update_tswap_config_over_the_max_fee_fails() {
+    let (mut context, owner, _, tswap) = setup().await;
+
+    let ix = update_config(tswap, owner.pubkey(), MAX_FEE_BPS + 1);
+    let err = send(&mut context, &[ix], &owner, &[]).await.unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::FeeBpsTooHigh as u32);
+    assert_eq!(tswap_v2(&mut context, tswap).await.config.fee_bps, 0);
+}
+
+#[tokio::test]
+async fn set_cosigner() {
+    let (mut context, owner, _, tswap) = setup().await;
+    let cosigner = Keypair::new();
+
+    let ix = SetCosignerBuilder::new()
+        .tswap(tswap)
+        .owner(owner.pubkey())
+        .cosigner(cosigner.pubkey())
+        .instruction();
+    send(&mut context, &[ix], &owner, &[&cosigner])
+        .await
+        .unwrap();
+
+    let data = tswap_v2(&mut context, tswap).await;
+    assert_eq!(data.cosigner, cosigner.pubkey());
+    assert_eq!(data.owner, owner.pubkey());
+}
+
+#[tokio::test]
+async fn set_fee_vault() {
+    let (mut context, owner, _, tswap) = setup().await;
+    let fee_vault = Pubkey::new_unique();
+
+    let ix = SetFeeVaultBuilder::new()
+        .tswap(tswap)
+        .owner(owner.pubkey())
+        .fee_vault(fee_vault)
+        .instruction();
+    send(&mut context, &[ix], &owner, &[]).await.unwrap();
+
+    assert_eq!(tswap_v2(&mut context, tswap).await.fee_vault, fee_vault);
+}
+
+#[tokio::test]
+async fn set_fee_vault_to_a_program_account_fails() {
+    let (mut context, owner, _, tswap) = setup().await;
+
+    // owned by the loader, not a wallet
+    let ix = SetFeeVaultBuilder::new()
+        .tswap(tswap)
+        .owner(owner.pubkey())
+        .fee_vault(tensor_escrow::ID)
+        .instruction();
+    let err = send(&mut context, &[ix], &owner, &[]).await.unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::BadFeeVault as u32);
+}
+
+#[tokio::test]
+async fn propose_and_accept_tswap_owner() {
+    let (mut context, owner, new_owner, tswap) = setup().await;
+
+    let ix = ProposeTswapOwnerBuilder::new()
+        .tswap(tswap)
+        .owner(owner.pubkey())
+        .new_owner(new_owner.pubkey())
+        .instruction();
+    send(&mut context, &[ix], &owner, &[]).await.unwrap();
+
+    let data = tswap_v2(&mut context, tswap).await;
+    assert_eq!(data.owner, owner.pubkey());
+    assert_eq!(data.pending_owner, new_owner.pubkey());
+
+    let ix = AcceptTswapOwnerBuilder::new()
+        .tswap(tswap)
+        .new_owner(new_owner.pubkey())
+        .instruction();
+    send(&mut context, &[ix], &new_owner, &[]).await.unwrap();
+
+    let data = tswap_v2(&mut context, tswap).await;
+    assert_eq!(data.owner, new_owner.pubkey());
+    assert_eq!(data.pending_owner, Pubkey::default());
+
+    // the previous owner is out
+    let ix = update_config(tswap, owner.pubkey(), 1);
+    let err = send(&mut context, &[ix], &owner, &[]).await.unwrap_err();
+    assert_custom_error(err, TensorEscrowError::BadOwner as u32);
+}
+
+#[tokio::test]
+async fn accept_tswap_owner_without_a_proposal_fails() {
+    let (mut context, owner, stranger, tswap) = setup().await;
+
+    let ix = AcceptTswapOwnerBuilder::new()
+        .tswap(tswap)
+        .new_owner(stranger.pubkey())
+        .instruction();
+    let err = send(&mut context, &[ix], &stranger, &[]).await.unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::BadOwner as u32);
+    assert_eq!(tswap_v2(&mut context, tswap).await.owner, owner.pubkey());
+}
+
+#[tokio::test]
+async fn non_owner_updates_fail() {
+    let (mut context, owner, stranger, tswap) = setup().await;
+    let ixs = [
+        update_config(tswap, stranger.pubkey(), 1),
+        SetCosignerBuilder::new()
+            .tswap(tswap)
+            .owner(stranger.pubkey())
+            .cosigner(stranger.pubkey())
+            .instruction(),
+        SetFeeVaultBuilder::new()
+            .tswap(tswap)
+            .owner(stranger.pubkey())
+            .fee_vault(stranger.pubkey())
+            .instruction(),
+        ProposeTswapOwnerBuilder::new()
+            .tswap(tswap)
+            .owner(stranger.pubkey())
+            .new_owner(stranger.pubkey())
+            .instruction(),
+    ];
+
+    for ix in ixs {
+        let err = send(&mut context, &[ix], &stranger, &[]).await.unwrap_err();
+        assert_custom_error(err, TensorEscrowError::BadOwner as u32);
+    }
+
+    let data = tswap_v2(&mut context, tswap).await;
+    assert_eq!(data.owner, owner.pubkey());
+    assert_eq!(data.cosigner, owner.pubkey());
+    assert_eq!(data.pending_owner, Pubkey::default());
+    assert_eq!(data.config.fee_bps, 0);
+}
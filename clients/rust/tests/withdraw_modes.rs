@@ -0,0 +1,198 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use tensor_escrow::{
+    accounts::{Hold, Reservation},
+    errors::TensorEscrowError,
+    find_margin_account_v2_pda,
+    instructions::{
+        PlaceHoldCpiTammBuilder, ReserveMarginLamportsBuilder, WithdrawMarginAccountCpiTammBuilder,
+    },
+    types::WithdrawMode,
+};
+
+/// Deposited on top of the margin account's rent.
+const DEPOSIT: u64 = 5 * BID_AMOUNT;
+const HELD: u64 = 2 * BID_AMOUNT;
+const RESERVED: u64 = BID_AMOUNT;
+/// What neither the hold nor the reservation take.
+const AVAILABLE: u64 = DEPOSIT - HELD - RESERVED;
+
+struct Setup {
+    owner: Keypair,
+    tswap: Pubkey,
+    margin_account: Pubkey,
+    pool: (Pubkey, u8, [u8; 32]),
+    destination: Pubkey,
+}
+
+/// Starts the program with a SOL pool on margin account 0 of the owner, which the tests
+/// create once the program runs.
+fn setup() -> (ProgramTest, Setup) {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    add_callers(&mut program_test);
+
+    let destination = Pubkey::new_unique();
+    program_test.add_account(destination, system_account(LAMPORTS));
+
+    let (margin_account, _) = find_margin_account_v2_pda(&owner.pubkey(), 0);
+    let pool = add_pool(
+        &mut program_test,
+        owner.pubkey(),
+        Pubkey::default(),
+        margin_account,
+    );
+
+    let setup = Setup {
+        owner,
+        tswap,
+        margin_account,
+        pool,
+        destination,
+    };
+    (program_test, setup)
+}
+
+impl Setup {
+    fn reserve(&self, caller_account: Pubkey, lamports: u64) -> Instruction {
+        ReserveMarginLamportsBuilder::new()
+            .margin_account(self.margin_account)
+            .reservation(Reservation::find_pda(&self.margin_account, &caller_account).0)
+            .owner(self.owner.pubkey())
+            .caller_account(caller_account)
+            .lamports(lamports)
+            .instruction()
+    }
+
+    /// Creates the margin account holding `DEPOSIT`, of which the pool holds `HELD` and
+    /// `RESERVED` is reserved for another caller.
+    async fn init(&self, context: &mut ProgramTestContext) {
+        init_margin_account(context, self.tswap, &self.owner, 0, DEPOSIT)
+            .await
+            .unwrap();
+
+        let ix = self.reserve(Pubkey::new_unique(), RESERVED);
+        send(context, &[ix], &self.owner, &[]).await.unwrap();
+
+        let (pool, bump, pool_id) = self.pool;
+        let hold_id = Pubkey::new_unique().to_bytes();
+        let ix = PlaceHoldCpiTammBuilder::new()
+            .margin_account(self.margin_account)
+            .pool(pool)
+            .owner(self.owner.pubkey())
+            .hold(Hold::find_pda(&self.margin_account, &pool, hold_id).0)
+            .payer(self.owner.pubkey())
+            .tswap(self.tswap)
+            .bump(bump)
+            .pool_id(pool_id)
+            .hold_id(hold_id)
+            .lamports(HELD)
+            .instruction();
+        call_through(context, &self.owner, tamm_id(), ix)
+            .await
+            .unwrap();
+    }
+
+    fn withdraw(&self, lamports: u64, mode: WithdrawMode, reservation: bool) -> Instruction {
+        let (pool, bump, pool_id) = self.pool;
+        WithdrawMarginAccountCpiTammBuilder::new()
+            .margin_account(self.margin_account)
+            .pool(pool)
+            .owner(self.owner.pubkey())
+            .destination(self.destination)
+            .tswap(self.tswap)
+            .reservation(reservation.then(|| Reservation::find_pda(&self.margin_account, &pool).0))
+            .bump(bump)
+            .pool_id(pool_id)
+            .lamports(lamports)
+            .mode(mode)
+            .instruction()
+    }
+}
+
+#[tokio::test]
+async fn up_to_available_withdraws_what_is_available() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    setup.init(&mut context).await;
+    let margin_before = balance(&mut context, setup.margin_account).await;
+
+    let ix = setup.withdraw(DEPOSIT, WithdrawMode::UpToAvailable, false);
+    call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before - AVAILABLE
+    );
+    assert_eq!(
+        balance(&mut context, setup.destination).await,
+        LAMPORTS + AVAILABLE
+    );
+}
+
+#[tokio::test]
+async fn up_to_available_withdraws_what_is_asked_when_available() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    setup.init(&mut context).await;
+
+    let ix = setup.withdraw(AVAILABLE - 1, WithdrawMode::UpToAvailable, false);
+    call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.destination).await,
+        LAMPORTS + AVAILABLE - 1
+    );
+}
+
+#[tokio::test]
+async fn up_to_available_draws_on_the_callers_reservation() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    setup.init(&mut context).await;
+    // the pool's own reservation comes out of the available balance
+    let ix = setup.reserve(setup.pool.0, BID_AMOUNT);
+    send(&mut context, &[ix], &setup.owner, &[]).await.unwrap();
+
+    let ix = setup.withdraw(DEPOSIT, WithdrawMode::UpToAvailable, true);
+    call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.destination).await,
+        LAMPORTS + AVAILABLE
+    );
+}
+
+#[tokio::test]
+async fn exact_over_the_available_balance_fails() {
+    let (program_test, setup) = setup();
+    let mut context = program_test.start_with_context().await;
+    setup.init(&mut context).await;
+    let margin_before = balance(&mut context, setup.margin_account).await;
+
+    let ix = setup.withdraw(AVAILABLE + 1, WithdrawMode::Exact, false);
+    let err = call_through(&mut context, &setup.owner, tamm_id(), ix)
+        .await
+        .unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::InsufficientAvailableBalance as u32);
+    assert_eq!(
+        balance(&mut context, setup.margin_account).await,
+        margin_before
+    );
+}
@@ -0,0 +1,346 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    clock::Clock,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use std::str::FromStr;
+use tensor_escrow::{
+    accounts::{MarginAccountV2, WithdrawalRequest},
+    errors::TensorEscrowError,
+    instructions::{
+        CancelWithdrawalBuilder, ExecuteWithdrawalBuilder, RequestWithdrawalBuilder,
+        SetWithdrawalDelayBuilder, WithdrawMarginAccountBuilder, WithdrawMarginAccountTokenBuilder,
+    },
+};
+
+const DELAY: u32 = 3_600;
+const AMOUNT: u64 = 100_000_000;
+const REQUEST_NR: u16 = 0;
+
+fn token_program_id() -> Pubkey {
+    Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap()
+}
+
+fn associated_token_program_id() -> Pubkey {
+    Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap()
+}
+
+fn find_withdrawal_request_pda(margin_account: &Pubkey, request_nr: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"withdrawal",
+            margin_account.as_ref(),
+            &request_nr.to_le_bytes(),
+        ],
+        &tensor_escrow::ID,
+    )
+    .0
+}
+
+fn find_associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program_id().as_ref(), mint.as_ref()],
+        &associated_token_program_id(),
+    )
+    .0
+}
+
+/// Starts the program with a V2 margin account of `withdrawal_delay`.
+fn setup(withdrawal_delay: u32) -> (ProgramTest, Pubkey, Keypair, Pubkey) {
+    let owner = Keypair::new();
+    let (mut program_test, tswap) = program_test(&owner);
+    let margin_account = add_margin_v2(&mut program_test, &owner.pubkey(), 0, |margin| {
+        margin.withdrawal_delay = withdrawal_delay;
+    });
+    (program_test, tswap, owner, margin_account)
+}
+
+/// Moves the clock `seconds` ahead, on a new slot so transactions get a fresh blockhash.
+async fn advance_clock(context: &mut ProgramTestContext, seconds: u32) {
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    context.warp_to_slot(clock.slot + 1).unwrap();
+    clock.slot += 1;
+    clock.unix_timestamp += i64::from(seconds);
+    context.set_sysvar(&clock);
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+}
+
+async fn now(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+async fn margin(context: &mut ProgramTestContext, margin_account: Pubkey) -> MarginAccountV2 {
+    let account = context
+        .banks_client
+        .get_account(margin_account)
+        .await
+        .unwrap()
+        .unwrap();
+    MarginAccountV2::from_bytes(&account.data).unwrap()
+}
+
+async fn request(
+    context: &mut ProgramTestContext,
+    margin_account: Pubkey,
+    owner: &Keypair,
+) -> Pubkey {
+    let withdrawal_request = find_withdrawal_request_pda(&margin_account, REQUEST_NR);
+    let ix = RequestWithdrawalBuilder::new()
+        .margin_account(margin_account)
+        .withdrawal_request(withdrawal_request)
+        .owner(owner.pubkey())
+        .request_nr(REQUEST_NR)
+        .lamports(AMOUNT)
+        .instruction();
+    send(context, &[ix], owner, &[]).await.unwrap();
+    withdrawal_request
+}
+
+#[tokio::test]
+async fn request_withdrawal_without_delay_fails() {
+    let (program_test, _, owner, margin_account) = setup(0);
+    let mut context = program_test.start_with_context().await;
+
+    let ix = RequestWithdrawalBuilder::new()
+        .margin_account(margin_account)
+        .withdrawal_request(find_withdrawal_request_pda(&margin_account, REQUEST_NR))
+        .owner(owner.pubkey())
+        .request_nr(REQUEST_NR)
+        .lamports(AMOUNT)
+        .instruction();
+    let err = send(&mut context, &[ix], &owner, &[]).await.unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::NoWithdrawalDelay as u32);
+}
+
+#[tokio::test]
+async fn execute_withdrawal_after_the_delay() {
+    let (program_test, tswap, owner, margin_account) = setup(DELAY);
+    let mut context = program_test.start_with_context().await;
+
+    let withdrawal_request = request(&mut context, margin_account, &owner).await;
+    let requested_at = now(&mut context).await;
+
+    let account = context
+        .banks_client
+        .get_account(withdrawal_request)
+        .await
+        .unwrap()
+        .unwrap();
+    let data = WithdrawalRequest::from_bytes(&account.data).unwrap();
+    assert_eq!(data.amount, AMOUNT);
+    assert_eq!(data.unlock_at, requested_at + i64::from(DELAY));
+    assert_eq!(
+        margin(&mut context, margin_account)
+            .await
+            .pending_withdrawals,
+        AMOUNT
+    );
+
+    let ix = ExecuteWithdrawalBuilder::new()
+        .margin_account(margin_account)
+        .withdrawal_request(withdrawal_request)
+        .owner(owner.pubkey())
+        .tswap(tswap)
+        .instruction();
+
+    let err = send(&mut context, std::slice::from_ref(&ix), &owner, &[])
+        .await
+        .unwrap_err();
+    assert_custom_error(err, TensorEscrowError::WithdrawalLocked as u32);
+
+    advance_clock(&mut context, DELAY).await;
+    let owner_balance = balance(&mut context, owner.pubkey()).await;
+    send(&mut context, &[ix], &owner, &[]).await.unwrap();
+
+    assert_eq!(
+        margin(&mut context, margin_account)
+            .await
+            .pending_withdrawals,
+        0
+    );
+    assert_eq!(
+        balance(&mut context, margin_account).await,
+        LAMPORTS - AMOUNT
+    );
+    // The request's rent is refunded too, net of the transaction fee.
+    assert!(balance(&mut context, owner.pubkey()).await > owner_balance + AMOUNT);
+    let account = context
+        .banks_client
+        .get_account(withdrawal_request)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
+
+#[tokio::test]
+async fn cancel_withdrawal_releases_the_amount() {
+    let (program_test, _, owner, margin_account) = setup(DELAY);
+    let mut context = program_test.start_with_context().await;
+
+    let withdrawal_request = request(&mut context, margin_account, &owner).await;
+
+    let ix = CancelWithdrawalBuilder::new()
+        .margin_account(margin_account)
+        .withdrawal_request(withdrawal_request)
+        .owner(owner.pubkey())
+        .instruction();
+    send(&mut context, &[ix], &owner, &[]).await.unwrap();
+
+    assert_eq!(
+        margin(&mut context, margin_account)
+            .await
+            .pending_withdrawals,
+        0
+    );
+    assert_eq!(balance(&mut context, margin_account).await, LAMPORTS);
+    let account = context
+        .banks_client
+        .get_account(withdrawal_request)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
+
+#[tokio::test]
+async fn raising_the_delay_applies_at_once() {
+    let owner = Keypair::new();
+    let (program_test, tswap) = program_test(&owner);
+    let mut context = program_test.start_with_context().await;
+    let margin_account = init_margin_account(&mut context, tswap, &owner, 0, 2 * AMOUNT)
+        .await
+        .unwrap();
+
+    let ix = SetWithdrawalDelayBuilder::new()
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .withdrawal_delay(DELAY)
+        .instruction();
+    send(&mut context, &[ix], &owner, &[]).await.unwrap();
+    assert_eq!(
+        margin(&mut context, margin_account).await.withdrawal_delay,
+        DELAY
+    );
+
+    let ix = WithdrawMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .lamports(AMOUNT)
+        .instruction();
+    let err = send(&mut context, &[ix], &owner, &[]).await.unwrap_err();
+    assert_custom_error(err, TensorEscrowError::WithdrawalDelayed as u32);
+
+    request(&mut context, margin_account, &owner).await;
+    assert_eq!(
+        margin(&mut context, margin_account)
+            .await
+            .pending_withdrawals,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn withdraw_with_delay_fails() {
+    let (program_test, tswap, owner, margin_account) = setup(DELAY);
+    let mut context = program_test.start_with_context().await;
+
+    let ix = WithdrawMarginAccountBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .lamports(AMOUNT)
+        .instruction();
+    let err = send(&mut context, &[ix], &owner, &[]).await.unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::WithdrawalDelayed as u32);
+}
+
+#[tokio::test]
+async fn withdraw_token_with_delay_fails() {
+    let (mut program_test, tswap, owner, margin_account) = setup(DELAY);
+
+    // spl-token layouts: a mint and two initialized token accounts.
+    let mint = Pubkey::new_unique();
+    let mut mint_data = vec![0; 82];
+    mint_data[36..44].copy_from_slice(&AMOUNT.to_le_bytes());
+    mint_data[45] = 1;
+    program_test.add_account(mint, account(mint_data, LAMPORTS, token_program_id()));
+
+    let token_account = |authority: &Pubkey, amount: u64| {
+        let mut data = vec![0; 165];
+        data[..32].copy_from_slice(mint.as_ref());
+        data[32..64].copy_from_slice(authority.as_ref());
+        data[64..72].copy_from_slice(&amount.to_le_bytes());
+        data[108] = 1;
+        account(data, LAMPORTS, token_program_id())
+    };
+    let margin_ta = find_associated_token_address(&margin_account, &mint);
+    let owner_ta = find_associated_token_address(&owner.pubkey(), &mint);
+    program_test.add_account(margin_ta, token_account(&margin_account, AMOUNT));
+    program_test.add_account(owner_ta, token_account(&owner.pubkey(), 0));
+
+    let mut context = program_test.start_with_context().await;
+
+    let ix = WithdrawMarginAccountTokenBuilder::new()
+        .tswap(tswap)
+        .margin_account(margin_account)
+        .margin_ta(margin_ta)
+        .owner_ta(owner_ta)
+        .mint(mint)
+        .owner(owner.pubkey())
+        .amount(AMOUNT)
+        .instruction();
+    let err = send(&mut context, &[ix], &owner, &[]).await.unwrap_err();
+
+    assert_custom_error(err, TensorEscrowError::WithdrawalDelayed as u32);
+}
+
+#[tokio::test]
+async fn lowering_the_delay_waits_out_the_current_delay() {
+    let (program_test, _, owner, margin_account) = setup(DELAY);
+    let mut context = program_test.start_with_context().await;
+
+    let ix = SetWithdrawalDelayBuilder::new()
+        .margin_account(margin_account)
+        .owner(owner.pubkey())
+        .withdrawal_delay(0)
+        .instruction();
+
+    send(&mut context, std::slice::from_ref(&ix), &owner, &[])
+        .await
+        .unwrap();
+    let scheduled_at = now(&mut context).await;
+
+    let data = margin(&mut context, margin_account).await;
+    assert_eq!(data.withdrawal_delay, DELAY);
+    assert_eq!(data.pending_withdrawal_delay, 0);
+    assert_eq!(
+        data.withdrawal_delay_unlock_at,
+        scheduled_at + i64::from(DELAY)
+    );
+
+    advance_clock(&mut context, DELAY - 1).await;
+    let err = send(&mut context, std::slice::from_ref(&ix), &owner, &[])
+        .await
+        .unwrap_err();
+    assert_custom_error(err, TensorEscrowError::WithdrawalLocked as u32);
+
+    advance_clock(&mut context, 1).await;
+    send(&mut context, &[ix], &owner, &[]).await.unwrap();
+
+    let data = margin(&mut context, margin_account).await;
+    assert_eq!(data.withdrawal_delay, 0);
+    assert_eq!(data.withdrawal_delay_unlock_at, 0);
+}
@@ -2,7 +2,70 @@ use anchor_lang::prelude::*;
 
 // (!) DONT USE UNDERSCORES (3_000) OR WONT BE ABLE TO READ JS-SIDE
 #[constant]
-pub const CURRENT_TSWAP_VERSION: u8 = 1;
+pub const CURRENT_TSWAP_VERSION: u8 = 2;
+
+/// Upper bound on `TSwapConfig::fee_bps` (10%).
+#[constant]
+pub const MAX_FEE_BPS: u16 = 1000;
 
 pub const TCOMP_BID_STATE_DISCRIMINATOR: [u8; 8] = [155, 197, 5, 97, 189, 60, 8, 183];
 pub const TAMM_POOL_DISCRIMINATOR: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
+
+// Flags of `TSwapV2::paused`.
+#[constant]
+pub const PAUSE_DEPOSITS: u8 = 1;
+/// Owner and delegate withdrawals, left open when only CPI withdrawals are paused.
+#[constant]
+pub const PAUSE_OWNER_WITHDRAWALS: u8 = 2;
+#[constant]
+pub const PAUSE_TAMM_CPI: u8 = 4;
+#[constant]
+pub const PAUSE_TCOMP_CPI: u8 = 8;
+/// Withdrawals by registered cpi consumers.
+#[constant]
+pub const PAUSE_CONSUMER_CPI: u8 = 16;
+
+// Flags of `MarginAccountV2::cpi_denied`, everything is allowed by default.
+#[constant]
+pub const DENY_TAMM_CPI: u8 = 1;
+#[constant]
+pub const DENY_TCOMP_CPI: u8 = 2;
+/// Withdrawals by registered cpi consumers.
+#[constant]
+pub const DENY_CONSUMER_CPI: u8 = 4;
+
+/// `lamports` value making owner withdrawals take everything above the rent-exempt minimum,
+/// as `amount` of an owner token withdrawal the whole vault.
+#[constant]
+pub const WITHDRAW_ALL: u64 = 18446744073709551615;
+
+#[constant]
+pub const CURRENT_MARGIN_VERSION: u8 = 2;
+
+#[constant]
+pub const CURRENT_CPI_CONSUMER_VERSION: u8 = 1;
+
+#[constant]
+pub const CURRENT_ALLOWANCE_VERSION: u8 = 1;
+
+#[constant]
+pub const CURRENT_WITHDRAWAL_REQUEST_VERSION: u8 = 1;
+
+#[constant]
+pub const CURRENT_FEE_EXEMPTION_VERSION: u8 = 1;
+
+#[constant]
+pub const CURRENT_RESERVATION_VERSION: u8 = 1;
+
+#[constant]
+pub const CURRENT_HOLD_VERSION: u8 = 1;
+
+#[constant]
+pub const CURRENT_ATTACHMENT_VERSION: u8 = 1;
+
+#[constant]
+pub const CURRENT_CONSUMER_APPROVAL_VERSION: u8 = 1;
+
+/// Slots a hold lives for (~1 day) while `TSwapV2::hold_expiry_slots` is unset.
+#[constant]
+pub const DEFAULT_HOLD_EXPIRY_SLOTS: u64 = 216000;
@@ -10,4 +10,80 @@ pub enum ErrorCode {
     BadOwner = 16,
     #[msg("bad margin account passed")]
     BadMargin = 27,
+    #[msg("margin account still has pools or bids attached")]
+    MarginInUse = 28,
+    #[msg("margin account is already on the latest version")]
+    MarginAlreadyMigrated = 29,
+    #[msg("margin account balance does not cover rent for the new layout")]
+    MarginRentNotCovered = 30,
+    #[msg("seed prefix must be between 1 and 32 bytes")]
+    BadSeedPrefix = 31,
+    #[msg("signer is not a PDA of a registered cpi consumer")]
+    BadCpiSigner = 32,
+    #[msg("signing pool or bid does not reference this margin account")]
+    MarginMismatch = 33,
+    #[msg("amount exceeds what the bid can draw")]
+    AmountExceedsBid = 34,
+    #[msg("amount exceeds the delegate's allowance")]
+    AllowanceExceeded = 35,
+    #[msg("delegate allowance has expired")]
+    AllowanceExpired = 36,
+    #[msg("margin account withdrawals have to be requested and wait for the delay")]
+    WithdrawalDelayed = 37,
+    #[msg("withdrawal is still locked")]
+    WithdrawalLocked = 38,
+    #[msg("amount exceeds the margin account's available balance")]
+    InsufficientAvailableBalance = 39,
+    #[msg("margin account has no withdrawal delay")]
+    NoWithdrawalDelay = 40,
+    #[msg("fee bps exceeds the maximum")]
+    FeeBpsTooHigh = 41,
+    #[msg("fee vault must be owned by the system program")]
+    BadFeeVault = 42,
+    #[msg("tswap is already on the latest version")]
+    TSwapAlreadyMigrated = 43,
+    #[msg("instruction is paused")]
+    Paused = 44,
+    #[msg("only the owner can unpause")]
+    OnlyOwnerCanUnpause = 45,
+    #[msg("withdrawal exceeds the caller's rate limit")]
+    RateLimitExceeded = 46,
+    #[msg("caller is not TAMM, TCOMP or a registered cpi consumer")]
+    BadCpiCaller = 47,
+    #[msg("fee vault has to be passed in when a fee is due")]
+    FeeVaultMissing = 48,
+    #[msg("cosigner is not the tswap cosigner")]
+    BadCosigner = 49,
+    #[msg("amount exceeds the cosign threshold and has to be co-signed")]
+    CosignRequired = 50,
+    #[msg("number of margin accounts passed in doesn't match the amounts")]
+    MarginCountMismatch = 51,
+    #[msg("margin account owner doesn't allow this caller")]
+    CpiDenied = 52,
+    #[msg("authority is neither the margin account owner nor the reservation's caller")]
+    BadReservationAuthority = 53,
+    #[msg("hold expired")]
+    HoldExpired = 54,
+    #[msg("hold can only be released by its caller until it expires")]
+    HoldNotExpired = 55,
+    #[msg("amount exceeds the hold")]
+    AmountExceedsHold = 56,
+    #[msg("mint is not the currency of the pool or bid")]
+    CurrencyMismatch = 57,
+    #[msg("mints with a transfer hook are not supported")]
+    UnsupportedMint = 58,
+    #[msg("margin account has to be migrated to V2 first")]
+    MarginNotMigrated = 59,
+    #[msg("TAMM and TCOMP can't be registered as cpi consumers")]
+    ReservedCpiConsumer = 60,
+    #[msg("granted on a closed margin account that was at this address")]
+    StaleGrant = 61,
+    #[msg("margin account can't be closed in the slot it was created or migrated in")]
+    MarginClosedTooSoon = 62,
+    #[msg("tswap has to be migrated to V2 first")]
+    TSwapNotMigrated = 63,
+    #[msg("margin account passed in more than once")]
+    DuplicateMarginAccount = 64,
+    #[msg("pools and bids attached while on V1 have to be reconciled first")]
+    UntrackedAttachments = 65,
 }
@@ -0,0 +1,302 @@
+//! Events emitted through self-CPI (or logs when the event accounts are not passed in).
+
+use anchor_lang::prelude::*;
+
+/// Who moved funds in or out of a margin account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Caller {
+    Owner,
+    TAmm,
+    Tcomp,
+    CpiConsumer,
+    Delegate,
+}
+
+#[event]
+pub struct InitMarginEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub nr: u16,
+    pub version: u8,
+}
+
+#[event]
+pub struct MigrateMarginEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub payer: Pubkey,
+    pub version: u8,
+}
+
+#[event]
+pub struct ReconcileAttachmentsEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct CloseMarginEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    /// Lamports held by the margin account before closing.
+    pub amount: u64,
+    pub rent_destination: Pubkey,
+}
+
+#[event]
+pub struct DepositEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    /// Set for token deposits, `None` for SOL.
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    /// Lamports (or tokens) held by the margin account after the deposit.
+    pub post_balance: u64,
+}
+
+/// Deposit paid by someone else than the owner, emitted instead of `DepositEvent`.
+#[event]
+pub struct DepositForEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub payer: Pubkey,
+    pub amount: u64,
+    /// Lamports held by the margin account after the deposit.
+    pub post_balance: u64,
+}
+
+#[event]
+pub struct WithdrawEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub caller: Caller,
+    /// Program of the registered cpi consumer, `None` for owner, TAMM and TCOMP withdrawals.
+    pub caller_program: Option<Pubkey>,
+    /// Pool, bid state or delegate that signed the withdrawal, `None` for owner withdrawals.
+    pub caller_account: Option<Pubkey>,
+    /// Set for token withdrawals, `None` for SOL.
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    /// Lamports (or tokens) held by the margin account after the withdrawal.
+    pub post_balance: u64,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct MarginTransferEvent {
+    pub owner: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub source_post_balance: u64,
+    pub destination_post_balance: u64,
+}
+
+#[event]
+pub struct AttachEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub caller: Caller,
+    /// Pool or bid state being attached or detached.
+    pub caller_account: Pubkey,
+    /// `false` when detaching.
+    pub attached: bool,
+    pub pools_attached: u32,
+    pub bids_attached: u32,
+}
+
+#[event]
+pub struct AllowanceEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    /// Lamports left to spend, 0 when revoked.
+    pub amount: u64,
+    pub expires_at: Option<i64>,
+}
+
+#[event]
+pub struct WithdrawalDelayEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub withdrawal_delay: u32,
+    /// Lower delay scheduled to apply at `withdrawal_delay_unlock_at`, if any.
+    pub pending_withdrawal_delay: u32,
+    pub withdrawal_delay_unlock_at: i64,
+}
+
+#[event]
+pub struct WithdrawalRequestEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub request_nr: u16,
+    pub amount: u64,
+    pub unlock_at: i64,
+    /// `true` when the owner cancels the request, executed requests emit a `WithdrawEvent`.
+    pub cancelled: bool,
+}
+
+#[event]
+pub struct CpiDeniedEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    /// `DENY_*` flags after the change.
+    pub cpi_denied: u8,
+}
+
+#[event]
+pub struct ReservationEvent {
+    pub margin: Pubkey,
+    pub caller_account: Pubkey,
+    /// Lamports left reserved for `caller_account`, 0 once released.
+    pub amount: u64,
+    /// Lamports reserved across all of the margin account's reservations.
+    pub reserved_lamports: u64,
+}
+
+/// Emitted when a hold is placed, captured or released.
+#[event]
+pub struct HoldEvent {
+    pub margin: Pubkey,
+    pub hold: Pubkey,
+    pub caller: Caller,
+    pub caller_account: Pubkey,
+    /// Lamports held, 0 once captured or released.
+    pub amount: u64,
+    /// Lamports sent to the destination by a capture.
+    pub captured: u64,
+    pub expiry_slot: u64,
+    /// Lamports held across all of the margin account's holds.
+    pub held_lamports: u64,
+}
+
+#[event]
+pub struct HoldExpiryEvent {
+    pub owner: Pubkey,
+    pub hold_expiry_slots: u64,
+}
+
+#[event]
+pub struct OwnerTransferEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    /// Proposed owner, default when a proposal is cancelled.
+    pub new_owner: Pubkey,
+    /// `false` when proposed, `true` once the new owner accepted.
+    pub accepted: bool,
+}
+
+#[event]
+pub struct TSwapUpdateEvent {
+    pub tswap: Pubkey,
+    pub owner: Pubkey,
+    pub fee_vault: Pubkey,
+    pub cosigner: Pubkey,
+    pub fee_bps: u16,
+    /// Owner waiting to accept, default when there is none (or the tswap is still on V1).
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct PauseEvent {
+    pub tswap: Pubkey,
+    /// Owner or guardian that made the change.
+    pub authority: Pubkey,
+    pub guardian: Pubkey,
+    /// `PAUSE_*` flags after the change.
+    pub paused: u8,
+}
+
+#[event]
+pub struct RateLimitEvent {
+    pub caller: Caller,
+    /// Program of the registered cpi consumer, `None` for TAMM and TCOMP.
+    pub caller_program: Option<Pubkey>,
+    pub limit: u64,
+    pub window: u32,
+}
+
+/// Alert for a CPI withdrawal that breached its caller's rate limit. The withdrawal
+/// fails, so this is only ever logged (see `consume_rate_limit`).
+#[event]
+pub struct RateLimitExceededEvent {
+    pub margin: Pubkey,
+    pub caller: Caller,
+    pub caller_program: Option<Pubkey>,
+    pub amount: u64,
+    /// Lamports the caller would have withdrawn over the window, including `amount`.
+    pub used: u64,
+    pub limit: u64,
+    pub window: u32,
+}
+
+/// Fee paid by a margin account on a CPI withdrawal, on top of the `WithdrawEvent` amount.
+#[event]
+pub struct FeeEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub caller: Caller,
+    pub caller_program: Option<Pubkey>,
+    pub amount: u64,
+    pub fee_vault: Pubkey,
+}
+
+#[event]
+pub struct FeeConfigEvent {
+    pub fees_enabled: bool,
+    /// Caller whose fee changed, `None` when fees are turned on or off.
+    pub caller: Option<Caller>,
+    pub caller_program: Option<Pubkey>,
+    /// Fee override of `caller`, `None` when it pays `TSwap::config.fee_bps`.
+    pub fee_bps: Option<u16>,
+}
+
+#[event]
+pub struct FeeExemptionEvent {
+    pub owner: Pubkey,
+    /// `false` when the exemption is removed.
+    pub exempt: bool,
+}
+
+#[event]
+pub struct WithdrawFeesEvent {
+    pub fee_vault: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct CosignThresholdEvent {
+    /// Margin account whose threshold changed, `None` for the global threshold.
+    pub margin: Option<Pubkey>,
+    /// Program owner or margin account owner that made the change.
+    pub owner: Pubkey,
+    /// Lamports above which owner withdrawals have to be co-signed, `None` when disabled.
+    pub threshold: Option<u64>,
+}
+
+#[event]
+pub struct CpiConsumerEvent {
+    pub program_id: Pubkey,
+    pub seed_prefix: Vec<u8>,
+    pub signer_discriminator: [u8; 8],
+    /// `false` when the consumer is closed.
+    pub registered: bool,
+}
+
+/// Emitted instead of `CpiConsumerEvent` when an already registered consumer is updated.
+#[event]
+pub struct CpiConsumerUpdateEvent {
+    pub program_id: Pubkey,
+    pub seed_prefix: Vec<u8>,
+    pub signer_discriminator: [u8; 8],
+}
+
+#[event]
+pub struct ConsumerApprovalEvent {
+    pub margin: Pubkey,
+    pub owner: Pubkey,
+    pub consumer_program: Pubkey,
+    /// `false` when revoked.
+    pub approved: bool,
+}
@@ -0,0 +1,55 @@
+//! Proposed owner taking over the margin account.
+//!
+//! The address stays the same, the key it was derived from is kept in
+//! `original_owner` so the account can still sign for itself.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::throw_err;
+
+use crate::{emit_event, error::ErrorCode, is_margin_account, MarginAccountV2, OwnerTransferEvent};
+
+#[derive(Accounts)]
+pub struct AcceptMarginOwner<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        constraint = margin_account.pending_owner == new_owner.key() @ ErrorCode::BadOwner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    pub new_owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_accept_margin_owner(ctx: Context<AcceptMarginOwner>) -> Result<()> {
+    let margin = &mut ctx.accounts.margin_account;
+    // pools and bids keep their own owner, they have to be detached first
+    if margin.has_attachments() {
+        throw_err!(ErrorCode::MarginInUse);
+    }
+
+    let previous_owner = margin.owner;
+    if margin.original_owner == Pubkey::default() {
+        margin.original_owner = previous_owner;
+    }
+    margin.owner = ctx.accounts.new_owner.key();
+    margin.pending_owner = Pubkey::default();
+
+    emit_event(
+        &OwnerTransferEvent {
+            margin: margin.key(),
+            owner: previous_owner,
+            new_owner: margin.owner,
+            accepted: true,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
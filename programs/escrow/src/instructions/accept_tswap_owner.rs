@@ -0,0 +1,45 @@
+//! Proposed owner taking over TSwap.
+
+use anchor_lang::prelude::*;
+
+use crate::{emit_event, error::ErrorCode, TSwapUpdateEvent, TSwapV2};
+
+#[derive(Accounts)]
+pub struct AcceptTSwapOwner<'info> {
+    #[account(
+        mut,
+        seeds = [],
+        bump = tswap.bump[0],
+        constraint = tswap.pending_owner == new_owner.key() @ ErrorCode::BadOwner,
+    )]
+    pub tswap: Box<Account<'info, TSwapV2>>,
+
+    pub new_owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_accept_tswap_owner(ctx: Context<AcceptTSwapOwner>) -> Result<()> {
+    let tswap = &mut ctx.accounts.tswap;
+    tswap.owner = ctx.accounts.new_owner.key();
+    tswap.pending_owner = Pubkey::default();
+
+    emit_event(
+        &TSwapUpdateEvent {
+            tswap: tswap.key(),
+            owner: tswap.owner,
+            fee_vault: tswap.fee_vault,
+            cosigner: tswap.cosigner,
+            fee_bps: tswap.config.fee_bps,
+            pending_owner: tswap.pending_owner,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
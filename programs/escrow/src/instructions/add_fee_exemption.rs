@@ -0,0 +1,56 @@
+//! Program owner exempting a margin account owner from CPI withdrawal fees.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::CURRENT_FEE_EXEMPTION_VERSION, emit_event, error::ErrorCode, FeeExemption,
+    FeeExemptionEvent, TSwap, FEE_EXEMPTION_SIZE,
+};
+
+#[derive(Accounts)]
+pub struct AddFeeExemption<'info> {
+    #[account(seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"fee_exemption".as_ref(), exempt_owner.key().as_ref()],
+        bump,
+        space = FEE_EXEMPTION_SIZE,
+    )]
+    pub fee_exemption: Box<Account<'info, FeeExemption>>,
+
+    /// CHECK: any margin account owner
+    pub exempt_owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_add_fee_exemption(ctx: Context<AddFeeExemption>) -> Result<()> {
+    let fee_exemption = &mut ctx.accounts.fee_exemption;
+
+    fee_exemption.version = CURRENT_FEE_EXEMPTION_VERSION;
+    fee_exemption.bump = [ctx.bumps.fee_exemption];
+    fee_exemption.owner = ctx.accounts.exempt_owner.key();
+
+    emit_event(
+        &FeeExemptionEvent {
+            owner: fee_exemption.owner,
+            exempt: true,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,84 @@
+//! Owner granting a delegate (eg a trading bot) a lamport allowance on a margin account.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::unwrap_opt;
+
+use crate::{
+    constants::CURRENT_ALLOWANCE_VERSION, emit_event, error::ErrorCode, is_margin_account,
+    load_margin_v2, Allowance, AllowanceEvent, MarginAccount, ALLOWANCE_SIZE,
+};
+
+#[derive(Accounts)]
+pub struct ApproveAllowance<'info> {
+    // V2 only (checked in process), allowances are bound to the account's generation.
+    #[account(
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    // NB: we can call this multiple times (init_if_needed) to replace the allowance.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            b"allowance".as_ref(),
+            margin_account.key().as_ref(),
+            delegate.key().as_ref(),
+        ],
+        bump,
+        space = ALLOWANCE_SIZE,
+    )]
+    pub allowance: Box<Account<'info, Allowance>>,
+
+    /// CHECK: any account can be a delegate, it has to sign to spend the allowance
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+/// Sets (not adds to) the allowance, so re-approving never grants more than `amount`.
+/// Re-approving also moves a stale allowance over to the current margin account.
+pub fn process_approve_allowance(
+    ctx: Context<ApproveAllowance>,
+    amount: u64,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let margin = unwrap_opt!(
+        load_margin_v2(&ctx.accounts.margin_account.to_account_info())?,
+        ErrorCode::MarginNotMigrated
+    );
+    let allowance = &mut ctx.accounts.allowance;
+
+    allowance.version = CURRENT_ALLOWANCE_VERSION;
+    allowance.bump = [ctx.bumps.allowance];
+    allowance.margin_account = ctx.accounts.margin_account.key();
+    allowance.delegate = ctx.accounts.delegate.key();
+    allowance.amount = amount;
+    allowance.expires_at = expires_at;
+    allowance.owner = ctx.accounts.owner.key();
+    allowance.margin_generation = margin.generation;
+
+    emit_event(
+        &AllowanceEvent {
+            margin: allowance.margin_account,
+            owner: ctx.accounts.owner.key(),
+            delegate: allowance.delegate,
+            amount,
+            expires_at,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
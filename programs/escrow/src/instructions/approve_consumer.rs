@@ -0,0 +1,81 @@
+//! Owner letting a registered cpi consumer withdraw from (and reserve on) a margin account.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::unwrap_opt;
+
+use crate::{
+    constants::CURRENT_CONSUMER_APPROVAL_VERSION, emit_event, error::ErrorCode, is_margin_account,
+    load_margin_v2, ConsumerApproval, ConsumerApprovalEvent, CpiConsumer, MarginAccount,
+    CONSUMER_APPROVAL_SIZE,
+};
+
+#[derive(Accounts)]
+pub struct ApproveConsumer<'info> {
+    // V2 only (checked in process), approvals are bound to the account's generation.
+    #[account(
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    /// Only registered consumers can be approved.
+    #[account(
+        seeds = [b"cpi_consumer".as_ref(), cpi_consumer.program_id.as_ref()],
+        bump = cpi_consumer.bump[0],
+    )]
+    pub cpi_consumer: Box<Account<'info, CpiConsumer>>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            b"consumer_approval".as_ref(),
+            margin_account.key().as_ref(),
+            cpi_consumer.program_id.as_ref(),
+        ],
+        bump,
+        space = CONSUMER_APPROVAL_SIZE,
+    )]
+    pub consumer_approval: Box<Account<'info, ConsumerApproval>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_approve_consumer(ctx: Context<ApproveConsumer>) -> Result<()> {
+    let margin = unwrap_opt!(
+        load_margin_v2(&ctx.accounts.margin_account.to_account_info())?,
+        ErrorCode::MarginNotMigrated
+    );
+
+    **ctx.accounts.consumer_approval = ConsumerApproval {
+        version: CURRENT_CONSUMER_APPROVAL_VERSION,
+        bump: [ctx.bumps.consumer_approval],
+        margin_account: ctx.accounts.margin_account.key(),
+        consumer_program: ctx.accounts.cpi_consumer.program_id,
+        owner: ctx.accounts.owner.key(),
+        margin_generation: margin.generation,
+        _reserved: [0; 24],
+    };
+
+    emit_event(
+        &ConsumerApprovalEvent {
+            margin: ctx.accounts.margin_account.key(),
+            owner: ctx.accounts.owner.key(),
+            consumer_program: ctx.accounts.cpi_consumer.program_id,
+            approved: true,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,211 @@
+use crate::{
+    assert_cpi_allowed,
+    constants::{CURRENT_ATTACHMENT_VERSION, DENY_TAMM_CPI},
+    emit_event,
+    error::ErrorCode,
+    is_margin_account, load_margin_v2, migrate_margin_in_place, set_cpi_return_data,
+    top_up_margin_v2_rent, AttachEvent, Attachment, Caller, MarginAccount, MarginAccountV2,
+    MigrateMarginEvent, ATTACHMENT_SIZE,
+};
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+use tensor_vipers::{unwrap_int, Validate};
+
+use super::{assert_discriminator, assert_pool_margin, constants::TAMM_POOL_DISCRIMINATOR};
+
+/// Only the pool PDA can sign, so the counter can only be moved by TAMM itself.
+#[derive(Accounts)]
+#[instruction(bump: u8, pool_id: [u8; 32])]
+pub struct AttachMarginAccountCpiTAmm<'info> {
+    // V1 accounts are migrated in place before the pool is counted.
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    // Use the pool account as the signing PDA for this instruction.
+    // The seeds check ensures it is a valid Pool account from the TAMM program.
+    #[account(
+        seeds=[b"pool".as_ref(), owner.key().as_ref(), pool_id.as_ref()],
+        seeds::program = Pubkey::from_str("TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg").unwrap(),
+        bump = bump,
+    )]
+    pub pool: Signer<'info>,
+
+    /// CHECK: has_one on margin_account
+    pub owner: UncheckedAccount<'info>,
+
+    // Init fails if the pool is already attached.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            b"attachment".as_ref(),
+            margin_account.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump,
+        space = ATTACHMENT_SIZE,
+    )]
+    pub attachment: Box<Account<'info, Attachment>>,
+
+    // The pool holds data, so it can't pay for the attachment (or the migration) itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for AttachMarginAccountCpiTAmm<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_cpi_allowed(&self.margin_account.to_account_info(), DENY_TAMM_CPI)?;
+
+        assert_discriminator(&self.pool.to_account_info(), &TAMM_POOL_DISCRIMINATOR)?;
+        assert_pool_margin(&self.pool.to_account_info(), &self.margin_account.key())?;
+
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn process_attach_margin_account_from_tamm(
+    ctx: Context<AttachMarginAccountCpiTAmm>,
+) -> Result<()> {
+    let margin_info = ctx.accounts.margin_account.to_account_info();
+
+    let mut margin = match load_margin_v2(&margin_info)? {
+        Some(margin) => margin,
+        None => {
+            top_up_margin_v2_rent(
+                &margin_info,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+            let migrated = migrate_margin_in_place(&margin_info, &ctx.accounts.margin_account)?;
+
+            emit_event(
+                &MigrateMarginEvent {
+                    margin: margin_info.key(),
+                    owner: migrated.owner,
+                    payer: ctx.accounts.payer.key(),
+                    version: migrated.version,
+                },
+                &ctx.accounts.event_authority,
+                &ctx.accounts.program,
+                ctx.bumps.event_authority,
+            )?;
+
+            migrated
+        }
+    };
+    margin.pools_attached = unwrap_int!(margin.pools_attached.checked_add(1));
+    margin.try_serialize(&mut &mut margin_info.try_borrow_mut_data()?[..])?;
+
+    **ctx.accounts.attachment = Attachment {
+        version: CURRENT_ATTACHMENT_VERSION,
+        bump: [ctx.bumps.attachment],
+        margin_account: margin_info.key(),
+        caller_account: ctx.accounts.pool.key(),
+        caller: Caller::TAmm,
+        rent_payer: ctx.accounts.payer.key(),
+        _reserved: [0; 32],
+    };
+
+    emit_event(
+        &AttachEvent {
+            margin: margin_info.key(),
+            owner: margin.owner,
+            caller: Caller::TAmm,
+            caller_account: ctx.accounts.pool.key(),
+            attached: true,
+            pools_attached: margin.pools_attached,
+            bids_attached: margin.bids_attached,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    set_cpi_return_data(&margin_info, 0)
+}
+
+/// Closes the attachment the pool created, so only an attached pool can be detached.
+#[derive(Accounts)]
+#[instruction(bump: u8, pool_id: [u8; 32])]
+pub struct DetachMarginAccountCpiTAmm<'info> {
+    // Always on V2, attaching migrates the account.
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    // Same signing PDA as AttachMarginAccountCpiTAmm.
+    #[account(
+        seeds=[b"pool".as_ref(), owner.key().as_ref(), pool_id.as_ref()],
+        seeds::program = Pubkey::from_str("TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg").unwrap(),
+        bump = bump,
+    )]
+    pub pool: Signer<'info>,
+
+    /// CHECK: has_one on margin_account
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"attachment".as_ref(),
+            margin_account.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump = attachment.bump[0],
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub attachment: Box<Account<'info, Attachment>>,
+
+    /// CHECK: has_one on attachment, refunded the attachment's rent
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_detach_margin_account_from_tamm(
+    ctx: Context<DetachMarginAccountCpiTAmm>,
+) -> Result<()> {
+    let margin = &mut ctx.accounts.margin_account;
+    margin.pools_attached = unwrap_int!(margin.pools_attached.checked_sub(1));
+
+    emit_event(
+        &AttachEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            caller: Caller::TAmm,
+            caller_account: ctx.accounts.pool.key(),
+            attached: false,
+            pools_attached: margin.pools_attached,
+            bids_attached: margin.bids_attached,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    set_cpi_return_data(&ctx.accounts.margin_account.to_account_info(), 0)
+}
@@ -0,0 +1,64 @@
+//! Owner cancelling a pending withdrawal request, the funds become available again.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    emit_event, error::ErrorCode, is_margin_account, MarginAccountV2, WithdrawalRequest,
+    WithdrawalRequestEvent,
+};
+
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal".as_ref(),
+            margin_account.key().as_ref(),
+            &withdrawal_request.request_nr.to_le_bytes(),
+        ],
+        bump = withdrawal_request.bump[0],
+        has_one = margin_account,
+        close = owner,
+    )]
+    pub withdrawal_request: Box<Account<'info, WithdrawalRequest>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    let margin = &mut ctx.accounts.margin_account;
+    let request = &ctx.accounts.withdrawal_request;
+
+    margin.pending_withdrawals = margin.pending_withdrawals.saturating_sub(request.amount);
+
+    emit_event(
+        &WithdrawalRequestEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            request_nr: request.request_nr,
+            amount: request.amount,
+            unlock_at: request.unlock_at,
+            cancelled: true,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
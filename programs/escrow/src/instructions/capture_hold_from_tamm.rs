@@ -0,0 +1,193 @@
+//! TAMM pool capturing (part of) its hold to a destination, the rest goes back to
+//! the available balance and the hold is closed.
+
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+use tensor_toolbox::transfer_lamports_from_pda;
+use tensor_vipers::{unwrap_int, Validate};
+
+use crate::{
+    assert_available_lamports, assert_cpi_allowed, assert_not_paused, capture_hold,
+    constants::{DENY_TAMM_CPI, PAUSE_TAMM_CPI},
+    consume_tswap_rate_limit, cpi_fee, emit_event,
+    error::ErrorCode,
+    is_margin_account, pay_cpi_fee, set_cpi_return_data, Caller, FeeEvent, FeeExemption, Hold,
+    HoldEvent, MarginAccountV2, TSwap, WithdrawEvent,
+};
+
+use super::{assert_discriminator, assert_pool_margin, constants::TAMM_POOL_DISCRIMINATOR};
+
+#[derive(Accounts)]
+#[instruction(bump: u8, pool_id: [u8; 32])]
+pub struct CaptureHoldCpiTAmm<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    // Same signing PDA as WithdrawMarginAccountCpiTAmm.
+    #[account(
+        seeds=[b"pool".as_ref(), owner.key().as_ref(), pool_id.as_ref()],
+        seeds::program = Pubkey::from_str("TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg").unwrap(),
+        bump = bump,
+    )]
+    pub pool: Signer<'info>,
+
+    /// CHECK: has_one on margin_account
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"hold".as_ref(),
+            margin_account.key().as_ref(),
+            pool.key().as_ref(),
+            hold.hold_id.as_ref(),
+        ],
+        bump = hold.bump[0],
+        has_one = margin_account,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub hold: Box<Account<'info, Hold>>,
+
+    /// CHECK: can only be passed in by TAMM, since it has to sign off with Pool PDA.
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// CHECK: has_one on hold, refunded the hold's rent
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // mut for the rate limit accounting
+    #[account(mut, seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// CHECK: address of tswap.fee_vault, only required once a fee is due
+    #[account(mut, address = tswap.fee_vault @ ErrorCode::BadFeeVault)]
+    pub fee_vault: Option<UncheckedAccount<'info>>,
+
+    /// Exempts the owner from the withdrawal fee.
+    #[account(
+        seeds = [b"fee_exemption".as_ref(), owner.key().as_ref()],
+        bump = fee_exemption.bump[0],
+    )]
+    pub fee_exemption: Option<Box<Account<'info, FeeExemption>>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for CaptureHoldCpiTAmm<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_not_paused(&self.tswap.to_account_info(), PAUSE_TAMM_CPI)?;
+        assert_cpi_allowed(&self.margin_account.to_account_info(), DENY_TAMM_CPI)?;
+
+        assert_discriminator(&self.pool.to_account_info(), &TAMM_POOL_DISCRIMINATOR)?;
+        assert_pool_margin(&self.pool.to_account_info(), &self.margin_account.key())?;
+
+        Ok(())
+    }
+}
+
+/// Captures `lamports` of the hold, `WITHDRAW_ALL` captures all of it.
+#[access_control(ctx.accounts.validate())]
+pub fn process_capture_hold_from_tamm(
+    ctx: Context<CaptureHoldCpiTAmm>,
+    lamports: u64,
+) -> Result<()> {
+    let captured = capture_hold(
+        &ctx.accounts.margin_account.to_account_info(),
+        &mut ctx.accounts.hold,
+        lamports,
+    )?;
+    ctx.accounts.margin_account.reload()?;
+
+    let fee = cpi_fee(
+        &ctx.accounts.tswap.to_account_info(),
+        Caller::TAmm,
+        None,
+        ctx.accounts.fee_exemption.is_some(),
+        captured,
+    )?;
+    assert_available_lamports(
+        &ctx.accounts.margin_account.to_account_info(),
+        unwrap_int!(captured.checked_add(fee)),
+    )?;
+    consume_tswap_rate_limit(
+        &ctx.accounts.tswap.to_account_info(),
+        ctx.accounts.margin_account.key(),
+        Caller::TAmm,
+        captured,
+    )?;
+
+    transfer_lamports_from_pda(
+        &ctx.accounts.margin_account.to_account_info(),
+        &ctx.accounts.destination.to_account_info(),
+        captured,
+    )?;
+
+    if fee > 0 {
+        pay_cpi_fee(
+            &ctx.accounts.margin_account.to_account_info(),
+            &ctx.accounts.fee_vault,
+            fee,
+        )?;
+        emit_event(
+            &FeeEvent {
+                margin: ctx.accounts.margin_account.key(),
+                owner: ctx.accounts.margin_account.owner,
+                caller: Caller::TAmm,
+                caller_program: None,
+                amount: fee,
+                fee_vault: ctx.accounts.tswap.fee_vault,
+            },
+            &ctx.accounts.event_authority,
+            &ctx.accounts.program,
+            ctx.bumps.event_authority,
+        )?;
+    }
+
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &HoldEvent {
+            margin: margin.key(),
+            hold: ctx.accounts.hold.key(),
+            caller: Caller::TAmm,
+            caller_account: ctx.accounts.pool.key(),
+            amount: 0,
+            captured,
+            expiry_slot: ctx.accounts.hold.expiry_slot,
+            held_lamports: margin.held_lamports,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+    emit_event(
+        &WithdrawEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            caller: Caller::TAmm,
+            caller_program: None,
+            caller_account: Some(ctx.accounts.pool.key()),
+            mint: None,
+            amount: captured,
+            post_balance: margin.to_account_info().lamports(),
+            destination: ctx.accounts.destination.key(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    set_cpi_return_data(&ctx.accounts.margin_account.to_account_info(), captured)
+}
@@ -0,0 +1,47 @@
+//! Program owner (Tensor) revoking a program's access to margin accounts.
+
+use anchor_lang::prelude::*;
+
+use crate::{emit_event, error::ErrorCode, CpiConsumer, CpiConsumerEvent, TSwap};
+
+#[derive(Accounts)]
+pub struct CloseCpiConsumer<'info> {
+    #[account(seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    #[account(
+        mut,
+        seeds = [b"cpi_consumer".as_ref(), cpi_consumer.program_id.as_ref()],
+        bump = cpi_consumer.bump[0],
+        close = owner,
+    )]
+    pub cpi_consumer: Box<Account<'info, CpiConsumer>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_close_cpi_consumer(ctx: Context<CloseCpiConsumer>) -> Result<()> {
+    let cpi_consumer = &ctx.accounts.cpi_consumer;
+
+    emit_event(
+        &CpiConsumerEvent {
+            program_id: cpi_consumer.program_id,
+            seed_prefix: cpi_consumer.seed_prefix().to_vec(),
+            signer_discriminator: cpi_consumer.signer_discriminator,
+            registered: false,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
+use tensor_vipers::{throw_err, unwrap_opt};
 
-use crate::{MarginAccount, TSwap};
+use crate::{
+    assert_cosigned, assert_not_paused, constants::PAUSE_OWNER_WITHDRAWALS, emit_event,
+    error::ErrorCode, is_margin_account, load_margin_v2, margin_rent_destination, CloseMarginEvent,
+    MarginAccount, TSwap,
+};
 
 #[derive(Accounts)]
 pub struct CloseMarginAccount<'info> {
@@ -9,15 +14,8 @@ pub struct CloseMarginAccount<'info> {
 
     #[account(
         mut,
-        seeds = [
-            b"margin".as_ref(),
-            tswap.key().as_ref(),
-            owner.key().as_ref(),
-            &margin_account.nr.to_le_bytes()
-        ],
-        bump = margin_account.bump[0],
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
         has_one = owner,
-        close = owner
     )]
     pub margin_account: Box<Account<'info, MarginAccount>>,
 
@@ -25,10 +23,78 @@ pub struct CloseMarginAccount<'info> {
     pub owner: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: chosen by the owner; receives the rent-exempt reserve instead of the owner.
+    #[account(mut)]
+    pub rent_destination: Option<UncheckedAccount<'info>>,
+
+    /// Required once the amount exceeds the cosign threshold.
+    #[account(address = tswap.cosigner @ ErrorCode::BadCosigner)]
+    pub cosigner: Option<Signer<'info>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
 }
 
 //since we're storing all funds on the account itself, this will drain the funds to the owner
 //TODO: in the future when we add NFTs owned by margin account this will have to also check that no NFTs are left
-pub fn process_close_margin_account(_ctx: Context<CloseMarginAccount>) -> Result<()> {
-    Ok(())
+/// V1 accounts don't track attachments, so they have to be migrated before they can be closed.
+/// Pools and bids attached while still on V1 are not counted after the migration either, so
+/// migrated accounts also wait for the owner to reconcile them (`reconcile_attachments`).
+pub fn process_close_margin_account(ctx: Context<CloseMarginAccount>) -> Result<()> {
+    assert_not_paused(
+        &ctx.accounts.tswap.to_account_info(),
+        PAUSE_OWNER_WITHDRAWALS,
+    )?;
+
+    let margin_info = ctx.accounts.margin_account.to_account_info();
+
+    let margin = unwrap_opt!(load_margin_v2(&margin_info)?, ErrorCode::MarginNotMigrated);
+    if margin.has_attachments() {
+        throw_err!(ErrorCode::MarginInUse);
+    }
+    if margin.untracked_attachments {
+        throw_err!(ErrorCode::UntrackedAttachments);
+    }
+    // Re-created in the same slot, the account would get the generation grants were bound to.
+    if margin.generation == Clock::get()?.slot {
+        throw_err!(ErrorCode::MarginClosedTooSoon);
+    }
+    // Closing sends everything to the owner, which would skip the delay.
+    if margin.is_timelocked() || margin.pending_withdrawals > 0 {
+        throw_err!(ErrorCode::WithdrawalDelayed);
+    }
+
+    // Everything leaves escrow.
+    assert_cosigned(
+        &ctx.accounts.tswap.to_account_info(),
+        &margin_info,
+        &ctx.accounts.cosigner,
+        margin_info.lamports(),
+    )?;
+
+    let rent_destination = margin_rent_destination(
+        &margin_info,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.rent_destination,
+    )?;
+
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &CloseMarginEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            amount: margin.to_account_info().lamports(),
+            rent_destination: rent_destination.key(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    ctx.accounts.margin_account.close(rent_destination)
 }
@@ -0,0 +1,70 @@
+//! Owner sweeping several of their margin accounts into one.
+//!
+//! The source margin accounts are passed in as remaining accounts, once each. Each
+//! one keeps its rent and the lamports locked by pending withdrawal requests,
+//! everything else moves to the target, under the same rules as
+//! `transfer_between_margin_accounts`.
+
+use anchor_lang::prelude::*;
+use tensor_toolbox::transfer_lamports_from_pda;
+use tensor_vipers::throw_err;
+
+use crate::{
+    assert_margin_transfer, assert_owned_margin_account, assert_unique_margin_accounts,
+    available_lamports, emit_event, error::ErrorCode, is_margin_account, MarginAccount,
+    MarginTransferEvent,
+};
+
+#[derive(Accounts)]
+pub struct ConsolidateMarginAccounts<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&target_margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub target_margin_account: Box<Account<'info, MarginAccount>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_consolidate_margin_accounts<'info>(
+    ctx: Context<'_, '_, '_, 'info, ConsolidateMarginAccounts<'info>>,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let target_info = ctx.accounts.target_margin_account.to_account_info();
+    assert_unique_margin_accounts(ctx.remaining_accounts)?;
+
+    for source_info in ctx.remaining_accounts {
+        if source_info.key() == target_info.key() {
+            throw_err!(ErrorCode::BadMargin);
+        }
+        assert_owned_margin_account(source_info, &owner)?;
+
+        let lamports = available_lamports(source_info)?;
+        assert_margin_transfer(source_info, &target_info, lamports)?;
+        transfer_lamports_from_pda(source_info, &target_info, lamports)?;
+
+        emit_event(
+            &MarginTransferEvent {
+                owner,
+                source: source_info.key(),
+                destination: target_info.key(),
+                amount: lamports,
+                source_post_balance: source_info.lamports(),
+                destination_post_balance: target_info.lamports(),
+            },
+            &ctx.accounts.event_authority,
+            &ctx.accounts.program,
+            ctx.bumps.event_authority,
+        )?;
+    }
+
+    Ok(())
+}
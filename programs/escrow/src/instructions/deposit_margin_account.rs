@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{program::invoke, system_instruction};
 
-use crate::{MarginAccount, TSwap};
+use crate::{
+    assert_not_paused, constants::PAUSE_DEPOSITS, emit_event, error::ErrorCode, is_margin_account,
+    DepositEvent, MarginAccount, TSwap,
+};
 
 #[derive(Accounts)]
 pub struct DepositMarginAccount<'info> {
@@ -10,13 +13,7 @@ pub struct DepositMarginAccount<'info> {
 
     #[account(
         mut,
-        seeds = [
-            b"margin".as_ref(),
-            tswap.key().as_ref(),
-            owner.key().as_ref(),
-            &margin_account.nr.to_le_bytes()
-        ],
-        bump = margin_account.bump[0],
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
         has_one = owner,
     )]
     pub margin_account: Box<Account<'info, MarginAccount>>,
@@ -25,6 +22,13 @@ pub struct DepositMarginAccount<'info> {
     pub owner: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> DepositMarginAccount<'info> {
@@ -45,7 +49,21 @@ pub fn process_deposit_margin_account(
     ctx: Context<DepositMarginAccount>,
     lamports: u64,
 ) -> Result<()> {
+    assert_not_paused(&ctx.accounts.tswap.to_account_info(), PAUSE_DEPOSITS)?;
+
     ctx.accounts.transfer_lamports(lamports)?;
 
-    Ok(())
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &DepositEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            mint: None,
+            amount: lamports,
+            post_balance: margin.to_account_info().lamports(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
 }
@@ -0,0 +1,75 @@
+//! Anyone (a sponsor, a treasury or another program) funding a margin account
+//! without the owner's signature.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke, system_instruction};
+
+use crate::{
+    assert_not_paused, constants::PAUSE_DEPOSITS, emit_event, error::ErrorCode, is_margin_account,
+    DepositForEvent, MarginAccount, TSwap,
+};
+
+#[derive(Accounts)]
+pub struct DepositMarginAccountFor<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    /// CHECK: has_one on margin_account
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> DepositMarginAccountFor<'info> {
+    fn transfer_lamports(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.payer.key, &self.margin_account.key(), lamports),
+            &[
+                self.payer.to_account_info(),
+                self.margin_account.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    }
+}
+
+pub fn process_deposit_margin_account_for(
+    ctx: Context<DepositMarginAccountFor>,
+    lamports: u64,
+) -> Result<()> {
+    assert_not_paused(&ctx.accounts.tswap.to_account_info(), PAUSE_DEPOSITS)?;
+
+    ctx.accounts.transfer_lamports(lamports)?;
+
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &DepositForEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            payer: ctx.accounts.payer.key(),
+            amount: lamports,
+            post_balance: margin.to_account_info().lamports(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
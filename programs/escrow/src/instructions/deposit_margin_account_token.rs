@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    assert_not_paused, assert_supported_mint, constants::PAUSE_DEPOSITS, emit_event,
+    error::ErrorCode, is_margin_account, DepositEvent, MarginAccount, TSwap,
+};
+
+#[derive(Accounts)]
+pub struct DepositMarginAccountToken<'info> {
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    #[account(
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    // Token vault of the margin account, created on the first deposit of this mint.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = margin_account,
+        associated_token::token_program = token_program,
+    )]
+    pub margin_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> DepositMarginAccountToken<'info> {
+    fn transfer_tokens(&self, amount: u64) -> Result<()> {
+        transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.owner_ta.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to: self.margin_ta.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            amount,
+            self.mint.decimals,
+        )
+    }
+}
+
+pub fn process_deposit_margin_account_token(
+    ctx: Context<DepositMarginAccountToken>,
+    amount: u64,
+) -> Result<()> {
+    assert_not_paused(&ctx.accounts.tswap.to_account_info(), PAUSE_DEPOSITS)?;
+    assert_supported_mint(&ctx.accounts.mint.to_account_info())?;
+
+    ctx.accounts.transfer_tokens(amount)?;
+    ctx.accounts.margin_ta.reload()?;
+
+    emit_event(
+        &DepositEvent {
+            margin: ctx.accounts.margin_account.key(),
+            owner: ctx.accounts.owner.key(),
+            mint: Some(ctx.accounts.mint.key()),
+            amount,
+            post_balance: ctx.accounts.margin_ta.amount,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
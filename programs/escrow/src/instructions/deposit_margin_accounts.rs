@@ -0,0 +1,79 @@
+//! Owner funding several of their margin accounts in one instruction.
+//!
+//! The margin accounts are passed in as remaining accounts, once each and in the order
+//! of `amounts`.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke, system_instruction};
+use tensor_vipers::throw_err;
+
+use crate::{
+    assert_not_paused, assert_owned_margin_account, assert_unique_margin_accounts,
+    constants::PAUSE_DEPOSITS, emit_event, error::ErrorCode, DepositEvent, TSwap,
+};
+
+#[derive(Accounts)]
+pub struct DepositMarginAccounts<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> DepositMarginAccounts<'info> {
+    fn transfer_lamports(&self, margin_info: &AccountInfo<'info>, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.owner.key, margin_info.key, lamports),
+            &[
+                self.owner.to_account_info(),
+                margin_info.clone(),
+                self.system_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    }
+}
+
+pub fn process_deposit_margin_accounts<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositMarginAccounts<'info>>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    assert_not_paused(&ctx.accounts.tswap.to_account_info(), PAUSE_DEPOSITS)?;
+
+    if ctx.remaining_accounts.len() != amounts.len() {
+        throw_err!(ErrorCode::MarginCountMismatch);
+    }
+    assert_unique_margin_accounts(ctx.remaining_accounts)?;
+
+    let owner = ctx.accounts.owner.key();
+    for (margin_info, lamports) in ctx.remaining_accounts.iter().zip(amounts) {
+        assert_owned_margin_account(margin_info, &owner)?;
+
+        ctx.accounts.transfer_lamports(margin_info, lamports)?;
+
+        emit_event(
+            &DepositEvent {
+                margin: margin_info.key(),
+                owner,
+                mint: None,
+                amount: lamports,
+                post_balance: margin_info.lamports(),
+            },
+            &ctx.accounts.event_authority,
+            &ctx.accounts.program,
+            ctx.bumps.event_authority,
+        )?;
+    }
+
+    Ok(())
+}
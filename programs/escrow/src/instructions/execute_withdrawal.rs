@@ -0,0 +1,100 @@
+//! Owner executing a withdrawal request once its delay has passed.
+
+use anchor_lang::prelude::*;
+use tensor_toolbox::transfer_lamports_from_pda;
+use tensor_vipers::{throw_err, Validate};
+
+use crate::{
+    assert_cosigned, assert_not_paused, constants::PAUSE_OWNER_WITHDRAWALS, emit_event,
+    error::ErrorCode, is_margin_account, Caller, MarginAccountV2, TSwap, WithdrawEvent,
+    WithdrawalRequest,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawal<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal".as_ref(),
+            margin_account.key().as_ref(),
+            &withdrawal_request.request_nr.to_le_bytes(),
+        ],
+        bump = withdrawal_request.bump[0],
+        has_one = margin_account,
+        close = owner,
+    )]
+    pub withdrawal_request: Box<Account<'info, WithdrawalRequest>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// Required once the amount exceeds the cosign threshold.
+    #[account(address = tswap.cosigner @ ErrorCode::BadCosigner)]
+    pub cosigner: Option<Signer<'info>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for ExecuteWithdrawal<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_not_paused(&self.tswap.to_account_info(), PAUSE_OWNER_WITHDRAWALS)?;
+
+        if Clock::get()?.unix_timestamp < self.withdrawal_request.unlock_at {
+            throw_err!(ErrorCode::WithdrawalLocked);
+        }
+
+        assert_cosigned(
+            &self.tswap.to_account_info(),
+            &self.margin_account.to_account_info(),
+            &self.cosigner,
+            self.withdrawal_request.amount,
+        )
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn process_execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+    let amount = ctx.accounts.withdrawal_request.amount;
+    let margin = &mut ctx.accounts.margin_account;
+    margin.pending_withdrawals = margin.pending_withdrawals.saturating_sub(amount);
+
+    transfer_lamports_from_pda(
+        &margin.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        amount,
+    )?;
+
+    emit_event(
+        &WithdrawEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            caller: Caller::Owner,
+            caller_program: None,
+            caller_account: None,
+            mint: None,
+            amount,
+            post_balance: margin.to_account_info().lamports(),
+            destination: margin.owner,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
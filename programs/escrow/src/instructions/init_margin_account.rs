@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{MarginAccount, TSwap, MARGIN_SIZE};
+use crate::{
+    constants::CURRENT_MARGIN_VERSION, emit_event, InitMarginEvent, MarginAccountV2, TSwap,
+    MARGIN_V2_SIZE,
+};
 
 #[derive(Accounts)]
 #[instruction(margin_nr: u16)]
@@ -13,20 +16,27 @@ pub struct InitMarginAccount<'info> {
         payer = owner,
         seeds = [
             b"margin".as_ref(),
-            // TODO: remove tswap from seed in V2 (annoying to have to pass account eg in CPIs).
+            // Legacy family, `init_margin_account_v2` derives accounts without tswap.
             tswap.key().as_ref(),
             owner.key().as_ref(),
             &margin_nr.to_le_bytes()
         ],
         bump,
-        space = MARGIN_SIZE,
+        space = MARGIN_V2_SIZE,
     )]
-    pub margin_account: Box<Account<'info, MarginAccount>>,
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
 
     #[account(mut)]
     pub owner: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
 }
 
 pub fn process_init_margin_account(
@@ -40,6 +50,18 @@ pub fn process_init_margin_account(
     margin.name = name;
     margin.nr = margin_nr;
     margin.bump = [ctx.bumps.margin_account];
+    margin.version = CURRENT_MARGIN_VERSION;
+    margin.generation = Clock::get()?.slot;
 
-    Ok(())
+    emit_event(
+        &InitMarginEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            nr: margin_nr,
+            version: margin.version,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
 }
@@ -0,0 +1,64 @@
+//! Program owner (Tensor) creating the program authority + metadata (eg swap fees) PDA account (TSwap)
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    assert_fee_vault, assert_tswap_config, constants::CURRENT_TSWAP_VERSION, emit_event,
+    TSwapConfig, TSwapUpdateEvent, TSwapV2, TSWAP_V2_SIZE,
+};
+
+#[derive(Accounts)]
+pub struct InitTSwap<'info> {
+    #[account(init, seeds = [], bump, payer = owner, space = TSWAP_V2_SIZE)]
+    pub tswap: Box<Account<'info, TSwapV2>>,
+
+    /// CHECK: assert_fee_vault in validate_init
+    pub fee_vault: UncheckedAccount<'info>,
+
+    /// We ask also for a signature just to make sure this wallet can actually sign things
+    pub cosigner: Signer<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> InitTSwap<'info> {
+    fn validate_init(&self, config: &TSwapConfig) -> Result<()> {
+        assert_tswap_config(config)?;
+        assert_fee_vault(&self.fee_vault)
+    }
+}
+
+#[access_control(ctx.accounts.validate_init(&config))]
+pub fn process_init_tswap(ctx: Context<InitTSwap>, config: TSwapConfig) -> Result<()> {
+    let tswap = &mut ctx.accounts.tswap;
+
+    tswap.version = CURRENT_TSWAP_VERSION;
+    tswap.bump = [ctx.bumps.tswap];
+    tswap.owner = ctx.accounts.owner.key();
+    tswap.config = config;
+    tswap.fee_vault = ctx.accounts.fee_vault.key();
+    tswap.cosigner = ctx.accounts.cosigner.key();
+
+    emit_event(
+        &TSwapUpdateEvent {
+            tswap: tswap.key(),
+            owner: tswap.owner,
+            fee_vault: tswap.fee_vault,
+            cosigner: tswap.cosigner,
+            fee_bps: tswap.config.fee_bps,
+            pending_owner: tswap.pending_owner,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,106 @@
+//! Program owner (Tensor) registering a program allowed to withdraw from margin accounts via CPI.
+
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+use tensor_vipers::throw_err;
+
+use crate::{
+    constants::CURRENT_CPI_CONSUMER_VERSION, emit_event, error::ErrorCode, CpiConsumer,
+    CpiConsumerEvent, CpiConsumerUpdateEvent, TSwap, CPI_CONSUMER_SIZE, MAX_SEED_PREFIX_LEN,
+};
+
+#[derive(Accounts)]
+pub struct InitUpdateCpiConsumer<'info> {
+    #[account(seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    // NB: we can call this multiple times (init_if_needed) eg to update the signer discriminator.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [b"cpi_consumer".as_ref(), consumer_program.key().as_ref()],
+        bump,
+        space = CPI_CONSUMER_SIZE,
+    )]
+    pub cpi_consumer: Box<Account<'info, CpiConsumer>>,
+
+    /// CHECK: the consumer program, has to be executable
+    #[account(executable)]
+    pub consumer_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> InitUpdateCpiConsumer<'info> {
+    fn validate_consumer(&self, seed_prefix: &[u8]) -> Result<()> {
+        // TAMM and TCOMP withdraw through their own instructions, registering them would
+        // skip the pool and bid checks.
+        let reserved = [
+            Pubkey::from_str("TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg").unwrap(),
+            Pubkey::from_str("TCMPhJdwDryooaGtiocG1u3xcYbRpiJzb283XfCZsDp").unwrap(),
+        ];
+        if reserved.contains(self.consumer_program.key) {
+            throw_err!(ErrorCode::ReservedCpiConsumer);
+        }
+
+        if seed_prefix.is_empty() || seed_prefix.len() > MAX_SEED_PREFIX_LEN {
+            throw_err!(ErrorCode::BadSeedPrefix);
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate_consumer(&seed_prefix))]
+pub fn process_init_update_cpi_consumer(
+    ctx: Context<InitUpdateCpiConsumer>,
+    seed_prefix: Vec<u8>,
+    signer_discriminator: [u8; 8],
+) -> Result<()> {
+    let cpi_consumer = &mut ctx.accounts.cpi_consumer;
+    // Freshly created by init_if_needed.
+    let created = cpi_consumer.version == 0;
+
+    cpi_consumer.version = CURRENT_CPI_CONSUMER_VERSION;
+    cpi_consumer.bump = [ctx.bumps.cpi_consumer];
+    cpi_consumer.program_id = ctx.accounts.consumer_program.key();
+    cpi_consumer.seed_prefix = [0; MAX_SEED_PREFIX_LEN];
+    cpi_consumer.seed_prefix[..seed_prefix.len()].copy_from_slice(&seed_prefix);
+    cpi_consumer.seed_prefix_len = seed_prefix.len() as u8;
+    cpi_consumer.signer_discriminator = signer_discriminator;
+
+    if created {
+        emit_event(
+            &CpiConsumerEvent {
+                program_id: cpi_consumer.program_id,
+                seed_prefix,
+                signer_discriminator,
+                registered: true,
+            },
+            &ctx.accounts.event_authority,
+            &ctx.accounts.program,
+            ctx.bumps.event_authority,
+        )
+    } else {
+        emit_event(
+            &CpiConsumerUpdateEvent {
+                program_id: cpi_consumer.program_id,
+                seed_prefix,
+                signer_discriminator,
+            },
+            &ctx.accounts.event_authority,
+            &ctx.accounts.program,
+            ctx.bumps.event_authority,
+        )
+    }
+}
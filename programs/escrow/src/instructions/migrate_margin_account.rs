@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use tensor_vipers::{throw_err, Validate};
+
+use crate::{
+    emit_event, error::ErrorCode, migrate_margin_in_place, top_up_margin_v2_rent, MarginAccount,
+    MigrateMarginEvent, TSwap, MARGIN_SIZE, MARGIN_V2_SIZE,
+};
+
+#[derive(Accounts)]
+pub struct MigrateMarginAccount<'info> {
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    // Reallocated in place, so the address (and every pool/bid pointing at it) stays the same.
+    #[account(
+        mut,
+        seeds = [
+            b"margin".as_ref(),
+            tswap.key().as_ref(),
+            owner.key().as_ref(),
+            &margin_account.nr.to_le_bytes()
+        ],
+        bump = margin_account.bump[0],
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    /// CHECK: has_one on margin_account
+    pub owner: UncheckedAccount<'info>,
+
+    /// Either the owner or a permissionless crank.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for MigrateMarginAccount<'info> {
+    fn validate(&self) -> Result<()> {
+        if self.margin_account.to_account_info().data_len() != MARGIN_SIZE {
+            throw_err!(ErrorCode::MarginAlreadyMigrated);
+        }
+
+        Ok(())
+    }
+}
+
+/// The extra rent is always borne by the owner: when the owner signs it is paid from
+/// their wallet, when a crank signs it is taken from the balance already held in the account.
+/// Counters start at zero, pools and bids attached while on V1 are not tracked: the account
+/// can't be closed until the owner reconciles them.
+#[access_control(ctx.accounts.validate())]
+pub fn process_migrate_margin_account(ctx: Context<MigrateMarginAccount>) -> Result<()> {
+    let margin_info = ctx.accounts.margin_account.to_account_info();
+    let rent = Rent::get()?.minimum_balance(MARGIN_V2_SIZE);
+
+    if ctx.accounts.payer.key() == ctx.accounts.owner.key() {
+        top_up_margin_v2_rent(
+            &margin_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+    } else if margin_info.lamports() < rent {
+        throw_err!(ErrorCode::MarginRentNotCovered);
+    }
+
+    let migrated = migrate_margin_in_place(&margin_info, &ctx.accounts.margin_account)?;
+
+    emit_event(
+        &MigrateMarginEvent {
+            margin: margin_info.key(),
+            owner: migrated.owner,
+            payer: ctx.accounts.payer.key(),
+            version: migrated.version,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,101 @@
+//! Program owner moving the TSwap account to the V2 layout.
+
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use tensor_vipers::{throw_err, Validate};
+
+use crate::{
+    constants::CURRENT_TSWAP_VERSION, emit_event, error::ErrorCode, RateLimit, TSwap,
+    TSwapUpdateEvent, TSwapV2, TSWAP_SIZE, TSWAP_V2_SIZE,
+};
+
+#[derive(Accounts)]
+pub struct MigrateTSwap<'info> {
+    // Reallocated in place, so the address (and every margin account derived from it) stays the same.
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for MigrateTSwap<'info> {
+    fn validate(&self) -> Result<()> {
+        if self.tswap.to_account_info().data_len() != TSWAP_SIZE {
+            throw_err!(ErrorCode::TSwapAlreadyMigrated);
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn process_migrate_tswap(ctx: Context<MigrateTSwap>) -> Result<()> {
+    let tswap_info = ctx.accounts.tswap.to_account_info();
+    let rent = Rent::get()?.minimum_balance(TSWAP_V2_SIZE);
+
+    let top_up = rent.saturating_sub(tswap_info.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: tswap_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    tswap_info.realloc(TSWAP_V2_SIZE, true)?;
+
+    let tswap = &mut ctx.accounts.tswap;
+    // the V1 view is written back on exit, keep it in sync with the V2 version
+    tswap.version = CURRENT_TSWAP_VERSION;
+    let migrated = TSwapV2 {
+        version: CURRENT_TSWAP_VERSION,
+        bump: tswap.bump,
+        config: tswap.config,
+        owner: tswap.owner,
+        fee_vault: tswap.fee_vault,
+        cosigner: tswap.cosigner,
+        pending_owner: Pubkey::default(),
+        guardian: Pubkey::default(),
+        paused: 0,
+        tamm_rate_limit: RateLimit::default(),
+        tcomp_rate_limit: RateLimit::default(),
+        fees_enabled: false,
+        tamm_fee_bps: None,
+        tcomp_fee_bps: None,
+        cosign_threshold: None,
+        hold_expiry_slots: 0,
+        _reserved: [0; 95],
+    };
+    migrated.try_serialize(&mut &mut tswap_info.try_borrow_mut_data()?[..])?;
+
+    emit_event(
+        &TSwapUpdateEvent {
+            tswap: tswap_info.key(),
+            owner: migrated.owner,
+            fee_vault: migrated.fee_vault,
+            cosigner: migrated.cosigner,
+            fee_bps: migrated.config.fee_bps,
+            pending_owner: migrated.pending_owner,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -1,18 +1,124 @@
+pub mod accept_margin_owner;
+pub mod accept_tswap_owner;
+pub mod add_fee_exemption;
+pub mod approve_allowance;
+pub mod approve_consumer;
+pub mod attach_margin_account_from_tamm;
+pub mod attach_margin_account_from_tcomp;
+pub mod cancel_withdrawal;
+pub mod capture_hold_from_tamm;
+pub mod capture_hold_from_tcomp;
+pub mod close_cpi_consumer;
 pub mod close_margin_account;
+pub mod consolidate_margin_accounts;
 pub mod deposit_margin_account;
+pub mod deposit_margin_account_for;
+pub mod deposit_margin_account_token;
+pub mod deposit_margin_accounts;
+pub mod execute_withdrawal;
 pub mod init_margin_account;
-pub mod init_update_tswap;
+pub mod init_margin_account_v2;
+pub mod init_tswap;
+pub mod init_update_cpi_consumer;
+pub mod migrate_margin_account;
+pub mod migrate_tswap;
+pub mod place_hold_from_tamm;
+pub mod place_hold_from_tcomp;
+pub mod propose_margin_owner;
+pub mod propose_tswap_owner;
+pub mod reconcile_attachments;
+pub mod release_hold;
+pub mod release_reservation;
+pub mod remove_fee_exemption;
+pub mod request_withdrawal;
+pub mod reserve_margin_lamports;
+pub mod reserve_margin_lamports_cpi;
+pub mod revoke_allowance;
+pub mod revoke_consumer;
+pub mod set_cosign_threshold;
+pub mod set_cosigner;
+pub mod set_cpi_denied;
+pub mod set_cpi_fee;
+pub mod set_fee_vault;
+pub mod set_fees_enabled;
+pub mod set_guardian;
+pub mod set_hold_expiry;
+pub mod set_margin_cosign_threshold;
+pub mod set_paused;
+pub mod set_rate_limit;
+pub mod set_withdrawal_delay;
+pub mod spend_allowance;
+pub mod transfer_between_margin_accounts;
+pub mod update_tswap_config;
+pub mod withdraw_fees;
 pub mod withdraw_margin_account;
+pub mod withdraw_margin_account_cpi;
 pub mod withdraw_margin_account_from_tamm;
 pub mod withdraw_margin_account_from_tcomp;
+pub mod withdraw_margin_account_token;
+pub mod withdraw_margin_account_token_from_tamm;
+pub mod withdraw_margin_account_token_from_tcomp;
 
+pub use accept_margin_owner::*;
+pub use accept_tswap_owner::*;
+pub use add_fee_exemption::*;
+pub use approve_allowance::*;
+pub use approve_consumer::*;
+pub use attach_margin_account_from_tamm::*;
+pub use attach_margin_account_from_tcomp::*;
+pub use cancel_withdrawal::*;
+pub use capture_hold_from_tamm::*;
+pub use capture_hold_from_tcomp::*;
+pub use close_cpi_consumer::*;
 pub use close_margin_account::*;
+pub use consolidate_margin_accounts::*;
 pub use deposit_margin_account::*;
+pub use deposit_margin_account_for::*;
+pub use deposit_margin_account_token::*;
+pub use deposit_margin_accounts::*;
+pub use execute_withdrawal::*;
 pub use init_margin_account::*;
-pub use init_update_tswap::*;
+pub use init_margin_account_v2::*;
+pub use init_tswap::*;
+pub use init_update_cpi_consumer::*;
+pub use migrate_margin_account::*;
+pub use migrate_tswap::*;
+pub use place_hold_from_tamm::*;
+pub use place_hold_from_tcomp::*;
+pub use propose_margin_owner::*;
+pub use propose_tswap_owner::*;
+pub use reconcile_attachments::*;
+pub use release_hold::*;
+pub use release_reservation::*;
+pub use remove_fee_exemption::*;
+pub use request_withdrawal::*;
+pub use reserve_margin_lamports::*;
+pub use reserve_margin_lamports_cpi::*;
+pub use revoke_allowance::*;
+pub use revoke_consumer::*;
+pub use set_cosign_threshold::*;
+pub use set_cosigner::*;
+pub use set_cpi_denied::*;
+pub use set_cpi_fee::*;
+pub use set_fee_vault::*;
+pub use set_fees_enabled::*;
+pub use set_guardian::*;
+pub use set_hold_expiry::*;
+pub use set_margin_cosign_threshold::*;
+pub use set_paused::*;
+pub use set_rate_limit::*;
+pub use set_withdrawal_delay::*;
+pub use spend_allowance::*;
+pub use transfer_between_margin_accounts::*;
+pub use update_tswap_config::*;
+pub use withdraw_fees::*;
 pub use withdraw_margin_account::*;
+pub use withdraw_margin_account_cpi::*;
 pub use withdraw_margin_account_from_tamm::*;
 pub use withdraw_margin_account_from_tcomp::*;
+pub use withdraw_margin_account_token::*;
+pub use withdraw_margin_account_token_from_tamm::*;
+pub use withdraw_margin_account_token_from_tcomp::*;
 
 use tensor_vipers::throw_err;
 
@@ -30,6 +136,57 @@ pub fn margin_pda(tswap: &Pubkey, owner: &Pubkey, nr: u16) -> (Pubkey, u8) {
     )
 }
 
+/// Margin accounts derived without the TSwap address, always on the V2 layout.
+pub fn margin_pda_v2(owner: &Pubkey, nr: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"margin".as_ref(), owner.as_ref(), &nr.to_le_bytes()],
+        &crate::id(),
+    )
+}
+
+/// Checks `key` against both margin account families, using the stored bump.
+pub fn is_margin_address(key: &Pubkey, owner: &Pubkey, nr: u16, bump: u8) -> bool {
+    let nr = nr.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"margin".as_ref(), owner.as_ref(), &nr, &[bump]];
+    if Pubkey::create_program_address(seeds, &crate::id()) == Ok(*key) {
+        return true;
+    }
+
+    let tswap = get_tswap_addr();
+    let seeds: &[&[u8]] = &[
+        b"margin".as_ref(),
+        tswap.as_ref(),
+        owner.as_ref(),
+        &nr,
+        &[bump],
+    ];
+    Pubkey::create_program_address(seeds, &crate::id()) == Ok(*key)
+}
+
+/// Checks that `margin_info` is a margin account of either family. The address is
+/// derived from the seed owner, so accounts keep passing after an ownership handoff.
+pub fn is_margin_account(margin_info: &AccountInfo) -> bool {
+    if *margin_info.owner != crate::id() {
+        return false;
+    }
+    let Ok(data) = margin_info.try_borrow_data() else {
+        return false;
+    };
+    let Ok(margin) = MarginAccount::try_deserialize(&mut &data[..]) else {
+        return false;
+    };
+    let seed_owner = if data.len() >= MARGIN_V2_SIZE {
+        match MarginAccountV2::try_deserialize(&mut &data[..]) {
+            Ok(margin) => margin.seed_owner(),
+            Err(_) => return false,
+        }
+    } else {
+        margin.owner
+    };
+
+    is_margin_address(margin_info.key, &seed_owner, margin.nr, margin.bump[0])
+}
+
 pub fn get_tswap_addr() -> Pubkey {
     let (pda, _) = Pubkey::find_program_address(&[], &crate::id());
     pda
@@ -44,17 +201,13 @@ pub fn assert_decode_margin_account<'info>(
     let margin_account: Box<MarginAccount> =
         Box::new(AccountDeserialize::try_deserialize(&mut data)?);
 
-    let program_id = &crate::id();
-    let (key, _) = margin_pda(&get_tswap_addr(), &owner.key(), margin_account.nr);
-    if key != *margin_account_info.key {
-        throw_err!(ErrorCode::BadMargin);
-    }
-    // Check program owner (redundant because of find_program_address above, but why not).
-    if *margin_account_info.owner != *program_id {
+    // Checks the address against the seed owner, which is not `owner` once the
+    // account has been handed off, and the program owner.
+    if !is_margin_account(margin_account_info) {
         throw_err!(ErrorCode::BadMargin);
     }
     // Check normal owner (not redundant - this actually checks if the account is
-    // initialized and stores the owner correctly).
+    // initialized and stores the current owner).
     if margin_account.owner != owner.key() {
         throw_err!(ErrorCode::BadMargin);
     }
@@ -0,0 +1,130 @@
+//! TAMM pool authorizing a later capture of part of its shared escrow.
+
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+use tensor_vipers::{unwrap_int, Validate};
+
+use crate::{
+    assert_available_lamports, assert_cpi_allowed, assert_not_paused,
+    constants::{CURRENT_HOLD_VERSION, DENY_TAMM_CPI, PAUSE_TAMM_CPI},
+    emit_event,
+    error::ErrorCode,
+    hold_expiry_slots, is_margin_account, set_cpi_return_data, Caller, Hold, HoldEvent,
+    MarginAccountV2, TSwap, HOLD_SIZE,
+};
+
+use super::{
+    assert_discriminator, assert_pool_currency, assert_pool_margin,
+    constants::TAMM_POOL_DISCRIMINATOR,
+};
+
+#[derive(Accounts)]
+#[instruction(bump: u8, pool_id: [u8; 32], hold_id: [u8; 32])]
+pub struct PlaceHoldCpiTAmm<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    // Same signing PDA as WithdrawMarginAccountCpiTAmm.
+    #[account(
+        seeds=[b"pool".as_ref(), owner.key().as_ref(), pool_id.as_ref()],
+        seeds::program = Pubkey::from_str("TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg").unwrap(),
+        bump = bump,
+    )]
+    pub pool: Signer<'info>,
+
+    /// CHECK: has_one on margin_account
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            b"hold".as_ref(),
+            margin_account.key().as_ref(),
+            pool.key().as_ref(),
+            hold_id.as_ref(),
+        ],
+        bump,
+        space = HOLD_SIZE,
+    )]
+    pub hold: Box<Account<'info, Hold>>,
+
+    // The pool holds data, so it can't pay for the hold itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for PlaceHoldCpiTAmm<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_not_paused(&self.tswap.to_account_info(), PAUSE_TAMM_CPI)?;
+        assert_cpi_allowed(&self.margin_account.to_account_info(), DENY_TAMM_CPI)?;
+
+        assert_discriminator(&self.pool.to_account_info(), &TAMM_POOL_DISCRIMINATOR)?;
+        assert_pool_margin(&self.pool.to_account_info(), &self.margin_account.key())?;
+        assert_pool_currency(&self.pool.to_account_info(), &Pubkey::default())?;
+
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn process_place_hold_from_tamm(
+    ctx: Context<PlaceHoldCpiTAmm>,
+    hold_id: [u8; 32],
+    lamports: u64,
+) -> Result<()> {
+    assert_available_lamports(&ctx.accounts.margin_account.to_account_info(), lamports)?;
+
+    let expiry_slot = unwrap_int!(Clock::get()?
+        .slot
+        .checked_add(hold_expiry_slots(&ctx.accounts.tswap.to_account_info())?));
+    **ctx.accounts.hold = Hold {
+        version: CURRENT_HOLD_VERSION,
+        bump: [ctx.bumps.hold],
+        margin_account: ctx.accounts.margin_account.key(),
+        caller_account: ctx.accounts.pool.key(),
+        caller: Caller::TAmm,
+        hold_id,
+        rent_payer: ctx.accounts.payer.key(),
+        amount: lamports,
+        expiry_slot,
+        _reserved: [0; 32],
+    };
+
+    let margin = &mut ctx.accounts.margin_account;
+    margin.held_lamports = unwrap_int!(margin.held_lamports.checked_add(lamports));
+
+    emit_event(
+        &HoldEvent {
+            margin: margin.key(),
+            hold: ctx.accounts.hold.key(),
+            caller: Caller::TAmm,
+            caller_account: ctx.accounts.pool.key(),
+            amount: lamports,
+            captured: 0,
+            expiry_slot,
+            held_lamports: margin.held_lamports,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    set_cpi_return_data(&ctx.accounts.margin_account.to_account_info(), 0)
+}
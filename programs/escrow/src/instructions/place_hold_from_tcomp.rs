@@ -0,0 +1,137 @@
+//! TCOMP bid authorizing a later capture of part of its margin account.
+
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+use tensor_vipers::{unwrap_int, Validate};
+
+use crate::{
+    assert_available_lamports, assert_cpi_allowed, assert_not_paused,
+    constants::{CURRENT_HOLD_VERSION, DENY_TCOMP_CPI, PAUSE_TCOMP_CPI},
+    emit_event,
+    error::ErrorCode,
+    hold_expiry_slots, is_margin_account, set_cpi_return_data, Caller, Hold, HoldEvent,
+    MarginAccountV2, TSwap, HOLD_SIZE,
+};
+
+use super::{
+    assert_bid_currency, assert_bid_margin, assert_bid_remaining, assert_discriminator,
+    constants::TCOMP_BID_STATE_DISCRIMINATOR,
+};
+
+#[derive(Accounts)]
+#[instruction(bump: u8, bid_id: Pubkey, hold_id: [u8; 32])]
+pub struct PlaceHoldCpiTcomp<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    // Same signing PDA as WithdrawMarginAccountCpiTcomp.
+    #[account(
+        seeds=[b"bid_state".as_ref(), owner.key().as_ref(), bid_id.as_ref()],
+        seeds::program = Pubkey::from_str("TCMPhJdwDryooaGtiocG1u3xcYbRpiJzb283XfCZsDp").unwrap(),
+        bump = bump,
+    )]
+    pub bid_state: Signer<'info>,
+
+    /// CHECK: has_one on margin_account, seeds in bid_state
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            b"hold".as_ref(),
+            margin_account.key().as_ref(),
+            bid_state.key().as_ref(),
+            hold_id.as_ref(),
+        ],
+        bump,
+        space = HOLD_SIZE,
+    )]
+    pub hold: Box<Account<'info, Hold>>,
+
+    // The bid state holds data, so it can't pay for the hold itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for PlaceHoldCpiTcomp<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_not_paused(&self.tswap.to_account_info(), PAUSE_TCOMP_CPI)?;
+        assert_cpi_allowed(&self.margin_account.to_account_info(), DENY_TCOMP_CPI)?;
+
+        assert_discriminator(
+            &self.bid_state.to_account_info(),
+            &TCOMP_BID_STATE_DISCRIMINATOR,
+        )?;
+        assert_bid_currency(&self.bid_state.to_account_info(), None)
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn process_place_hold_from_tcomp(
+    ctx: Context<PlaceHoldCpiTcomp>,
+    hold_id: [u8; 32],
+    lamports: u64,
+) -> Result<()> {
+    // A hold can cover several fills, so it is capped by the whole rest of the bid.
+    assert_bid_margin(
+        &ctx.accounts.bid_state.to_account_info(),
+        &ctx.accounts.margin_account.key(),
+        0,
+    )?;
+    assert_bid_remaining(&ctx.accounts.bid_state.to_account_info(), lamports)?;
+    assert_available_lamports(&ctx.accounts.margin_account.to_account_info(), lamports)?;
+
+    let expiry_slot = unwrap_int!(Clock::get()?
+        .slot
+        .checked_add(hold_expiry_slots(&ctx.accounts.tswap.to_account_info())?));
+    **ctx.accounts.hold = Hold {
+        version: CURRENT_HOLD_VERSION,
+        bump: [ctx.bumps.hold],
+        margin_account: ctx.accounts.margin_account.key(),
+        caller_account: ctx.accounts.bid_state.key(),
+        caller: Caller::Tcomp,
+        hold_id,
+        rent_payer: ctx.accounts.payer.key(),
+        amount: lamports,
+        expiry_slot,
+        _reserved: [0; 32],
+    };
+
+    let margin = &mut ctx.accounts.margin_account;
+    margin.held_lamports = unwrap_int!(margin.held_lamports.checked_add(lamports));
+
+    emit_event(
+        &HoldEvent {
+            margin: margin.key(),
+            hold: ctx.accounts.hold.key(),
+            caller: Caller::Tcomp,
+            caller_account: ctx.accounts.bid_state.key(),
+            amount: lamports,
+            captured: 0,
+            expiry_slot,
+            held_lamports: margin.held_lamports,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    set_cpi_return_data(&ctx.accounts.margin_account.to_account_info(), 0)
+}
@@ -0,0 +1,52 @@
+//! Owner proposing a new owner for the margin account.
+//!
+//! Ownership only changes once the proposed owner accepts, so a typo can't lock
+//! the funds away. Proposing the default key cancels a pending handoff.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::throw_err;
+
+use crate::{emit_event, error::ErrorCode, is_margin_account, MarginAccountV2, OwnerTransferEvent};
+
+#[derive(Accounts)]
+pub struct ProposeMarginOwner<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: any key can be proposed, it has to sign to accept
+    pub new_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_propose_margin_owner(ctx: Context<ProposeMarginOwner>) -> Result<()> {
+    let margin = &mut ctx.accounts.margin_account;
+    // a handoff moves every lamport, so it has to respect the delay too
+    if margin.is_timelocked() {
+        throw_err!(ErrorCode::WithdrawalDelayed);
+    }
+    margin.pending_owner = ctx.accounts.new_owner.key();
+
+    emit_event(
+        &OwnerTransferEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            new_owner: margin.pending_owner,
+            accepted: false,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,45 @@
+//! Program owner proposing a new TSwap owner.
+//!
+//! The owner only changes once the proposed owner accepts, proposing the default
+//! key cancels a pending handoff.
+
+use anchor_lang::prelude::*;
+
+use crate::{emit_event, error::ErrorCode, TSwapUpdateEvent, TSwapV2};
+
+#[derive(Accounts)]
+pub struct ProposeTSwapOwner<'info> {
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwapV2>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: any key can be proposed, it has to sign to accept
+    pub new_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_propose_tswap_owner(ctx: Context<ProposeTSwapOwner>) -> Result<()> {
+    let tswap = &mut ctx.accounts.tswap;
+    tswap.pending_owner = ctx.accounts.new_owner.key();
+
+    emit_event(
+        &TSwapUpdateEvent {
+            tswap: tswap.key(),
+            owner: tswap.owner,
+            fee_vault: tswap.fee_vault,
+            cosigner: tswap.cosigner,
+            fee_bps: tswap.config.fee_bps,
+            pending_owner: tswap.pending_owner,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
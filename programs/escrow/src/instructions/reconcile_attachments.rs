@@ -0,0 +1,43 @@
+//! Owner confirming that no pool or bid attached while the margin account was on V1 is left.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    emit_event, error::ErrorCode, is_margin_account, MarginAccountV2, ReconcileAttachmentsEvent,
+};
+
+#[derive(Accounts)]
+pub struct ReconcileAttachments<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+/// Pools and bids attached on V1 can't be counted on-chain, so the owner vouches that they
+/// were closed (or moved to another margin account). Only unblocks closing the account.
+pub fn process_reconcile_attachments(ctx: Context<ReconcileAttachments>) -> Result<()> {
+    let margin = &mut ctx.accounts.margin_account;
+    margin.untracked_attachments = false;
+
+    emit_event(
+        &ReconcileAttachmentsEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
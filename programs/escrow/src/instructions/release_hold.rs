@@ -0,0 +1,83 @@
+//! Caller (or anyone, once the hold expired) handing a hold back to the available
+//! balance and closing it.
+//!
+//! The margin account only tracks the total it holds, not when each hold expires, so
+//! an expired hold keeps its lamports locked (and the account open) until it is
+//! released here.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::unwrap_int;
+
+use crate::{emit_event, error::ErrorCode, is_margin_account, Hold, HoldEvent, MarginAccountV2};
+
+#[derive(Accounts)]
+pub struct ReleaseHold<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"hold".as_ref(),
+            margin_account.key().as_ref(),
+            hold.caller_account.as_ref(),
+            hold.hold_id.as_ref(),
+        ],
+        bump = hold.bump[0],
+        has_one = margin_account,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub hold: Box<Account<'info, Hold>>,
+
+    /// The hold's caller account, only required until the hold expires.
+    pub authority: Option<Signer<'info>>,
+
+    /// CHECK: has_one on hold, refunded the hold's rent
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> ReleaseHold<'info> {
+    fn validate_release(&self) -> Result<()> {
+        let by_caller = matches!(&self.authority, Some(authority) if authority.key() == self.hold.caller_account);
+        if !by_caller && !self.hold.is_expired(Clock::get()?.slot) {
+            return Err(ErrorCode::HoldNotExpired.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate_release())]
+pub fn process_release_hold(ctx: Context<ReleaseHold>) -> Result<()> {
+    let hold = &ctx.accounts.hold;
+    let margin = &mut ctx.accounts.margin_account;
+    margin.held_lamports = unwrap_int!(margin.held_lamports.checked_sub(hold.amount));
+
+    emit_event(
+        &HoldEvent {
+            margin: margin.key(),
+            hold: hold.key(),
+            caller: hold.caller,
+            caller_account: hold.caller_account,
+            amount: 0,
+            captured: 0,
+            expiry_slot: hold.expiry_slot,
+            held_lamports: margin.held_lamports,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
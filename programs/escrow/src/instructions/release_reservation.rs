@@ -0,0 +1,79 @@
+//! Owner (or the caller it is reserved for) handing reserved lamports back to the
+//! available balance. The reservation is closed once empty.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    emit_event, error::ErrorCode, is_margin_account, MarginAccountV2, Reservation, ReservationEvent,
+};
+
+#[derive(Accounts)]
+pub struct ReleaseReservation<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"reservation".as_ref(),
+            margin_account.key().as_ref(),
+            reservation.caller_account.as_ref(),
+        ],
+        bump = reservation.bump[0],
+        has_one = margin_account,
+        has_one = rent_payer,
+    )]
+    pub reservation: Box<Account<'info, Reservation>>,
+
+    /// Either the margin account owner or the reservation's caller account.
+    #[account(
+        constraint = authority.key() == margin_account.owner
+            || authority.key() == reservation.caller_account
+            @ ErrorCode::BadReservationAuthority,
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: has_one on reservation, refunded the rent once the reservation is empty
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+/// Releases up to `lamports`, `WITHDRAW_ALL` releases the whole reservation.
+pub fn process_release_reservation(ctx: Context<ReleaseReservation>, lamports: u64) -> Result<()> {
+    let reservation = &mut ctx.accounts.reservation;
+    let margin = &mut ctx.accounts.margin_account;
+
+    let released = reservation.amount.min(lamports);
+    reservation.amount -= released;
+    margin.reserved_lamports = margin.reserved_lamports.saturating_sub(released);
+
+    emit_event(
+        &ReservationEvent {
+            margin: margin.key(),
+            caller_account: reservation.caller_account,
+            amount: reservation.amount,
+            reserved_lamports: margin.reserved_lamports,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    if ctx.accounts.reservation.amount == 0 {
+        ctx.accounts
+            .reservation
+            .close(ctx.accounts.rent_payer.to_account_info())?;
+    }
+
+    Ok(())
+}
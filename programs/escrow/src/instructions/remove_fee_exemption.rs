@@ -0,0 +1,43 @@
+//! Program owner removing a fee exemption, the rent goes back to the program owner.
+
+use anchor_lang::prelude::*;
+
+use crate::{emit_event, error::ErrorCode, FeeExemption, FeeExemptionEvent, TSwap};
+
+#[derive(Accounts)]
+pub struct RemoveFeeExemption<'info> {
+    #[account(seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"fee_exemption".as_ref(), fee_exemption.owner.as_ref()],
+        bump = fee_exemption.bump[0],
+    )]
+    pub fee_exemption: Box<Account<'info, FeeExemption>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_remove_fee_exemption(ctx: Context<RemoveFeeExemption>) -> Result<()> {
+    emit_event(
+        &FeeExemptionEvent {
+            owner: ctx.accounts.fee_exemption.owner,
+            exempt: false,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,90 @@
+//! Owner requesting a withdrawal from a margin account with a withdrawal delay.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::{throw_err, unwrap_int};
+
+use crate::{
+    assert_available_lamports, constants::CURRENT_WITHDRAWAL_REQUEST_VERSION, emit_event,
+    error::ErrorCode, is_margin_account, MarginAccountV2, WithdrawalRequest,
+    WithdrawalRequestEvent, WITHDRAWAL_REQUEST_SIZE,
+};
+
+#[derive(Accounts)]
+#[instruction(request_nr: u16)]
+pub struct RequestWithdrawal<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            b"withdrawal".as_ref(),
+            margin_account.key().as_ref(),
+            &request_nr.to_le_bytes(),
+        ],
+        bump,
+        space = WITHDRAWAL_REQUEST_SIZE,
+    )]
+    pub withdrawal_request: Box<Account<'info, WithdrawalRequest>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> RequestWithdrawal<'info> {
+    fn validate_request(&self, lamports: u64) -> Result<()> {
+        if !self.margin_account.is_timelocked() {
+            throw_err!(ErrorCode::NoWithdrawalDelay);
+        }
+
+        assert_available_lamports(&self.margin_account.to_account_info(), lamports)
+    }
+}
+
+#[access_control(ctx.accounts.validate_request(lamports))]
+pub fn process_request_withdrawal(
+    ctx: Context<RequestWithdrawal>,
+    request_nr: u16,
+    lamports: u64,
+) -> Result<()> {
+    let margin = &mut ctx.accounts.margin_account;
+    let request = &mut ctx.accounts.withdrawal_request;
+    let now = Clock::get()?.unix_timestamp;
+
+    margin.pending_withdrawals = unwrap_int!(margin.pending_withdrawals.checked_add(lamports));
+
+    request.version = CURRENT_WITHDRAWAL_REQUEST_VERSION;
+    request.bump = [ctx.bumps.withdrawal_request];
+    request.margin_account = margin.key();
+    request.request_nr = request_nr;
+    request.amount = lamports;
+    request.unlock_at = unwrap_int!(now.checked_add(i64::from(margin.withdrawal_delay)));
+
+    emit_event(
+        &WithdrawalRequestEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            request_nr,
+            amount: lamports,
+            unlock_at: request.unlock_at,
+            cancelled: false,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,77 @@
+//! Owner earmarking lamports of their margin account for one pool, bid or consumer signer.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    assert_available_lamports, emit_event, error::ErrorCode, is_margin_account, reserve_lamports,
+    MarginAccountV2, Reservation, ReservationEvent, RESERVATION_SIZE,
+};
+
+#[derive(Accounts)]
+#[instruction(caller_account: Pubkey)]
+pub struct ReserveMarginLamports<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [b"reservation".as_ref(), margin_account.key().as_ref(), caller_account.as_ref()],
+        bump,
+        space = RESERVATION_SIZE,
+    )]
+    pub reservation: Box<Account<'info, Reservation>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> ReserveMarginLamports<'info> {
+    fn validate_reserve(&self, lamports: u64) -> Result<()> {
+        assert_available_lamports(&self.margin_account.to_account_info(), lamports)
+    }
+}
+
+/// `caller_account` is the TAMM pool, TCOMP bid state or cpi consumer signer that will withdraw.
+#[access_control(ctx.accounts.validate_reserve(lamports))]
+pub fn process_reserve_margin_lamports(
+    ctx: Context<ReserveMarginLamports>,
+    caller_account: Pubkey,
+    lamports: u64,
+) -> Result<()> {
+    let margin_key = ctx.accounts.margin_account.key();
+    reserve_lamports(
+        &mut ctx.accounts.margin_account,
+        margin_key,
+        &mut ctx.accounts.reservation,
+        ctx.bumps.reservation,
+        caller_account,
+        ctx.accounts.owner.key(),
+        lamports,
+    )?;
+
+    emit_event(
+        &ReservationEvent {
+            margin: margin_key,
+            caller_account,
+            amount: ctx.accounts.reservation.amount,
+            reserved_lamports: ctx.accounts.margin_account.reserved_lamports,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
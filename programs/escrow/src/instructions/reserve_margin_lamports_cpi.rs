@@ -0,0 +1,121 @@
+//! Registered cpi consumer earmarking lamports of a margin account for its own signer,
+//! eg when the owner places an order through it.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    assert_available_lamports, assert_consumer_signer, assert_cpi_allowed,
+    assert_margin_generation, constants::DENY_CONSUMER_CPI, emit_event, error::ErrorCode,
+    is_margin_account, reserve_lamports, set_cpi_return_data, ConsumerApproval, CpiConsumer,
+    MarginAccountV2, Reservation, ReservationEvent, RESERVATION_SIZE,
+};
+
+#[derive(Accounts)]
+#[instruction(bump: u8, id: [u8; 32])]
+pub struct ReserveMarginLamportsCpi<'info> {
+    #[account(
+        seeds = [b"cpi_consumer".as_ref(), cpi_consumer.program_id.as_ref()],
+        bump = cpi_consumer.bump[0],
+    )]
+    pub cpi_consumer: Box<Account<'info, CpiConsumer>>,
+
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    /// Granted by the owner through `approve_consumer`.
+    #[account(
+        seeds = [
+            b"consumer_approval".as_ref(),
+            margin_account.key().as_ref(),
+            cpi_consumer.program_id.as_ref(),
+        ],
+        bump = consumer_approval.bump[0],
+        has_one = owner,
+    )]
+    pub consumer_approval: Box<Account<'info, ConsumerApproval>>,
+
+    /// The consumer's PDA, derived from `[seed_prefix, owner, id]`: only the consumer
+    /// program can sign for it.
+    pub signer: Signer<'info>,
+
+    /// CHECK: has_one on margin_account, seeds in signer
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"reservation".as_ref(), margin_account.key().as_ref(), signer.key().as_ref()],
+        bump,
+        space = RESERVATION_SIZE,
+    )]
+    pub reservation: Box<Account<'info, Reservation>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> ReserveMarginLamportsCpi<'info> {
+    fn validate_reserve(&self, bump: u8, id: &[u8; 32], lamports: u64) -> Result<()> {
+        assert_cpi_allowed(&self.margin_account.to_account_info(), DENY_CONSUMER_CPI)?;
+        assert_margin_generation(
+            &self.margin_account.to_account_info(),
+            self.consumer_approval.margin_generation,
+        )?;
+        assert_consumer_signer(
+            &self.cpi_consumer,
+            &self.signer.to_account_info(),
+            self.owner.key,
+            bump,
+            id,
+        )?;
+
+        assert_available_lamports(&self.margin_account.to_account_info(), lamports)
+    }
+}
+
+#[access_control(ctx.accounts.validate_reserve(bump, &id, lamports))]
+pub fn process_reserve_margin_lamports_cpi(
+    ctx: Context<ReserveMarginLamportsCpi>,
+    bump: u8,
+    id: [u8; 32],
+    lamports: u64,
+) -> Result<()> {
+    let margin_key = ctx.accounts.margin_account.key();
+    let caller_account = ctx.accounts.signer.key();
+    reserve_lamports(
+        &mut ctx.accounts.margin_account,
+        margin_key,
+        &mut ctx.accounts.reservation,
+        ctx.bumps.reservation,
+        caller_account,
+        ctx.accounts.payer.key(),
+        lamports,
+    )?;
+
+    emit_event(
+        &ReservationEvent {
+            margin: margin_key,
+            caller_account,
+            amount: ctx.accounts.reservation.amount,
+            reserved_lamports: ctx.accounts.margin_account.reserved_lamports,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    set_cpi_return_data(&ctx.accounts.margin_account.to_account_info(), 0)
+}
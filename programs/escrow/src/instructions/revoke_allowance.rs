@@ -0,0 +1,56 @@
+//! Owner revoking a delegate's allowance, the rent goes back to the owner.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    emit_event, error::ErrorCode, is_margin_account, Allowance, AllowanceEvent, MarginAccount,
+};
+
+#[derive(Accounts)]
+pub struct RevokeAllowance<'info> {
+    #[account(
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"allowance".as_ref(),
+            margin_account.key().as_ref(),
+            allowance.delegate.as_ref(),
+        ],
+        bump = allowance.bump[0],
+        has_one = margin_account,
+        close = owner,
+    )]
+    pub allowance: Box<Account<'info, Allowance>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_revoke_allowance(ctx: Context<RevokeAllowance>) -> Result<()> {
+    emit_event(
+        &AllowanceEvent {
+            margin: ctx.accounts.margin_account.key(),
+            owner: ctx.accounts.owner.key(),
+            delegate: ctx.accounts.allowance.delegate,
+            amount: 0,
+            expires_at: None,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,57 @@
+//! Owner revoking a cpi consumer's access to a margin account, the rent goes back to the owner.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    emit_event, error::ErrorCode, is_margin_account, ConsumerApproval, ConsumerApprovalEvent,
+    MarginAccount,
+};
+
+#[derive(Accounts)]
+pub struct RevokeConsumer<'info> {
+    #[account(
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    // Works after the consumer is closed, so no cpi_consumer account here.
+    #[account(
+        mut,
+        seeds = [
+            b"consumer_approval".as_ref(),
+            margin_account.key().as_ref(),
+            consumer_approval.consumer_program.as_ref(),
+        ],
+        bump = consumer_approval.bump[0],
+        has_one = margin_account,
+        close = owner,
+    )]
+    pub consumer_approval: Box<Account<'info, ConsumerApproval>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_revoke_consumer(ctx: Context<RevokeConsumer>) -> Result<()> {
+    emit_event(
+        &ConsumerApprovalEvent {
+            margin: ctx.accounts.margin_account.key(),
+            owner: ctx.accounts.owner.key(),
+            consumer_program: ctx.accounts.consumer_approval.consumer_program,
+            approved: false,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
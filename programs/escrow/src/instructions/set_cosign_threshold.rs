@@ -0,0 +1,40 @@
+//! Program owner setting the global threshold above which owner withdrawals
+//! have to be co-signed by the tswap cosigner.
+
+use anchor_lang::prelude::*;
+
+use crate::{emit_event, error::ErrorCode, CosignThresholdEvent, TSwapV2};
+
+#[derive(Accounts)]
+pub struct SetCosignThreshold<'info> {
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwapV2>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+/// `None` turns the global threshold off, per-account thresholds still apply.
+pub fn process_set_cosign_threshold(
+    ctx: Context<SetCosignThreshold>,
+    threshold: Option<u64>,
+) -> Result<()> {
+    ctx.accounts.tswap.cosign_threshold = threshold;
+
+    emit_event(
+        &CosignThresholdEvent {
+            margin: None,
+            owner: ctx.accounts.owner.key(),
+            threshold,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
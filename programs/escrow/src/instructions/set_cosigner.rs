@@ -0,0 +1,44 @@
+//! Program owner rotating the TSwap cosigner.
+
+use anchor_lang::prelude::*;
+
+use crate::{emit_event, error::ErrorCode, load_tswap_v2, TSwap, TSwapUpdateEvent};
+
+#[derive(Accounts)]
+pub struct SetCosigner<'info> {
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    pub owner: Signer<'info>,
+
+    /// We ask also for a signature just to make sure this wallet can actually sign things
+    pub cosigner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_set_cosigner(ctx: Context<SetCosigner>) -> Result<()> {
+    let tswap = &mut ctx.accounts.tswap;
+    tswap.cosigner = ctx.accounts.cosigner.key();
+
+    let pending_owner = load_tswap_v2(&tswap.to_account_info())?
+        .map_or_else(Pubkey::default, |tswap| tswap.pending_owner);
+    emit_event(
+        &TSwapUpdateEvent {
+            tswap: tswap.key(),
+            owner: tswap.owner,
+            fee_vault: tswap.fee_vault,
+            cosigner: tswap.cosigner,
+            fee_bps: tswap.config.fee_bps,
+            pending_owner,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
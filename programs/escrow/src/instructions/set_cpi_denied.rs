@@ -0,0 +1,41 @@
+//! Owner choosing which CPI callers can use their margin account.
+
+use anchor_lang::prelude::*;
+
+use crate::{emit_event, error::ErrorCode, is_margin_account, CpiDeniedEvent, MarginAccountV2};
+
+#[derive(Accounts)]
+pub struct SetCpiDenied<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+/// Already attached pools and bids stay attached, but can no longer withdraw once denied.
+pub fn process_set_cpi_denied(ctx: Context<SetCpiDenied>, cpi_denied: u8) -> Result<()> {
+    let margin = &mut ctx.accounts.margin_account;
+    margin.cpi_denied = cpi_denied;
+
+    emit_event(
+        &CpiDeniedEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            cpi_denied,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
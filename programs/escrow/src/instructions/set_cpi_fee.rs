@@ -0,0 +1,74 @@
+//! Program owner overriding the fee a CPI caller pays on withdrawals.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::{throw_err, unwrap_opt};
+
+use crate::{
+    assert_fee_bps, emit_event, error::ErrorCode, Caller, CpiConsumer, FeeConfigEvent, TSwapV2,
+};
+
+#[derive(Accounts)]
+pub struct SetCpiFee<'info> {
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwapV2>>,
+
+    pub owner: Signer<'info>,
+
+    /// Required to set the fee of a registered cpi consumer.
+    #[account(
+        mut,
+        seeds = [b"cpi_consumer".as_ref(), cpi_consumer.program_id.as_ref()],
+        bump = cpi_consumer.bump[0],
+    )]
+    pub cpi_consumer: Option<Box<Account<'info, CpiConsumer>>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> SetCpiFee<'info> {
+    fn validate_fee(&self, fee_bps: Option<u16>) -> Result<()> {
+        fee_bps.map_or(Ok(()), assert_fee_bps)
+    }
+}
+
+/// `None` makes the caller pay `TSwap::config.fee_bps`.
+#[access_control(ctx.accounts.validate_fee(fee_bps))]
+pub fn process_set_cpi_fee(
+    ctx: Context<SetCpiFee>,
+    caller: Caller,
+    fee_bps: Option<u16>,
+) -> Result<()> {
+    let caller_program = match caller {
+        Caller::TAmm => {
+            ctx.accounts.tswap.tamm_fee_bps = fee_bps;
+            None
+        }
+        Caller::Tcomp => {
+            ctx.accounts.tswap.tcomp_fee_bps = fee_bps;
+            None
+        }
+        Caller::CpiConsumer => {
+            let consumer = unwrap_opt!(ctx.accounts.cpi_consumer.as_mut(), ErrorCode::BadCpiCaller);
+            consumer.fee_bps = fee_bps;
+            Some(consumer.program_id)
+        }
+        Caller::Owner | Caller::Delegate => throw_err!(ErrorCode::BadCpiCaller),
+    };
+
+    emit_event(
+        &FeeConfigEvent {
+            fees_enabled: ctx.accounts.tswap.fees_enabled,
+            caller: Some(caller),
+            caller_program,
+            fee_bps,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,54 @@
+//! Program owner pointing TSwap fees at a new vault.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::Validate;
+
+use crate::{
+    assert_fee_vault, emit_event, error::ErrorCode, load_tswap_v2, TSwap, TSwapUpdateEvent,
+};
+
+#[derive(Accounts)]
+pub struct SetFeeVault<'info> {
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: assert_fee_vault in validate
+    pub fee_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for SetFeeVault<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_fee_vault(&self.fee_vault)
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn process_set_fee_vault(ctx: Context<SetFeeVault>) -> Result<()> {
+    let tswap = &mut ctx.accounts.tswap;
+    tswap.fee_vault = ctx.accounts.fee_vault.key();
+
+    let pending_owner = load_tswap_v2(&tswap.to_account_info())?
+        .map_or_else(Pubkey::default, |tswap| tswap.pending_owner);
+    emit_event(
+        &TSwapUpdateEvent {
+            tswap: tswap.key(),
+            owner: tswap.owner,
+            fee_vault: tswap.fee_vault,
+            cosigner: tswap.cosigner,
+            fee_bps: tswap.config.fee_bps,
+            pending_owner,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
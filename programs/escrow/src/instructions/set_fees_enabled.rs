@@ -0,0 +1,37 @@
+//! Program owner turning the CPI withdrawal fee on or off.
+
+use anchor_lang::prelude::*;
+
+use crate::{emit_event, error::ErrorCode, FeeConfigEvent, TSwapV2};
+
+#[derive(Accounts)]
+pub struct SetFeesEnabled<'info> {
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwapV2>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_set_fees_enabled(ctx: Context<SetFeesEnabled>, fees_enabled: bool) -> Result<()> {
+    let tswap = &mut ctx.accounts.tswap;
+    tswap.fees_enabled = fees_enabled;
+
+    emit_event(
+        &FeeConfigEvent {
+            fees_enabled,
+            caller: None,
+            caller_program: None,
+            fee_bps: Some(tswap.config.fee_bps),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
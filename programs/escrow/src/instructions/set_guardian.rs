@@ -0,0 +1,40 @@
+//! Program owner designating the guardian that can pause the program.
+
+use anchor_lang::prelude::*;
+
+use crate::{emit_event, error::ErrorCode, PauseEvent, TSwapV2};
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwapV2>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: any key, the default key removes the guardian
+    pub guardian: UncheckedAccount<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_set_guardian(ctx: Context<SetGuardian>) -> Result<()> {
+    let tswap = &mut ctx.accounts.tswap;
+    tswap.guardian = ctx.accounts.guardian.key();
+
+    emit_event(
+        &PauseEvent {
+            tswap: tswap.key(),
+            authority: tswap.owner,
+            guardian: tswap.guardian,
+            paused: tswap.paused,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,35 @@
+//! Program owner setting how many slots TAMM and TCOMP holds live for.
+
+use anchor_lang::prelude::*;
+
+use crate::{emit_event, error::ErrorCode, HoldExpiryEvent, TSwapV2};
+
+#[derive(Accounts)]
+pub struct SetHoldExpiry<'info> {
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwapV2>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+/// 0 falls back to `DEFAULT_HOLD_EXPIRY_SLOTS`, only holds placed afterwards are affected.
+pub fn process_set_hold_expiry(ctx: Context<SetHoldExpiry>, hold_expiry_slots: u64) -> Result<()> {
+    ctx.accounts.tswap.hold_expiry_slots = hold_expiry_slots;
+
+    emit_event(
+        &HoldExpiryEvent {
+            owner: ctx.accounts.owner.key(),
+            hold_expiry_slots,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,71 @@
+//! Owner requiring the tswap cosigner on large withdrawals from their margin account.
+//!
+//! Lowering (or setting) the threshold applies right away. Raising or removing it
+//! has to be co-signed, so a stolen key can't simply turn it off.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::throw_err;
+
+use crate::{
+    emit_event, error::ErrorCode, is_margin_account, CosignThresholdEvent, MarginAccountV2, TSwap,
+};
+
+#[derive(Accounts)]
+pub struct SetMarginCosignThreshold<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// Required to raise or remove the threshold.
+    #[account(address = tswap.cosigner @ ErrorCode::BadCosigner)]
+    pub cosigner: Option<Signer<'info>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> SetMarginCosignThreshold<'info> {
+    fn validate_threshold(&self, threshold: Option<u64>) -> Result<()> {
+        let Some(current) = self.margin_account.cosign_threshold else {
+            return Ok(());
+        };
+        let loosened = !matches!(threshold, Some(threshold) if threshold <= current);
+        if loosened && self.cosigner.is_none() {
+            throw_err!(ErrorCode::CosignRequired);
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate_threshold(threshold))]
+pub fn process_set_margin_cosign_threshold(
+    ctx: Context<SetMarginCosignThreshold>,
+    threshold: Option<u64>,
+) -> Result<()> {
+    let margin = &mut ctx.accounts.margin_account;
+    margin.cosign_threshold = threshold;
+
+    emit_event(
+        &CosignThresholdEvent {
+            margin: Some(margin.key()),
+            owner: margin.owner,
+            threshold,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,58 @@
+//! Owner or guardian pausing (parts of) the program, eg if TAMM or TCOMP is compromised.
+//!
+//! The guardian can only add `PAUSE_*` flags, lifting a pause takes the owner.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::throw_err;
+
+use crate::{emit_event, error::ErrorCode, PauseEvent, TSwapV2};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [],
+        bump = tswap.bump[0],
+        constraint = authority.key() == tswap.owner
+            || authority.key() == tswap.guardian @ ErrorCode::BadOwner,
+    )]
+    pub tswap: Box<Account<'info, TSwapV2>>,
+
+    /// Either the owner or the guardian.
+    pub authority: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> SetPaused<'info> {
+    fn validate_paused(&self, paused: u8) -> Result<()> {
+        let unpausing = self.tswap.paused & !paused != 0;
+        if unpausing && self.authority.key() != self.tswap.owner {
+            throw_err!(ErrorCode::OnlyOwnerCanUnpause);
+        }
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate_paused(paused))]
+pub fn process_set_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+    let tswap = &mut ctx.accounts.tswap;
+    tswap.paused = paused;
+
+    emit_event(
+        &PauseEvent {
+            tswap: tswap.key(),
+            authority: ctx.accounts.authority.key(),
+            guardian: tswap.guardian,
+            paused: tswap.paused,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,77 @@
+//! Program owner capping what a CPI caller can withdraw per rolling window.
+//!
+//! Limits the blast radius of a bug in TAMM, TCOMP or a registered consumer: a
+//! limit of 0 lifts the cap. The TAMM and TCOMP limits live on TSwap, which has
+//! to be migrated to V2 first.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::{throw_err, unwrap_opt};
+
+use crate::{
+    emit_event, error::ErrorCode, load_tswap_v2, Caller, CpiConsumer, RateLimitEvent, TSwap,
+};
+
+#[derive(Accounts)]
+pub struct SetRateLimit<'info> {
+    /// Has to be migrated to V2 to limit TAMM or TCOMP.
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    pub owner: Signer<'info>,
+
+    /// Required to limit a registered cpi consumer.
+    #[account(
+        mut,
+        seeds = [b"cpi_consumer".as_ref(), cpi_consumer.program_id.as_ref()],
+        bump = cpi_consumer.bump[0],
+    )]
+    pub cpi_consumer: Option<Box<Account<'info, CpiConsumer>>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_set_rate_limit(
+    ctx: Context<SetRateLimit>,
+    caller: Caller,
+    limit: u64,
+    window: u32,
+) -> Result<()> {
+    let caller_program = match caller {
+        Caller::TAmm | Caller::Tcomp => {
+            let tswap_info = ctx.accounts.tswap.to_account_info();
+            // consume_tswap_rate_limit skips a V1 TSwap, the limit would never apply.
+            let mut tswap = unwrap_opt!(load_tswap_v2(&tswap_info)?, ErrorCode::TSwapNotMigrated);
+            let rate_limit = if caller == Caller::TAmm {
+                &mut tswap.tamm_rate_limit
+            } else {
+                &mut tswap.tcomp_rate_limit
+            };
+            rate_limit.configure(limit, window);
+            tswap.try_serialize(&mut &mut tswap_info.try_borrow_mut_data()?[..])?;
+            None
+        }
+        Caller::CpiConsumer => {
+            let consumer = unwrap_opt!(ctx.accounts.cpi_consumer.as_mut(), ErrorCode::BadCpiCaller);
+            consumer.rate_limit.configure(limit, window);
+            Some(consumer.program_id)
+        }
+        Caller::Owner | Caller::Delegate => throw_err!(ErrorCode::BadCpiCaller),
+    };
+
+    emit_event(
+        &RateLimitEvent {
+            caller,
+            caller_program,
+            limit,
+            window,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
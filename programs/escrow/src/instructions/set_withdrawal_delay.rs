@@ -0,0 +1,70 @@
+//! Owner opting in to (or out of) timelocked withdrawals.
+//!
+//! Raising the delay applies right away. Lowering it is scheduled for after the
+//! current delay and applied by calling this again once it has passed, so a
+//! stolen key can't simply turn the delay off.
+
+use anchor_lang::prelude::*;
+use tensor_vipers::{throw_err, unwrap_int};
+
+use crate::{
+    emit_event, error::ErrorCode, is_margin_account, MarginAccountV2, WithdrawalDelayEvent,
+};
+
+#[derive(Accounts)]
+pub struct SetWithdrawalDelay<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccountV2>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_set_withdrawal_delay(
+    ctx: Context<SetWithdrawalDelay>,
+    withdrawal_delay: u32,
+) -> Result<()> {
+    let margin = &mut ctx.accounts.margin_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    if withdrawal_delay >= margin.withdrawal_delay {
+        margin.withdrawal_delay = withdrawal_delay;
+        margin.pending_withdrawal_delay = 0;
+        margin.withdrawal_delay_unlock_at = 0;
+    } else if margin.withdrawal_delay_unlock_at == 0
+        || margin.pending_withdrawal_delay != withdrawal_delay
+    {
+        margin.pending_withdrawal_delay = withdrawal_delay;
+        margin.withdrawal_delay_unlock_at =
+            unwrap_int!(now.checked_add(i64::from(margin.withdrawal_delay)));
+    } else if now >= margin.withdrawal_delay_unlock_at {
+        margin.withdrawal_delay = withdrawal_delay;
+        margin.pending_withdrawal_delay = 0;
+        margin.withdrawal_delay_unlock_at = 0;
+    } else {
+        throw_err!(ErrorCode::WithdrawalLocked);
+    }
+
+    emit_event(
+        &WithdrawalDelayEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            withdrawal_delay: margin.withdrawal_delay,
+            pending_withdrawal_delay: margin.pending_withdrawal_delay,
+            withdrawal_delay_unlock_at: margin.withdrawal_delay_unlock_at,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
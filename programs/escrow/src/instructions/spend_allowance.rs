@@ -0,0 +1,119 @@
+//! Delegate withdrawing from a margin account within the allowance granted by the owner.
+
+use anchor_lang::prelude::*;
+use tensor_toolbox::transfer_lamports_from_pda;
+use tensor_vipers::{throw_err, unwrap_opt, Validate};
+
+use crate::{
+    assert_available_lamports, assert_cosigned, assert_margin_generation, assert_not_paused,
+    assert_not_timelocked, constants::PAUSE_OWNER_WITHDRAWALS, emit_event, error::ErrorCode,
+    is_margin_account, Allowance, Caller, MarginAccount, TSwap, WithdrawEvent,
+};
+
+#[derive(Accounts)]
+pub struct SpendAllowance<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"allowance".as_ref(),
+            margin_account.key().as_ref(),
+            delegate.key().as_ref(),
+        ],
+        bump = allowance.bump[0],
+        has_one = margin_account,
+        has_one = delegate,
+        has_one = owner,
+    )]
+    pub allowance: Box<Account<'info, Allowance>>,
+
+    pub delegate: Signer<'info>,
+
+    /// CHECK: has_one on margin_account and allowance
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: chosen by the delegate, who can spend the allowance anyway.
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// Required once the amount exceeds the cosign threshold.
+    #[account(address = tswap.cosigner @ ErrorCode::BadCosigner)]
+    pub cosigner: Option<Signer<'info>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for SpendAllowance<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_not_paused(&self.tswap.to_account_info(), PAUSE_OWNER_WITHDRAWALS)?;
+
+        if self.allowance.is_expired(Clock::get()?.unix_timestamp) {
+            throw_err!(ErrorCode::AllowanceExpired);
+        }
+        assert_margin_generation(
+            &self.margin_account.to_account_info(),
+            self.allowance.margin_generation,
+        )?;
+
+        // Delegates spend on behalf of the owner, so they are timelocked too.
+        assert_not_timelocked(&self.margin_account.to_account_info())
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn process_spend_allowance(ctx: Context<SpendAllowance>, lamports: u64) -> Result<()> {
+    assert_available_lamports(&ctx.accounts.margin_account.to_account_info(), lamports)?;
+    assert_cosigned(
+        &ctx.accounts.tswap.to_account_info(),
+        &ctx.accounts.margin_account.to_account_info(),
+        &ctx.accounts.cosigner,
+        lamports,
+    )?;
+
+    let allowance = &mut ctx.accounts.allowance;
+    // Decremented before the transfer: the whole instruction fails if the allowance is short.
+    allowance.amount = unwrap_opt!(
+        allowance.amount.checked_sub(lamports),
+        ErrorCode::AllowanceExceeded
+    );
+
+    transfer_lamports_from_pda(
+        &ctx.accounts.margin_account.to_account_info(),
+        &ctx.accounts.destination.to_account_info(),
+        lamports,
+    )?;
+
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &WithdrawEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            caller: Caller::Delegate,
+            caller_program: None,
+            caller_account: Some(ctx.accounts.delegate.key()),
+            mint: None,
+            amount: lamports,
+            post_balance: margin.to_account_info().lamports(),
+            destination: ctx.accounts.destination.key(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
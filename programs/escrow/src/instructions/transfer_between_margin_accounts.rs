@@ -0,0 +1,73 @@
+//! Owner moving lamports directly between two of their margin accounts.
+
+use anchor_lang::prelude::*;
+use tensor_toolbox::transfer_lamports_from_pda;
+
+use crate::{
+    assert_available_lamports, assert_margin_transfer, emit_event, error::ErrorCode,
+    is_margin_account, MarginAccount, MarginTransferEvent,
+};
+
+#[derive(Accounts)]
+pub struct TransferBetweenMarginAccounts<'info> {
+    #[account(
+        mut,
+        constraint = is_margin_account(&source_margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub source_margin_account: Box<Account<'info, MarginAccount>>,
+
+    #[account(
+        mut,
+        constraint = is_margin_account(&destination_margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        constraint = destination_margin_account.key() != source_margin_account.key() @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub destination_margin_account: Box<Account<'info, MarginAccount>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> TransferBetweenMarginAccounts<'info> {
+    fn validate_transfer(&self, lamports: u64) -> Result<()> {
+        assert_margin_transfer(
+            &self.source_margin_account.to_account_info(),
+            &self.destination_margin_account.to_account_info(),
+            lamports,
+        )
+    }
+}
+
+#[access_control(ctx.accounts.validate_transfer(lamports))]
+pub fn process_transfer_between_margin_accounts(
+    ctx: Context<TransferBetweenMarginAccounts>,
+    lamports: u64,
+) -> Result<()> {
+    let source_info = ctx.accounts.source_margin_account.to_account_info();
+    let destination_info = ctx.accounts.destination_margin_account.to_account_info();
+
+    // Keeps the source rent-exempt and leaves pending withdrawal requests covered.
+    assert_available_lamports(&source_info, lamports)?;
+    transfer_lamports_from_pda(&source_info, &destination_info, lamports)?;
+
+    emit_event(
+        &MarginTransferEvent {
+            owner: ctx.accounts.owner.key(),
+            source: source_info.key(),
+            destination: destination_info.key(),
+            amount: lamports,
+            source_post_balance: source_info.lamports(),
+            destination_post_balance: destination_info.lamports(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
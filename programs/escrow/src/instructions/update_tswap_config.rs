@@ -0,0 +1,54 @@
+//! Program owner updating the TSwap config (eg swap fees).
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    assert_tswap_config, emit_event, error::ErrorCode, load_tswap_v2, TSwap, TSwapConfig,
+    TSwapUpdateEvent,
+};
+
+#[derive(Accounts)]
+pub struct UpdateTSwapConfig<'info> {
+    #[account(mut, seeds = [], bump = tswap.bump[0], has_one = owner @ ErrorCode::BadOwner)]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> UpdateTSwapConfig<'info> {
+    fn validate_config(&self, config: &TSwapConfig) -> Result<()> {
+        assert_tswap_config(config)
+    }
+}
+
+#[access_control(ctx.accounts.validate_config(&config))]
+pub fn process_update_tswap_config(
+    ctx: Context<UpdateTSwapConfig>,
+    config: TSwapConfig,
+) -> Result<()> {
+    let tswap = &mut ctx.accounts.tswap;
+    tswap.config = config;
+
+    let pending_owner = load_tswap_v2(&tswap.to_account_info())?
+        .map_or_else(Pubkey::default, |tswap| tswap.pending_owner);
+    emit_event(
+        &TSwapUpdateEvent {
+            tswap: tswap.key(),
+            owner: tswap.owner,
+            fee_vault: tswap.fee_vault,
+            cosigner: tswap.cosigner,
+            fee_bps: tswap.config.fee_bps,
+            pending_owner,
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,66 @@
+//! Program owner sweeping collected fees out of the fee vault.
+//!
+//! Only works while the fee vault is this program's `[b"fee_vault"]` PDA, a wallet
+//! vault is simply spent by its owner.
+
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{emit_event, error::ErrorCode, TSwap, WithdrawFeesEvent};
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        seeds = [],
+        bump = tswap.bump[0],
+        has_one = owner @ ErrorCode::BadOwner,
+        has_one = fee_vault @ ErrorCode::BadFeeVault,
+    )]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// CHECK: seeds, system-owned (see set_fee_vault)
+    #[account(mut, seeds = [b"fee_vault".as_ref()], bump)]
+    pub fee_vault: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: chosen by the program owner
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_withdraw_fees(ctx: Context<WithdrawFees>, lamports: u64) -> Result<()> {
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.fee_vault.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+            },
+            &[&[b"fee_vault".as_ref(), &[ctx.bumps.fee_vault]]],
+        ),
+        lamports,
+    )?;
+
+    emit_event(
+        &WithdrawFeesEvent {
+            fee_vault: ctx.accounts.fee_vault.key(),
+            amount: lamports,
+            destination: ctx.accounts.destination.key(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -1,7 +1,14 @@
 use anchor_lang::prelude::*;
 use tensor_toolbox::transfer_lamports_from_pda;
 
-use crate::{MarginAccount, TSwap};
+use crate::{
+    assert_available_lamports, assert_cosigned, assert_not_paused, assert_not_timelocked,
+    available_lamports,
+    constants::{PAUSE_OWNER_WITHDRAWALS, WITHDRAW_ALL},
+    emit_event,
+    error::ErrorCode,
+    is_margin_account, Caller, MarginAccount, TSwap, WithdrawEvent,
+};
 
 #[derive(Accounts)]
 pub struct WithdrawMarginAccount<'info> {
@@ -10,13 +17,7 @@ pub struct WithdrawMarginAccount<'info> {
 
     #[account(
         mut,
-        seeds = [
-            b"margin".as_ref(),
-            tswap.key().as_ref(),
-            owner.key().as_ref(),
-            &margin_account.nr.to_le_bytes()
-        ],
-        bump = margin_account.bump[0],
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
         has_one = owner,
     )]
     pub margin_account: Box<Account<'info, MarginAccount>>,
@@ -25,24 +26,86 @@ pub struct WithdrawMarginAccount<'info> {
     pub owner: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Required once the amount exceeds the cosign threshold.
+    #[account(address = tswap.cosigner @ ErrorCode::BadCosigner)]
+    pub cosigner: Option<Signer<'info>>,
+
+    /// CHECK: chosen by the owner, the owner when not passed in.
+    #[account(mut)]
+    pub destination: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> WithdrawMarginAccount<'info> {
-    fn transfer_lamports_to_owner(&self, lamports: u64) -> Result<()> {
+    fn destination(&self) -> AccountInfo<'info> {
+        self.destination.as_ref().map_or_else(
+            || self.owner.to_account_info(),
+            |destination| destination.to_account_info(),
+        )
+    }
+
+    fn transfer_lamports(&self, lamports: u64) -> Result<()> {
         transfer_lamports_from_pda(
             &self.margin_account.to_account_info(),
-            &self.owner.to_account_info(),
+            &self.destination(),
             lamports,
         )
     }
 }
 
+/// `WITHDRAW_ALL` withdraws everything above the rent-exempt minimum.
 pub fn process_withdraw_margin_account(
     ctx: Context<WithdrawMarginAccount>,
     lamports: u64,
 ) -> Result<()> {
+    let lamports = if lamports == WITHDRAW_ALL {
+        available_lamports(&ctx.accounts.margin_account.to_account_info())?
+    } else {
+        lamports
+    };
+
+    assert_not_paused(
+        &ctx.accounts.tswap.to_account_info(),
+        PAUSE_OWNER_WITHDRAWALS,
+    )?;
+
+    assert_not_timelocked(&ctx.accounts.margin_account.to_account_info())?;
+
+    // Reserved lamports stay with the callers they are reserved for.
+    assert_available_lamports(&ctx.accounts.margin_account.to_account_info(), lamports)?;
+
+    assert_cosigned(
+        &ctx.accounts.tswap.to_account_info(),
+        &ctx.accounts.margin_account.to_account_info(),
+        &ctx.accounts.cosigner,
+        lamports,
+    )?;
+
     // do the transfer
-    ctx.accounts.transfer_lamports_to_owner(lamports)?;
+    ctx.accounts.transfer_lamports(lamports)?;
 
-    Ok(())
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &WithdrawEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            caller: Caller::Owner,
+            caller_program: None,
+            caller_account: None,
+            mint: None,
+            amount: lamports,
+            post_balance: margin.to_account_info().lamports(),
+            destination: ctx.accounts.destination().key(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
 }
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use tensor_toolbox::transfer_lamports_from_pda;
+use tensor_vipers::unwrap_int;
+
+use crate::{
+    assert_consumer_signer, assert_cpi_allowed, assert_margin_generation, assert_not_paused,
+    constants::{DENY_CONSUMER_CPI, PAUSE_CONSUMER_CPI},
+    consume_rate_limit, cpi_fee, draw_available_lamports, emit_event,
+    error::ErrorCode,
+    is_margin_account, pay_cpi_fee, set_cpi_return_data, withdrawable_lamports, Caller,
+    ConsumerApproval, CpiConsumer, FeeEvent, FeeExemption, MarginAccount, Reservation, TSwap,
+    WithdrawEvent, WithdrawMode,
+};
+
+/// Generic version of the TAMM/TCOMP CPI withdrawals for any program in the consumer registry.
+#[derive(Accounts)]
+#[instruction(bump: u8, id: [u8; 32])]
+pub struct WithdrawMarginAccountCpi<'info> {
+    // mut for the rate limit accounting
+    #[account(
+        mut,
+        seeds = [b"cpi_consumer".as_ref(), cpi_consumer.program_id.as_ref()],
+        bump = cpi_consumer.bump[0],
+    )]
+    pub cpi_consumer: Box<Account<'info, CpiConsumer>>,
+
+    // Both margin account families can be withdrawn from.
+    #[account(
+        mut,
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    /// Granted by the owner through `approve_consumer`.
+    #[account(
+        seeds = [
+            b"consumer_approval".as_ref(),
+            margin_account.key().as_ref(),
+            cpi_consumer.program_id.as_ref(),
+        ],
+        bump = consumer_approval.bump[0],
+        has_one = owner,
+    )]
+    pub consumer_approval: Box<Account<'info, ConsumerApproval>>,
+
+    /// The consumer's PDA, derived from `[seed_prefix, owner, id]`: only the consumer
+    /// program can sign for it.
+    pub signer: Signer<'info>,
+
+    /// CHECK: has_one on margin_account, seeds in signer
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: can only be passed in by the consumer, since it has to sign off with its PDA.
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// CHECK: address of tswap.fee_vault, only required once a fee is due
+    #[account(mut, address = tswap.fee_vault @ ErrorCode::BadFeeVault)]
+    pub fee_vault: Option<UncheckedAccount<'info>>,
+
+    /// Exempts the owner from the withdrawal fee.
+    #[account(
+        seeds = [b"fee_exemption".as_ref(), owner.key().as_ref()],
+        bump = fee_exemption.bump[0],
+    )]
+    pub fee_exemption: Option<Box<Account<'info, FeeExemption>>>,
+
+    /// Lamports earmarked for the signer, drawn from first.
+    #[account(
+        mut,
+        seeds = [b"reservation".as_ref(), margin_account.key().as_ref(), signer.key().as_ref()],
+        bump = reservation.bump[0],
+    )]
+    pub reservation: Option<Box<Account<'info, Reservation>>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> WithdrawMarginAccountCpi<'info> {
+    fn validate_signer(&self, bump: u8, id: &[u8; 32]) -> Result<()> {
+        assert_not_paused(&self.tswap.to_account_info(), PAUSE_CONSUMER_CPI)?;
+        assert_cpi_allowed(&self.margin_account.to_account_info(), DENY_CONSUMER_CPI)?;
+        assert_margin_generation(
+            &self.margin_account.to_account_info(),
+            self.consumer_approval.margin_generation,
+        )?;
+
+        assert_consumer_signer(
+            &self.cpi_consumer,
+            &self.signer.to_account_info(),
+            self.owner.key,
+            bump,
+            id,
+        )
+    }
+}
+
+#[access_control(ctx.accounts.validate_signer(bump, &id))]
+pub fn process_withdraw_margin_account_cpi(
+    ctx: Context<WithdrawMarginAccountCpi>,
+    bump: u8,
+    id: [u8; 32],
+    lamports: u64,
+    mode: WithdrawMode,
+) -> Result<()> {
+    let fee_of = |lamports: u64| {
+        cpi_fee(
+            &ctx.accounts.tswap.to_account_info(),
+            Caller::CpiConsumer,
+            ctx.accounts.cpi_consumer.fee_bps,
+            ctx.accounts.fee_exemption.is_some(),
+            lamports,
+        )
+    };
+    let lamports = withdrawable_lamports(
+        &ctx.accounts.margin_account.to_account_info(),
+        ctx.accounts
+            .reservation
+            .as_deref()
+            .map(|reservation| &**reservation),
+        mode,
+        lamports,
+        fee_of,
+    )?;
+    let fee = fee_of(lamports)?;
+    draw_available_lamports(
+        &ctx.accounts.margin_account.to_account_info(),
+        ctx.accounts
+            .reservation
+            .as_deref_mut()
+            .map(|reservation| &mut **reservation),
+        unwrap_int!(lamports.checked_add(fee)),
+    )?;
+    let consumer_program = ctx.accounts.cpi_consumer.program_id;
+    consume_rate_limit(
+        &mut ctx.accounts.cpi_consumer.rate_limit,
+        ctx.accounts.margin_account.key(),
+        Caller::CpiConsumer,
+        Some(consumer_program),
+        lamports,
+    )?;
+
+    transfer_lamports_from_pda(
+        &ctx.accounts.margin_account.to_account_info(),
+        &ctx.accounts.destination.to_account_info(),
+        lamports,
+    )?;
+
+    if fee > 0 {
+        pay_cpi_fee(
+            &ctx.accounts.margin_account.to_account_info(),
+            &ctx.accounts.fee_vault,
+            fee,
+        )?;
+        emit_event(
+            &FeeEvent {
+                margin: ctx.accounts.margin_account.key(),
+                owner: ctx.accounts.margin_account.owner,
+                caller: Caller::CpiConsumer,
+                caller_program: Some(consumer_program),
+                amount: fee,
+                fee_vault: ctx.accounts.tswap.fee_vault,
+            },
+            &ctx.accounts.event_authority,
+            &ctx.accounts.program,
+            ctx.bumps.event_authority,
+        )?;
+    }
+
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &WithdrawEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            caller: Caller::CpiConsumer,
+            caller_program: Some(ctx.accounts.cpi_consumer.program_id),
+            caller_account: Some(ctx.accounts.signer.key()),
+            mint: None,
+            amount: lamports,
+            post_balance: margin.to_account_info().lamports(),
+            destination: ctx.accounts.destination.key(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    set_cpi_return_data(&ctx.accounts.margin_account.to_account_info(), lamports)
+}
@@ -1,23 +1,27 @@
-use crate::{get_tswap_addr, MarginAccount};
+use crate::{
+    assert_cpi_allowed, assert_not_paused,
+    constants::{DENY_TAMM_CPI, PAUSE_TAMM_CPI},
+    consume_tswap_rate_limit, cpi_fee, draw_available_lamports, emit_event,
+    error::ErrorCode,
+    is_margin_account, pay_cpi_fee, set_cpi_return_data, withdrawable_lamports, Caller, FeeEvent,
+    FeeExemption, MarginAccount, Reservation, TSwap, WithdrawEvent, WithdrawMode,
+};
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 use tensor_toolbox::transfer_lamports_from_pda;
-use tensor_vipers::Validate;
+use tensor_vipers::{unwrap_int, Validate};
 
-use super::{assert_discriminator, constants::TAMM_POOL_DISCRIMINATOR};
+use super::{
+    assert_discriminator, assert_pool_currency, assert_pool_margin,
+    constants::TAMM_POOL_DISCRIMINATOR,
+};
 
 #[derive(Accounts)]
 #[instruction(bump: u8, pool_id: [u8; 32])]
 pub struct WithdrawMarginAccountCpiTAmm<'info> {
     #[account(
         mut,
-        seeds = [
-            b"margin".as_ref(),
-            get_tswap_addr().as_ref(),
-            owner.key().as_ref(),
-            &margin_account.nr.to_le_bytes()
-        ],
-        bump = margin_account.bump[0],
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
         has_one = owner,
     )]
     pub margin_account: Box<Account<'info, MarginAccount>>,
@@ -39,11 +43,46 @@ pub struct WithdrawMarginAccountCpiTAmm<'info> {
     pub destination: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    // mut for the rate limit accounting
+    #[account(mut, seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// CHECK: address of tswap.fee_vault, only required once a fee is due
+    #[account(mut, address = tswap.fee_vault @ ErrorCode::BadFeeVault)]
+    pub fee_vault: Option<UncheckedAccount<'info>>,
+
+    /// Exempts the owner from the withdrawal fee.
+    #[account(
+        seeds = [b"fee_exemption".as_ref(), owner.key().as_ref()],
+        bump = fee_exemption.bump[0],
+    )]
+    pub fee_exemption: Option<Box<Account<'info, FeeExemption>>>,
+
+    /// Lamports earmarked for the pool, drawn from first.
+    #[account(
+        mut,
+        seeds = [b"reservation".as_ref(), margin_account.key().as_ref(), pool.key().as_ref()],
+        bump = reservation.bump[0],
+    )]
+    pub reservation: Option<Box<Account<'info, Reservation>>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> Validate<'info> for WithdrawMarginAccountCpiTAmm<'info> {
     fn validate(&self) -> Result<()> {
+        assert_not_paused(&self.tswap.to_account_info(), PAUSE_TAMM_CPI)?;
+        assert_cpi_allowed(&self.margin_account.to_account_info(), DENY_TAMM_CPI)?;
+
         assert_discriminator(&self.pool.to_account_info(), &TAMM_POOL_DISCRIMINATOR)?;
+        assert_pool_margin(&self.pool.to_account_info(), &self.margin_account.key())?;
+        assert_pool_currency(&self.pool.to_account_info(), &Pubkey::default())?;
 
         Ok(())
     }
@@ -53,10 +92,87 @@ impl<'info> Validate<'info> for WithdrawMarginAccountCpiTAmm<'info> {
 pub fn process_withdraw_margin_account_from_tamm(
     ctx: Context<WithdrawMarginAccountCpiTAmm>,
     lamports: u64,
+    mode: WithdrawMode,
 ) -> Result<()> {
+    let fee_of = |lamports: u64| {
+        cpi_fee(
+            &ctx.accounts.tswap.to_account_info(),
+            Caller::TAmm,
+            None,
+            ctx.accounts.fee_exemption.is_some(),
+            lamports,
+        )
+    };
+    let lamports = withdrawable_lamports(
+        &ctx.accounts.margin_account.to_account_info(),
+        ctx.accounts
+            .reservation
+            .as_deref()
+            .map(|reservation| &**reservation),
+        mode,
+        lamports,
+        fee_of,
+    )?;
+    let fee = fee_of(lamports)?;
+    draw_available_lamports(
+        &ctx.accounts.margin_account.to_account_info(),
+        ctx.accounts
+            .reservation
+            .as_deref_mut()
+            .map(|reservation| &mut **reservation),
+        unwrap_int!(lamports.checked_add(fee)),
+    )?;
+    consume_tswap_rate_limit(
+        &ctx.accounts.tswap.to_account_info(),
+        ctx.accounts.margin_account.key(),
+        Caller::TAmm,
+        lamports,
+    )?;
+
     transfer_lamports_from_pda(
         &ctx.accounts.margin_account.to_account_info(),
         &ctx.accounts.destination.to_account_info(),
         lamports,
-    )
+    )?;
+
+    if fee > 0 {
+        pay_cpi_fee(
+            &ctx.accounts.margin_account.to_account_info(),
+            &ctx.accounts.fee_vault,
+            fee,
+        )?;
+        emit_event(
+            &FeeEvent {
+                margin: ctx.accounts.margin_account.key(),
+                owner: ctx.accounts.margin_account.owner,
+                caller: Caller::TAmm,
+                caller_program: None,
+                amount: fee,
+                fee_vault: ctx.accounts.tswap.fee_vault,
+            },
+            &ctx.accounts.event_authority,
+            &ctx.accounts.program,
+            ctx.bumps.event_authority,
+        )?;
+    }
+
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &WithdrawEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            caller: Caller::TAmm,
+            caller_program: None,
+            caller_account: Some(ctx.accounts.pool.key()),
+            mint: None,
+            amount: lamports,
+            post_balance: margin.to_account_info().lamports(),
+            destination: ctx.accounts.destination.key(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    set_cpi_return_data(&ctx.accounts.margin_account.to_account_info(), lamports)
 }
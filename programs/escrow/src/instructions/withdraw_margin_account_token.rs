@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    assert_not_paused, assert_not_timelocked,
+    constants::{PAUSE_OWNER_WITHDRAWALS, WITHDRAW_ALL},
+    emit_event,
+    error::ErrorCode,
+    is_margin_account, transfer_tokens_from_margin, Caller, MarginAccount, TSwap, WithdrawEvent,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawMarginAccountToken<'info> {
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    #[account(
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = margin_account,
+        associated_token::token_program = token_program,
+    )]
+    pub margin_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+/// `WITHDRAW_ALL` withdraws the whole token vault.
+pub fn process_withdraw_margin_account_token(
+    ctx: Context<WithdrawMarginAccountToken>,
+    amount: u64,
+) -> Result<()> {
+    let amount = if amount == WITHDRAW_ALL {
+        ctx.accounts.margin_ta.amount
+    } else {
+        amount
+    };
+
+    assert_not_paused(
+        &ctx.accounts.tswap.to_account_info(),
+        PAUSE_OWNER_WITHDRAWALS,
+    )?;
+    // Withdrawal requests only queue lamports, so token withdrawals are blocked while a
+    // delay is set.
+    assert_not_timelocked(&ctx.accounts.margin_account.to_account_info())?;
+
+    transfer_tokens_from_margin(
+        &ctx.accounts.margin_account,
+        &ctx.accounts.tswap.key(),
+        &ctx.accounts.margin_ta,
+        &ctx.accounts.owner_ta,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+    ctx.accounts.margin_ta.reload()?;
+
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &WithdrawEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            caller: Caller::Owner,
+            caller_program: None,
+            caller_account: None,
+            mint: Some(ctx.accounts.mint.key()),
+            amount,
+            post_balance: ctx.accounts.margin_ta.amount,
+            destination: ctx.accounts.owner_ta.key(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )
+}
@@ -0,0 +1,120 @@
+use crate::{
+    assert_cpi_allowed, assert_not_paused,
+    constants::{DENY_TAMM_CPI, PAUSE_TAMM_CPI},
+    emit_event,
+    error::ErrorCode,
+    get_tswap_addr, is_margin_account, set_token_cpi_return_data, transfer_tokens_from_margin,
+    Caller, MarginAccount, TSwap, WithdrawEvent,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use std::str::FromStr;
+use tensor_vipers::Validate;
+
+use super::{
+    assert_discriminator, assert_pool_currency, assert_pool_margin,
+    constants::TAMM_POOL_DISCRIMINATOR,
+};
+
+#[derive(Accounts)]
+#[instruction(bump: u8, pool_id: [u8; 32])]
+pub struct WithdrawMarginAccountTokenCpiTAmm<'info> {
+    #[account(
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    // Use the pool account as the signing PDA for this instruction.
+    // The seeds check ensures it is a valid Pool account from the TAMM program.
+    #[account(
+        seeds=[b"pool".as_ref(), owner.key().as_ref(), pool_id.as_ref()],
+        seeds::program = Pubkey::from_str("TAMM6ub33ij1mbetoMyVBLeKY5iP41i4UPUJQGkhfsg").unwrap(),
+        bump = bump,
+    )]
+    pub pool: Signer<'info>,
+
+    /// CHECK: has_one on margin_account
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = margin_account,
+        associated_token::token_program = token_program,
+    )]
+    pub margin_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Can only be passed in by TAMM, since it has to sign off with Pool PDA.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for WithdrawMarginAccountTokenCpiTAmm<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_not_paused(&self.tswap.to_account_info(), PAUSE_TAMM_CPI)?;
+        assert_cpi_allowed(&self.margin_account.to_account_info(), DENY_TAMM_CPI)?;
+
+        assert_discriminator(&self.pool.to_account_info(), &TAMM_POOL_DISCRIMINATOR)?;
+        assert_pool_margin(&self.pool.to_account_info(), &self.margin_account.key())?;
+        assert_pool_currency(&self.pool.to_account_info(), &self.mint.key())?;
+
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn process_withdraw_margin_account_token_from_tamm(
+    ctx: Context<WithdrawMarginAccountTokenCpiTAmm>,
+    amount: u64,
+) -> Result<()> {
+    transfer_tokens_from_margin(
+        &ctx.accounts.margin_account,
+        &get_tswap_addr(),
+        &ctx.accounts.margin_ta,
+        &ctx.accounts.destination_ta,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+    ctx.accounts.margin_ta.reload()?;
+
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &WithdrawEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            caller: Caller::TAmm,
+            caller_program: None,
+            caller_account: Some(ctx.accounts.pool.key()),
+            mint: Some(ctx.accounts.mint.key()),
+            amount,
+            post_balance: ctx.accounts.margin_ta.amount,
+            destination: ctx.accounts.destination_ta.key(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    set_token_cpi_return_data(amount, ctx.accounts.margin_ta.amount)
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use std::str::FromStr;
+use tensor_vipers::Validate;
+
+use crate::{
+    assert_cpi_allowed, assert_not_paused,
+    constants::{DENY_TCOMP_CPI, PAUSE_TCOMP_CPI},
+    emit_event,
+    error::ErrorCode,
+    get_tswap_addr, is_margin_account, set_token_cpi_return_data, transfer_tokens_from_margin,
+    Caller, MarginAccount, TSwap, WithdrawEvent,
+};
+
+use super::{
+    assert_bid_currency, assert_bid_margin, assert_discriminator,
+    constants::TCOMP_BID_STATE_DISCRIMINATOR,
+};
+
+#[derive(Accounts)]
+#[instruction(bump: u8, bid_id: Pubkey)]
+pub struct WithdrawMarginAccountTokenCpiTcomp<'info> {
+    #[account(
+        constraint = is_margin_account(&margin_account.to_account_info()) @ ErrorCode::BadMargin,
+        has_one = owner,
+    )]
+    pub margin_account: Box<Account<'info, MarginAccount>>,
+
+    // this bid state can only be derived from TCOMP program for a given owner
+    // and because it's a signer only TCOMP can call this
+    #[account(
+        seeds=[b"bid_state".as_ref(), owner.key().as_ref(), bid_id.as_ref()],
+        seeds::program = Pubkey::from_str("TCMPhJdwDryooaGtiocG1u3xcYbRpiJzb283XfCZsDp").unwrap(),
+        bump = bump,
+    )]
+    pub bid_state: Signer<'info>,
+
+    /// CHECK: has_one on margin_account, seeds in bid_state
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = margin_account,
+        associated_token::token_program = token_program,
+    )]
+    pub margin_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Can only be passed in by TCOMP, since it has to sign off with bid pda.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination_ta: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(seeds = [], bump = tswap.bump[0])]
+    pub tswap: Box<Account<'info, TSwap>>,
+
+    /// CHECK: Only the event authority can invoke self-CPI
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Self-CPI will fail if the program is not the current program
+    pub program: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> Validate<'info> for WithdrawMarginAccountTokenCpiTcomp<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_not_paused(&self.tswap.to_account_info(), PAUSE_TCOMP_CPI)?;
+        assert_cpi_allowed(&self.margin_account.to_account_info(), DENY_TCOMP_CPI)?;
+
+        assert_discriminator(
+            &self.bid_state.to_account_info(),
+            &TCOMP_BID_STATE_DISCRIMINATOR,
+        )?;
+        assert_bid_currency(&self.bid_state.to_account_info(), Some(self.mint.key()))?;
+
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn process_withdraw_margin_account_token_from_tcomp(
+    ctx: Context<WithdrawMarginAccountTokenCpiTcomp>,
+    amount: u64,
+) -> Result<()> {
+    assert_bid_margin(
+        &ctx.accounts.bid_state.to_account_info(),
+        &ctx.accounts.margin_account.key(),
+        amount,
+    )?;
+
+    transfer_tokens_from_margin(
+        &ctx.accounts.margin_account,
+        &get_tswap_addr(),
+        &ctx.accounts.margin_ta,
+        &ctx.accounts.destination_ta,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+    ctx.accounts.margin_ta.reload()?;
+
+    let margin = &ctx.accounts.margin_account;
+    emit_event(
+        &WithdrawEvent {
+            margin: margin.key(),
+            owner: margin.owner,
+            caller: Caller::Tcomp,
+            caller_program: None,
+            caller_account: Some(ctx.accounts.bid_state.key()),
+            mint: Some(ctx.accounts.mint.key()),
+            amount,
+            post_balance: ctx.accounts.margin_ta.amount,
+            destination: ctx.accounts.destination_ta.key(),
+        },
+        &ctx.accounts.event_authority,
+        &ctx.accounts.program,
+        ctx.bumps.event_authority,
+    )?;
+
+    set_token_cpi_return_data(amount, ctx.accounts.margin_ta.amount)
+}
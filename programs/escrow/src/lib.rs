@@ -12,11 +12,13 @@
 
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub(crate) mod shared;
 pub mod state;
 
 use anchor_lang::prelude::*;
+use events::*;
 use instructions::*;
 pub(crate) use shared::*;
 use state::*;
@@ -28,8 +30,84 @@ pub mod escrow_program {
 
     use super::*;
 
-    pub fn init_update_tswap(ctx: Context<InitUpdateTSwap>, config: TSwapConfig) -> Result<()> {
-        instructions::init_update_tswap::process_init_update_tswap(ctx, config)
+    pub fn init_tswap(ctx: Context<InitTSwap>, config: TSwapConfig) -> Result<()> {
+        instructions::init_tswap::process_init_tswap(ctx, config)
+    }
+
+    pub fn migrate_tswap(ctx: Context<MigrateTSwap>) -> Result<()> {
+        instructions::migrate_tswap::process_migrate_tswap(ctx)
+    }
+
+    pub fn update_tswap_config(ctx: Context<UpdateTSwapConfig>, config: TSwapConfig) -> Result<()> {
+        instructions::update_tswap_config::process_update_tswap_config(ctx, config)
+    }
+
+    pub fn set_cosigner(ctx: Context<SetCosigner>) -> Result<()> {
+        instructions::set_cosigner::process_set_cosigner(ctx)
+    }
+
+    pub fn set_fee_vault(ctx: Context<SetFeeVault>) -> Result<()> {
+        instructions::set_fee_vault::process_set_fee_vault(ctx)
+    }
+
+    pub fn propose_tswap_owner(ctx: Context<ProposeTSwapOwner>) -> Result<()> {
+        instructions::propose_tswap_owner::process_propose_tswap_owner(ctx)
+    }
+
+    pub fn accept_tswap_owner(ctx: Context<AcceptTSwapOwner>) -> Result<()> {
+        instructions::accept_tswap_owner::process_accept_tswap_owner(ctx)
+    }
+
+    pub fn set_guardian(ctx: Context<SetGuardian>) -> Result<()> {
+        instructions::set_guardian::process_set_guardian(ctx)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+        instructions::set_paused::process_set_paused(ctx, paused)
+    }
+
+    pub fn set_rate_limit(
+        ctx: Context<SetRateLimit>,
+        caller: Caller,
+        limit: u64,
+        window: u32,
+    ) -> Result<()> {
+        instructions::set_rate_limit::process_set_rate_limit(ctx, caller, limit, window)
+    }
+
+    pub fn set_fees_enabled(ctx: Context<SetFeesEnabled>, fees_enabled: bool) -> Result<()> {
+        instructions::set_fees_enabled::process_set_fees_enabled(ctx, fees_enabled)
+    }
+
+    pub fn set_cpi_fee(
+        ctx: Context<SetCpiFee>,
+        caller: Caller,
+        fee_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::set_cpi_fee::process_set_cpi_fee(ctx, caller, fee_bps)
+    }
+
+    pub fn add_fee_exemption(ctx: Context<AddFeeExemption>) -> Result<()> {
+        instructions::add_fee_exemption::process_add_fee_exemption(ctx)
+    }
+
+    pub fn remove_fee_exemption(ctx: Context<RemoveFeeExemption>) -> Result<()> {
+        instructions::remove_fee_exemption::process_remove_fee_exemption(ctx)
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, lamports: u64) -> Result<()> {
+        instructions::withdraw_fees::process_withdraw_fees(ctx, lamports)
+    }
+
+    pub fn set_cosign_threshold(
+        ctx: Context<SetCosignThreshold>,
+        threshold: Option<u64>,
+    ) -> Result<()> {
+        instructions::set_cosign_threshold::process_set_cosign_threshold(ctx, threshold)
+    }
+
+    pub fn set_hold_expiry(ctx: Context<SetHoldExpiry>, hold_expiry_slots: u64) -> Result<()> {
+        instructions::set_hold_expiry::process_set_hold_expiry(ctx, hold_expiry_slots)
     }
 
     pub fn init_margin_account(
@@ -60,9 +138,10 @@ pub mod escrow_program {
         _bump: u8,
         _pool_id: [u8; 32],
         lamports: u64,
+        mode: WithdrawMode,
     ) -> Result<()> {
         instructions::withdraw_margin_account_from_tamm::process_withdraw_margin_account_from_tamm(
-            ctx, lamports,
+            ctx, lamports, mode,
         )
     }
 
@@ -71,9 +150,296 @@ pub mod escrow_program {
         _bump: u8,
         _bid_id: Pubkey,
         lamports: u64,
+        mode: WithdrawMode,
     ) -> Result<()> {
         instructions::withdraw_margin_account_from_tcomp::process_withdraw_margin_account_from_tcomp(
+            ctx, lamports, mode,
+        )
+    }
+
+    pub fn deposit_margin_account_token(
+        ctx: Context<DepositMarginAccountToken>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_margin_account_token::process_deposit_margin_account_token(
+            ctx, amount,
+        )
+    }
+
+    pub fn withdraw_margin_account_token(
+        ctx: Context<WithdrawMarginAccountToken>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_margin_account_token::process_withdraw_margin_account_token(
+            ctx, amount,
+        )
+    }
+
+    pub fn withdraw_margin_account_token_cpi_tamm(
+        ctx: Context<WithdrawMarginAccountTokenCpiTAmm>,
+        _bump: u8,
+        _pool_id: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_margin_account_token_from_tamm::process_withdraw_margin_account_token_from_tamm(
+            ctx, amount,
+        )
+    }
+
+    pub fn withdraw_margin_account_token_cpi_tcomp(
+        ctx: Context<WithdrawMarginAccountTokenCpiTcomp>,
+        _bump: u8,
+        _bid_id: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_margin_account_token_from_tcomp::process_withdraw_margin_account_token_from_tcomp(
+            ctx, amount,
+        )
+    }
+
+    pub fn attach_margin_account_cpi_tamm(
+        ctx: Context<AttachMarginAccountCpiTAmm>,
+        _bump: u8,
+        _pool_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::attach_margin_account_from_tamm::process_attach_margin_account_from_tamm(ctx)
+    }
+
+    pub fn detach_margin_account_cpi_tamm(
+        ctx: Context<DetachMarginAccountCpiTAmm>,
+        _bump: u8,
+        _pool_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::attach_margin_account_from_tamm::process_detach_margin_account_from_tamm(ctx)
+    }
+
+    pub fn attach_margin_account_cpi_tcomp(
+        ctx: Context<AttachMarginAccountCpiTcomp>,
+        _bump: u8,
+        _bid_id: Pubkey,
+    ) -> Result<()> {
+        instructions::attach_margin_account_from_tcomp::process_attach_margin_account_from_tcomp(
+            ctx,
+        )
+    }
+
+    pub fn detach_margin_account_cpi_tcomp(
+        ctx: Context<DetachMarginAccountCpiTcomp>,
+        _bump: u8,
+        _bid_id: Pubkey,
+    ) -> Result<()> {
+        instructions::attach_margin_account_from_tcomp::process_detach_margin_account_from_tcomp(
+            ctx,
+        )
+    }
+
+    pub fn migrate_margin_account(ctx: Context<MigrateMarginAccount>) -> Result<()> {
+        instructions::migrate_margin_account::process_migrate_margin_account(ctx)
+    }
+
+    pub fn reconcile_attachments(ctx: Context<ReconcileAttachments>) -> Result<()> {
+        instructions::reconcile_attachments::process_reconcile_attachments(ctx)
+    }
+
+    pub fn init_margin_account_v2(
+        ctx: Context<InitMarginAccountV2>,
+        margin_nr: u16,
+        name: [u8; 32],
+    ) -> Result<()> {
+        instructions::init_margin_account_v2::process_init_margin_account_v2(ctx, margin_nr, name)
+    }
+
+    pub fn deposit_margin_account_for(
+        ctx: Context<DepositMarginAccountFor>,
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::deposit_margin_account_for::process_deposit_margin_account_for(ctx, lamports)
+    }
+
+    pub fn deposit_margin_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositMarginAccounts<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        instructions::deposit_margin_accounts::process_deposit_margin_accounts(ctx, amounts)
+    }
+
+    pub fn init_update_cpi_consumer(
+        ctx: Context<InitUpdateCpiConsumer>,
+        seed_prefix: Vec<u8>,
+        signer_discriminator: [u8; 8],
+    ) -> Result<()> {
+        instructions::init_update_cpi_consumer::process_init_update_cpi_consumer(
+            ctx,
+            seed_prefix,
+            signer_discriminator,
+        )
+    }
+
+    pub fn close_cpi_consumer(ctx: Context<CloseCpiConsumer>) -> Result<()> {
+        instructions::close_cpi_consumer::process_close_cpi_consumer(ctx)
+    }
+
+    pub fn approve_consumer(ctx: Context<ApproveConsumer>) -> Result<()> {
+        instructions::approve_consumer::process_approve_consumer(ctx)
+    }
+
+    pub fn revoke_consumer(ctx: Context<RevokeConsumer>) -> Result<()> {
+        instructions::revoke_consumer::process_revoke_consumer(ctx)
+    }
+
+    pub fn withdraw_margin_account_cpi(
+        ctx: Context<WithdrawMarginAccountCpi>,
+        bump: u8,
+        id: [u8; 32],
+        lamports: u64,
+        mode: WithdrawMode,
+    ) -> Result<()> {
+        instructions::withdraw_margin_account_cpi::process_withdraw_margin_account_cpi(
+            ctx, bump, id, lamports, mode,
+        )
+    }
+
+    pub fn approve_allowance(
+        ctx: Context<ApproveAllowance>,
+        amount: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::approve_allowance::process_approve_allowance(ctx, amount, expires_at)
+    }
+
+    pub fn revoke_allowance(ctx: Context<RevokeAllowance>) -> Result<()> {
+        instructions::revoke_allowance::process_revoke_allowance(ctx)
+    }
+
+    pub fn spend_allowance(ctx: Context<SpendAllowance>, lamports: u64) -> Result<()> {
+        instructions::spend_allowance::process_spend_allowance(ctx, lamports)
+    }
+
+    pub fn set_withdrawal_delay(
+        ctx: Context<SetWithdrawalDelay>,
+        withdrawal_delay: u32,
+    ) -> Result<()> {
+        instructions::set_withdrawal_delay::process_set_withdrawal_delay(ctx, withdrawal_delay)
+    }
+
+    pub fn set_margin_cosign_threshold(
+        ctx: Context<SetMarginCosignThreshold>,
+        threshold: Option<u64>,
+    ) -> Result<()> {
+        instructions::set_margin_cosign_threshold::process_set_margin_cosign_threshold(
+            ctx, threshold,
+        )
+    }
+
+    pub fn set_cpi_denied(ctx: Context<SetCpiDenied>, cpi_denied: u8) -> Result<()> {
+        instructions::set_cpi_denied::process_set_cpi_denied(ctx, cpi_denied)
+    }
+
+    pub fn request_withdrawal(
+        ctx: Context<RequestWithdrawal>,
+        request_nr: u16,
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::request_withdrawal::process_request_withdrawal(ctx, request_nr, lamports)
+    }
+
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        instructions::cancel_withdrawal::process_cancel_withdrawal(ctx)
+    }
+
+    pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+        instructions::execute_withdrawal::process_execute_withdrawal(ctx)
+    }
+
+    pub fn transfer_between_margin_accounts(
+        ctx: Context<TransferBetweenMarginAccounts>,
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::transfer_between_margin_accounts::process_transfer_between_margin_accounts(
             ctx, lamports,
         )
     }
+
+    pub fn consolidate_margin_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, ConsolidateMarginAccounts<'info>>,
+    ) -> Result<()> {
+        instructions::consolidate_margin_accounts::process_consolidate_margin_accounts(ctx)
+    }
+
+    pub fn reserve_margin_lamports(
+        ctx: Context<ReserveMarginLamports>,
+        caller_account: Pubkey,
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::reserve_margin_lamports::process_reserve_margin_lamports(
+            ctx,
+            caller_account,
+            lamports,
+        )
+    }
+
+    pub fn reserve_margin_lamports_cpi(
+        ctx: Context<ReserveMarginLamportsCpi>,
+        bump: u8,
+        id: [u8; 32],
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::reserve_margin_lamports_cpi::process_reserve_margin_lamports_cpi(
+            ctx, bump, id, lamports,
+        )
+    }
+
+    pub fn release_reservation(ctx: Context<ReleaseReservation>, lamports: u64) -> Result<()> {
+        instructions::release_reservation::process_release_reservation(ctx, lamports)
+    }
+
+    pub fn place_hold_cpi_tamm(
+        ctx: Context<PlaceHoldCpiTAmm>,
+        _bump: u8,
+        _pool_id: [u8; 32],
+        hold_id: [u8; 32],
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::place_hold_from_tamm::process_place_hold_from_tamm(ctx, hold_id, lamports)
+    }
+
+    pub fn place_hold_cpi_tcomp(
+        ctx: Context<PlaceHoldCpiTcomp>,
+        _bump: u8,
+        _bid_id: Pubkey,
+        hold_id: [u8; 32],
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::place_hold_from_tcomp::process_place_hold_from_tcomp(ctx, hold_id, lamports)
+    }
+
+    pub fn capture_hold_cpi_tamm(
+        ctx: Context<CaptureHoldCpiTAmm>,
+        _bump: u8,
+        _pool_id: [u8; 32],
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::capture_hold_from_tamm::process_capture_hold_from_tamm(ctx, lamports)
+    }
+
+    pub fn capture_hold_cpi_tcomp(
+        ctx: Context<CaptureHoldCpiTcomp>,
+        _bump: u8,
+        _bid_id: Pubkey,
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::capture_hold_from_tcomp::process_capture_hold_from_tcomp(ctx, lamports)
+    }
+
+    pub fn release_hold(ctx: Context<ReleaseHold>) -> Result<()> {
+        instructions::release_hold::process_release_hold(ctx)
+    }
+
+    pub fn propose_margin_owner(ctx: Context<ProposeMarginOwner>) -> Result<()> {
+        instructions::propose_margin_owner::process_propose_margin_owner(ctx)
+    }
+
+    pub fn accept_margin_owner(ctx: Context<AcceptMarginOwner>) -> Result<()> {
+        instructions::accept_margin_owner::process_accept_margin_owner(ctx)
+    }
 }
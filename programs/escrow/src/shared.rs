@@ -1,4 +1,32 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::Instruction,
+        program::{invoke_signed, set_return_data},
+    },
+    system_program::{transfer, Transfer},
+    Event,
+};
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self,
+        extension::{transfer_hook, StateWithExtensions},
+    },
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use tensor_toolbox::transfer_lamports_from_pda;
+use tensor_vipers::unwrap_int;
+
+use crate::{
+    constants::{
+        CURRENT_RESERVATION_VERSION, DEFAULT_HOLD_EXPIRY_SLOTS, MAX_FEE_BPS, WITHDRAW_ALL,
+    },
+    error::ErrorCode as EscrowError,
+    Caller, CpiConsumer, CpiReturnData, Hold, MarginAccount, MarginAccountV2, RateLimit,
+    RateLimitExceededEvent, Reservation, TAmmPoolPrefix, TSwapConfig, TSwapV2, TcompBidStatePrefix,
+    WithdrawMode, MARGIN_V2_SIZE, TSWAP_V2_SIZE,
+};
 
 // Anchor discriminator length.
 const DISCRIMINATOR_LEN: usize = 8;
@@ -23,3 +51,671 @@ pub(crate) fn assert_discriminator(account_info: &AccountInfo, expected: &[u8; 8
 
     Ok(())
 }
+
+/// Checks that `signer` is the registered consumer's PDA, derived from
+/// `[seed_prefix, owner, id]`: only the consumer program can sign for it.
+pub(crate) fn assert_consumer_signer(
+    consumer: &CpiConsumer,
+    signer: &AccountInfo,
+    owner: &Pubkey,
+    bump: u8,
+    id: &[u8; 32],
+) -> Result<()> {
+    let expected = Pubkey::create_program_address(
+        &[consumer.seed_prefix(), owner.as_ref(), id, &[bump]],
+        &consumer.program_id,
+    )
+    .map_err(|_| EscrowError::BadCpiSigner)?;
+
+    if expected != *signer.key || *signer.owner != consumer.program_id {
+        return Err(EscrowError::BadCpiSigner.into());
+    }
+
+    assert_discriminator(signer, &consumer.signer_discriminator)
+}
+
+/// Checks that the signing TAMM pool uses `margin` as its shared escrow.
+pub(crate) fn assert_pool_margin(pool: &AccountInfo, margin: &Pubkey) -> Result<()> {
+    let data = pool.try_borrow_data()?;
+    let pool = TAmmPoolPrefix::deserialize(&mut &data[DISCRIMINATOR_LEN..])
+        .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+    if pool.shared_escrow != *margin {
+        return Err(EscrowError::MarginMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that the signing TCOMP bid uses `margin` and that `amount` is at most one unit of the bid.
+///
+/// Every fill draws at most the bid's price, capping by the remaining quantity instead would
+/// depend on whether TCOMP bumps `filled_quantity` before or after calling in.
+pub(crate) fn assert_bid_margin(
+    bid_state: &AccountInfo,
+    margin: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let data = bid_state.try_borrow_data()?;
+    let bid = TcompBidStatePrefix::deserialize(&mut &data[DISCRIMINATOR_LEN..])
+        .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+    if bid.margin != Some(*margin) {
+        return Err(EscrowError::MarginMismatch.into());
+    }
+    if amount > bid.amount {
+        return Err(EscrowError::AmountExceedsBid.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that `amount` fits in what is needed to fill the rest of the signing TCOMP bid.
+pub(crate) fn assert_bid_remaining(bid_state: &AccountInfo, amount: u64) -> Result<()> {
+    let data = bid_state.try_borrow_data()?;
+    let bid = TcompBidStatePrefix::deserialize(&mut &data[DISCRIMINATOR_LEN..])
+        .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+    if amount > bid.remaining_amount() {
+        return Err(EscrowError::AmountExceedsBid.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that the signing TAMM pool trades in `currency` (the default key for SOL).
+pub(crate) fn assert_pool_currency(pool: &AccountInfo, currency: &Pubkey) -> Result<()> {
+    let data = pool.try_borrow_data()?;
+    let pool = TAmmPoolPrefix::deserialize(&mut &data[DISCRIMINATOR_LEN..])
+        .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+    if pool.currency != *currency {
+        return Err(EscrowError::CurrencyMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that the signing TCOMP bid is paid in `currency` (`None` for SOL).
+pub(crate) fn assert_bid_currency(bid_state: &AccountInfo, currency: Option<Pubkey>) -> Result<()> {
+    let data = bid_state.try_borrow_data()?;
+    let bid = TcompBidStatePrefix::deserialize(&mut &data[DISCRIMINATOR_LEN..])
+        .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+    if bid.currency != currency {
+        return Err(EscrowError::CurrencyMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Token-2022 transfer hooks need extra accounts on every transfer, which neither the
+/// owner nor TAMM/TCOMP pass in, so tokens of such mints could never leave the vault.
+pub(crate) fn assert_supported_mint(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    if transfer_hook::get_program_id(&mint).is_some() {
+        return Err(EscrowError::UnsupportedMint.into());
+    }
+
+    Ok(())
+}
+
+/// Decodes the V2 fields of a margin account, `None` for accounts still on V1.
+pub(crate) fn load_margin_v2(margin_info: &AccountInfo) -> Result<Option<MarginAccountV2>> {
+    if margin_info.data_len() < MARGIN_V2_SIZE {
+        return Ok(None);
+    }
+
+    let data = margin_info.try_borrow_data()?;
+    Ok(Some(MarginAccountV2::try_deserialize(&mut &data[..])?))
+}
+
+/// Tops the margin account up to the V2 rent-exempt minimum from `payer`.
+pub(crate) fn top_up_margin_v2_rent<'info>(
+    margin_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(MARGIN_V2_SIZE);
+    let top_up = rent.saturating_sub(margin_info.lamports());
+    if top_up == 0 {
+        return Ok(());
+    }
+
+    transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: payer.clone(),
+                to: margin_info.clone(),
+            },
+        ),
+        top_up,
+    )
+}
+
+/// Reallocates a V1 margin account to the V2 layout in place, the address (and every
+/// pool or bid pointing at it) stays the same. The account has to hold the V2 rent already.
+pub(crate) fn migrate_margin_in_place(
+    margin_info: &AccountInfo,
+    margin: &MarginAccount,
+) -> Result<MarginAccountV2> {
+    margin_info.realloc(MARGIN_V2_SIZE, true)?;
+
+    let migrated = MarginAccountV2::from_v1(margin, Clock::get()?.slot);
+    migrated.try_serialize(&mut &mut margin_info.try_borrow_mut_data()?[..])?;
+
+    Ok(migrated)
+}
+
+/// Checks that an allowance or consumer approval granted on margin `generation` still
+/// applies, i.e. the margin account wasn't closed and re-created at the same address since.
+pub(crate) fn assert_margin_generation(margin_info: &AccountInfo, generation: u64) -> Result<()> {
+    let current = load_margin_v2(margin_info)?.map(|margin| margin.generation);
+    if current != Some(generation) {
+        return Err(EscrowError::StaleGrant.into());
+    }
+
+    Ok(())
+}
+
+/// Decodes the V2 fields of the TSwap account, `None` while it is still on V1.
+pub(crate) fn load_tswap_v2(tswap_info: &AccountInfo) -> Result<Option<TSwapV2>> {
+    if tswap_info.data_len() < TSWAP_V2_SIZE {
+        return Ok(None);
+    }
+
+    let data = tswap_info.try_borrow_data()?;
+    Ok(Some(TSwapV2::try_deserialize(&mut &data[..])?))
+}
+
+/// Fails when any of `flags` is paused, TSwap can't be paused while still on V1.
+pub(crate) fn assert_not_paused(tswap_info: &AccountInfo, flags: u8) -> Result<()> {
+    let paused = load_tswap_v2(tswap_info)?.map_or(0, |tswap| tswap.paused);
+    if paused & flags != 0 {
+        return Err(EscrowError::Paused.into());
+    }
+    Ok(())
+}
+
+/// Fails when the margin account's owner denied any of `flags`, V1 accounts allow everything.
+pub(crate) fn assert_cpi_allowed(margin_info: &AccountInfo, flags: u8) -> Result<()> {
+    let denied = load_margin_v2(margin_info)?.map_or(0, |margin| margin.cpi_denied);
+    if denied & flags != 0 {
+        return Err(EscrowError::CpiDenied.into());
+    }
+    Ok(())
+}
+
+/// Counts a CPI withdrawal against its caller's rate limit. Breaching the limit fails
+/// the withdrawal, so the alert is logged: a self-CPI would be rolled back with it.
+pub(crate) fn consume_rate_limit(
+    rate_limit: &mut RateLimit,
+    margin: Pubkey,
+    caller: Caller,
+    caller_program: Option<Pubkey>,
+    lamports: u64,
+) -> Result<()> {
+    if let Err(used) = rate_limit.try_consume(lamports, Clock::get()?.unix_timestamp) {
+        emit!(RateLimitExceededEvent {
+            margin,
+            caller,
+            caller_program,
+            amount: lamports,
+            used,
+            limit: rate_limit.limit,
+            window: rate_limit.window,
+        });
+        return Err(EscrowError::RateLimitExceeded.into());
+    }
+    Ok(())
+}
+
+/// TAMM and TCOMP limits live on TSwap, there is no room for them while it is still on V1.
+pub(crate) fn consume_tswap_rate_limit(
+    tswap_info: &AccountInfo,
+    margin: Pubkey,
+    caller: Caller,
+    lamports: u64,
+) -> Result<()> {
+    let Some(mut tswap) = load_tswap_v2(tswap_info)? else {
+        return Ok(());
+    };
+    let rate_limit = match caller {
+        Caller::TAmm => &mut tswap.tamm_rate_limit,
+        Caller::Tcomp => &mut tswap.tcomp_rate_limit,
+        _ => return Ok(()),
+    };
+    if !rate_limit.is_enabled() {
+        return Ok(());
+    }
+
+    consume_rate_limit(rate_limit, margin, caller, None, lamports)?;
+    tswap.try_serialize(&mut &mut tswap_info.try_borrow_mut_data()?[..])
+}
+
+/// Protocol fee on a CPI withdrawal of `lamports`. It is charged on top, so the caller
+/// still receives what it asked for. 0 while fees are off (or TSwap is still on V1) and
+/// for exempt owners.
+pub(crate) fn cpi_fee(
+    tswap_info: &AccountInfo,
+    caller: Caller,
+    consumer_fee_bps: Option<u16>,
+    exempt: bool,
+    lamports: u64,
+) -> Result<u64> {
+    let Some(tswap) = load_tswap_v2(tswap_info)? else {
+        return Ok(0);
+    };
+    if !tswap.fees_enabled || exempt {
+        return Ok(0);
+    }
+
+    let fee_bps = match caller {
+        Caller::TAmm => tswap.tamm_fee_bps,
+        Caller::Tcomp => tswap.tcomp_fee_bps,
+        _ => consumer_fee_bps,
+    }
+    .unwrap_or(tswap.config.fee_bps)
+    // a V1 config.fee_bps was never validated, so cap it here rather than trust it
+    .min(MAX_FEE_BPS);
+
+    Ok((u128::from(lamports) * u128::from(fee_bps) / 10_000) as u64)
+}
+
+/// Moves a CPI withdrawal fee into the fee vault, which only has to be passed in once a fee is due.
+pub(crate) fn pay_cpi_fee<'info>(
+    margin_info: &AccountInfo<'info>,
+    fee_vault: &Option<UncheckedAccount<'info>>,
+    fee: u64,
+) -> Result<()> {
+    let Some(fee_vault) = fee_vault else {
+        return Err(EscrowError::FeeVaultMissing.into());
+    };
+    transfer_lamports_from_pda(margin_info, &fee_vault.to_account_info(), fee)
+}
+
+pub(crate) fn assert_tswap_config(config: &TSwapConfig) -> Result<()> {
+    assert_fee_bps(config.fee_bps)
+}
+
+pub(crate) fn assert_fee_bps(fee_bps: u16) -> Result<()> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(EscrowError::FeeBpsTooHigh.into());
+    }
+    Ok(())
+}
+
+/// The fee vault only holds lamports, so it has to be a wallet (or a data-less PDA).
+pub(crate) fn assert_fee_vault(fee_vault: &AccountInfo) -> Result<()> {
+    if *fee_vault.owner != anchor_lang::system_program::ID {
+        return Err(EscrowError::BadFeeVault.into());
+    }
+    Ok(())
+}
+
+/// Lamports that can leave the margin account without touching its rent or the
+/// lamports locked by the owner (eg pending withdrawal requests).
+pub(crate) fn available_lamports(margin_info: &AccountInfo) -> Result<u64> {
+    let locked = load_margin_v2(margin_info)?.map_or(0, |margin| margin.locked_lamports());
+    let rent = Rent::get()?.minimum_balance(margin_info.data_len());
+    Ok(margin_info
+        .lamports()
+        .saturating_sub(rent)
+        .saturating_sub(locked))
+}
+
+/// Checks that `lamports` can leave the margin account, see `available_lamports`.
+pub(crate) fn assert_available_lamports(margin_info: &AccountInfo, lamports: u64) -> Result<()> {
+    if lamports > available_lamports(margin_info)? {
+        return Err(EscrowError::InsufficientAvailableBalance.into());
+    }
+
+    Ok(())
+}
+
+/// Moves `lamports` of the available balance (checked by the caller) into the reservation
+/// of `caller_account`, initializing it on first use.
+pub(crate) fn reserve_lamports(
+    margin: &mut MarginAccountV2,
+    margin_key: Pubkey,
+    reservation: &mut Reservation,
+    bump: u8,
+    caller_account: Pubkey,
+    rent_payer: Pubkey,
+    lamports: u64,
+) -> Result<()> {
+    if reservation.version == 0 {
+        reservation.version = CURRENT_RESERVATION_VERSION;
+        reservation.bump = [bump];
+        reservation.margin_account = margin_key;
+        reservation.caller_account = caller_account;
+        reservation.rent_payer = rent_payer;
+    }
+
+    reservation.amount = unwrap_int!(reservation.amount.checked_add(lamports));
+    margin.reserved_lamports = unwrap_int!(margin.reserved_lamports.checked_add(lamports));
+
+    Ok(())
+}
+
+/// Covers a CPI withdrawal of `lamports` from the caller's reservation first, and from
+/// the unreserved balance for the rest. The reservation accounting is written straight
+/// to the margin account data, reload any `MarginAccountV2` view of it afterwards.
+pub(crate) fn draw_available_lamports(
+    margin_info: &AccountInfo,
+    reservation: Option<&mut Reservation>,
+    lamports: u64,
+) -> Result<()> {
+    let Some(reservation) = reservation else {
+        return assert_available_lamports(margin_info, lamports);
+    };
+    // reservations can only be made on V2 accounts
+    let Some(mut margin) = load_margin_v2(margin_info)? else {
+        return Err(EscrowError::BadMargin.into());
+    };
+
+    let drawn = reservation.amount.min(lamports);
+    reservation.amount -= drawn;
+    margin.reserved_lamports = margin.reserved_lamports.saturating_sub(drawn);
+    margin.try_serialize(&mut &mut margin_info.try_borrow_mut_data()?[..])?;
+
+    assert_available_lamports(margin_info, lamports - drawn)
+}
+
+/// Slots a new hold lives for, `DEFAULT_HOLD_EXPIRY_SLOTS` while TSwap doesn't set it.
+pub(crate) fn hold_expiry_slots(tswap_info: &AccountInfo) -> Result<u64> {
+    let slots = load_tswap_v2(tswap_info)?.map_or(0, |tswap| tswap.hold_expiry_slots);
+    Ok(if slots == 0 {
+        DEFAULT_HOLD_EXPIRY_SLOTS
+    } else {
+        slots
+    })
+}
+
+/// Captures `lamports` (the whole hold for `WITHDRAW_ALL`) of an unexpired hold and
+/// hands the whole hold back to the available balance, out of which the capture is
+/// then paid. Like `draw_available_lamports`, the hold accounting is written straight
+/// to the margin account data: reload any `MarginAccountV2` view of it afterwards.
+pub(crate) fn capture_hold(
+    margin_info: &AccountInfo,
+    hold: &mut Hold,
+    lamports: u64,
+) -> Result<u64> {
+    if hold.is_expired(Clock::get()?.slot) {
+        return Err(EscrowError::HoldExpired.into());
+    }
+    let captured = if lamports == WITHDRAW_ALL {
+        hold.amount
+    } else {
+        lamports
+    };
+    if captured > hold.amount {
+        return Err(EscrowError::AmountExceedsHold.into());
+    }
+    // holds can only be placed on V2 accounts
+    let Some(mut margin) = load_margin_v2(margin_info)? else {
+        return Err(EscrowError::BadMargin.into());
+    };
+
+    margin.held_lamports = unwrap_int!(margin.held_lamports.checked_sub(hold.amount));
+    margin.try_serialize(&mut &mut margin_info.try_borrow_mut_data()?[..])?;
+    hold.amount = 0;
+
+    Ok(captured)
+}
+
+/// Lamports a CPI withdrawal of `lamports` moves. `Exact` asks for all of them (and
+/// fails further down when they aren't covered), `UpToAvailable` caps them at what the
+/// caller's reservation and the available balance cover, `fee` included.
+pub(crate) fn withdrawable_lamports(
+    margin_info: &AccountInfo,
+    reservation: Option<&Reservation>,
+    mode: WithdrawMode,
+    lamports: u64,
+    fee: impl Fn(u64) -> Result<u64>,
+) -> Result<u64> {
+    if mode == WithdrawMode::Exact {
+        return Ok(lamports);
+    }
+
+    let covered = available_lamports(margin_info)?
+        .saturating_add(reservation.map_or(0, |reservation| reservation.amount));
+    if lamports.saturating_add(fee(lamports)?) <= covered {
+        return Ok(lamports);
+    }
+    // the fee grows with the amount, so what is left of `covered` after its own fee is covered
+    Ok(covered.saturating_sub(fee(covered)?).min(lamports))
+}
+
+/// Hands the lamports a CPI entrypoint moved, and what is left of the margin account, back
+/// to the caller. Set last: the event self-CPIs would clear it.
+pub(crate) fn set_cpi_return_data(margin_info: &AccountInfo, lamports: u64) -> Result<()> {
+    let data = CpiReturnData {
+        amount: lamports,
+        balance: margin_info.lamports(),
+        rent: Rent::get()?.minimum_balance(margin_info.data_len()),
+    };
+    set_return_data(&data.try_to_vec()?);
+    Ok(())
+}
+
+/// `set_cpi_return_data` for token withdrawals, `balance` is what is left in the vault.
+pub(crate) fn set_token_cpi_return_data(amount: u64, balance: u64) -> Result<()> {
+    let data = CpiReturnData {
+        amount,
+        balance,
+        rent: 0,
+    };
+    set_return_data(&data.try_to_vec()?);
+    Ok(())
+}
+
+/// Owner withdrawals (direct or through a delegate) have to go through a request
+/// once a withdrawal delay is set.
+pub(crate) fn assert_not_timelocked(margin_info: &AccountInfo) -> Result<()> {
+    if let Some(margin) = load_margin_v2(margin_info)? {
+        if margin.is_timelocked() || margin.pending_withdrawals > 0 {
+            return Err(EscrowError::WithdrawalDelayed.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Lower of the global and the margin account's cosign thresholds, `None` when neither is set.
+pub(crate) fn cosign_threshold(
+    tswap_info: &AccountInfo,
+    margin_info: &AccountInfo,
+) -> Result<Option<u64>> {
+    let global = load_tswap_v2(tswap_info)?.and_then(|tswap| tswap.cosign_threshold);
+    let account = load_margin_v2(margin_info)?.and_then(|margin| margin.cosign_threshold);
+    Ok(global.into_iter().chain(account).min())
+}
+
+/// Owner withdrawals (direct or through a delegate) above the cosign threshold
+/// have to be co-signed by the tswap cosigner.
+pub(crate) fn assert_cosigned<'info>(
+    tswap_info: &AccountInfo,
+    margin_info: &AccountInfo,
+    cosigner: &Option<Signer<'info>>,
+    lamports: u64,
+) -> Result<()> {
+    if cosigner.is_some() {
+        return Ok(());
+    }
+    if matches!(cosign_threshold(tswap_info, margin_info)?, Some(threshold) if lamports > threshold)
+    {
+        return Err(EscrowError::CosignRequired.into());
+    }
+
+    Ok(())
+}
+
+/// Moving lamports to a margin account with a shorter withdrawal delay would skip the
+/// difference. Same for a looser cosign threshold: the global threshold applies to
+/// both accounts, so only the source's own threshold matters.
+pub(crate) fn assert_margin_transfer(
+    source_info: &AccountInfo,
+    destination_info: &AccountInfo,
+    lamports: u64,
+) -> Result<()> {
+    let source = load_margin_v2(source_info)?;
+    let destination = load_margin_v2(destination_info)?;
+
+    let delay =
+        |margin: &Option<MarginAccountV2>| margin.as_ref().map_or(0, |m| m.withdrawal_delay);
+    if delay(&source) > delay(&destination) {
+        return Err(EscrowError::WithdrawalDelayed.into());
+    }
+
+    let threshold =
+        |margin: &Option<MarginAccountV2>| margin.as_ref().and_then(|m| m.cosign_threshold);
+    if let Some(source_threshold) = threshold(&source) {
+        let looser = !matches!(threshold(&destination), Some(t) if t <= source_threshold);
+        if looser && lamports > source_threshold {
+            return Err(EscrowError::CosignRequired.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that a margin account passed in as a remaining account is a writable
+/// margin PDA (see `is_margin_account`) owned by `owner`.
+pub(crate) fn assert_owned_margin_account(margin_info: &AccountInfo, owner: &Pubkey) -> Result<()> {
+    if !margin_info.is_writable || !crate::is_margin_account(margin_info) {
+        return Err(EscrowError::BadMargin.into());
+    }
+    let margin = MarginAccount::try_deserialize(&mut &margin_info.try_borrow_data()?[..])?;
+    if margin.owner != *owner {
+        return Err(EscrowError::BadOwner.into());
+    }
+
+    Ok(())
+}
+
+/// Fails when a margin account is passed in as a remaining account more than once.
+pub(crate) fn assert_unique_margin_accounts(margin_infos: &[AccountInfo]) -> Result<()> {
+    for (i, margin_info) in margin_infos.iter().enumerate() {
+        if margin_infos[..i]
+            .iter()
+            .any(|other| other.key == margin_info.key)
+        {
+            return Err(EscrowError::DuplicateMarginAccount.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves tokens out of the margin account's token vault, signing with the margin PDA.
+pub(crate) fn transfer_tokens_from_margin<'info>(
+    margin_account: &Account<'info, MarginAccount>,
+    tswap: &Pubkey,
+    margin_ta: &InterfaceAccount<'info, TokenAccount>,
+    destination_ta: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    assert_supported_mint(&mint.to_account_info())?;
+
+    // sign with the key the address was derived from, which differs from the
+    // owner once ownership has been handed off
+    let seed_owner = load_margin_v2(&margin_account.to_account_info())?
+        .map_or(margin_account.owner, |margin| margin.seed_owner());
+    let nr = margin_account.nr.to_le_bytes();
+    let seeds: &[&[u8]] = &[
+        b"margin".as_ref(),
+        seed_owner.as_ref(),
+        &nr,
+        &margin_account.bump,
+    ];
+    let legacy_seeds: &[&[u8]] = &[
+        b"margin".as_ref(),
+        tswap.as_ref(),
+        seed_owner.as_ref(),
+        &nr,
+        &margin_account.bump,
+    ];
+    let signer_seeds: &[&[&[u8]]] =
+        if Pubkey::create_program_address(seeds, &crate::ID) == Ok(margin_account.key()) {
+            &[seeds]
+        } else {
+            &[legacy_seeds]
+        };
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: margin_ta.to_account_info(),
+                mint: mint.to_account_info(),
+                to: destination_ta.to_account_info(),
+                authority: margin_account.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Returns the account receiving the margin account's rent-exempt reserve on close.
+/// With a separate rent destination, everything above the reserve is first sent to the owner.
+pub(crate) fn margin_rent_destination<'info>(
+    margin_info: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    rent_destination: &Option<UncheckedAccount<'info>>,
+) -> Result<AccountInfo<'info>> {
+    match rent_destination {
+        Some(rent_destination) => {
+            let rent = Rent::get()?.minimum_balance(margin_info.data_len());
+            let excess = margin_info.lamports().saturating_sub(rent);
+            transfer_lamports_from_pda(margin_info, owner, excess)?;
+            Ok(rent_destination.to_account_info())
+        }
+        None => Ok(owner.clone()),
+    }
+}
+
+/// Emits `event` through a self-CPI when the event accounts are passed in, mirroring `emit_cpi!`.
+///
+/// `emit_cpi!` needs the event accounts on every call, but deployed TAMM/TCOMP build their
+/// CPIs without them and cannot be upgraded in lockstep. Failing their withdrawals would be
+/// worse than a log entry that RPCs may truncate, so those events fall back to `emit!`. Both
+/// forms carry the same discriminator and payload, see `EscrowEvent::from_bytes` in the client.
+pub(crate) fn emit_event<'info, E: Event>(
+    event: &E,
+    event_authority: &Option<UncheckedAccount<'info>>,
+    program: &Option<UncheckedAccount<'info>>,
+    bump: u8,
+) -> Result<()> {
+    let (Some(event_authority), Some(_)) = (event_authority, program) else {
+        emit!(*event);
+        return Ok(());
+    };
+
+    let ix_data: Vec<u8> = anchor_lang::event::EVENT_IX_TAG_LE
+        .into_iter()
+        .chain(event.data())
+        .collect();
+    let ix = Instruction::new_with_bytes(
+        crate::ID,
+        &ix_data,
+        vec![AccountMeta::new_readonly(event_authority.key(), true)],
+    );
+
+    invoke_signed(
+        &ix,
+        &[event_authority.to_account_info()],
+        &[&[b"__event_authority", &[bump]]],
+    )
+    .map_err(Into::into)
+}
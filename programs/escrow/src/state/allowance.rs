@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+// (!) INCLUSIVE of discriminator (8 bytes)
+#[constant]
+#[allow(clippy::identity_op)]
+pub const ALLOWANCE_SIZE: usize = 8 + 1 + 1 + 32 + 32 + 8 + 9 + 32 + 8 + 24;
+
+/// Lamports a delegate can withdraw from a margin account without the owner's signature.
+///
+/// Derived from `[b"allowance", margin_account, delegate]`, so there is one per delegate.
+/// The address outlives the margin account, `margin_generation` keeps an allowance from
+/// applying to a margin account re-created there.
+#[account]
+pub struct Allowance {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub margin_account: Pubkey,
+    pub delegate: Pubkey,
+    /// Lamports left to spend, decremented on every withdrawal.
+    pub amount: u64,
+    /// Unix timestamp after which the allowance can no longer be spent.
+    pub expires_at: Option<i64>,
+    /// Owner that granted the allowance, it stops applying once ownership is handed off.
+    pub owner: Pubkey,
+    /// `MarginAccountV2::generation` of the margin account it was granted on.
+    pub margin_generation: u64,
+    pub _reserved: [u8; 24],
+}
+
+impl Allowance {
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if now > expires_at)
+    }
+}
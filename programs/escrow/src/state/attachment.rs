@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::Caller;
+
+// (!) INCLUSIVE of discriminator (8 bytes)
+#[constant]
+#[allow(clippy::identity_op)]
+pub const ATTACHMENT_SIZE: usize = 8 + 1 + 1 + 32 + 32 + 1 + 32 + 32;
+
+/// Marks a TAMM pool or TCOMP bid as attached to a margin account,
+/// derived from `[b"attachment", margin_account, caller_account]`.
+///
+/// Lives from attach to detach, so a pool or bid is counted at most once and only
+/// what was attached can be detached.
+#[account]
+pub struct Attachment {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub margin_account: Pubkey,
+    /// TAMM pool or TCOMP bid state that attached itself.
+    pub caller_account: Pubkey,
+    pub caller: Caller,
+    /// Refunded the rent on detach.
+    pub rent_payer: Pubkey,
+    pub _reserved: [u8; 32],
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+// (!) INCLUSIVE of discriminator (8 bytes)
+#[constant]
+#[allow(clippy::identity_op)]
+pub const CONSUMER_APPROVAL_SIZE: usize = 8 + 1 + 1 + 32 + 32 + 32 + 8 + 24;
+
+/// Owner's approval for a registered cpi consumer to withdraw from (or reserve on) a margin account.
+///
+/// Derived from `[b"consumer_approval", margin_account, consumer_program]`, registering a consumer
+/// alone doesn't give it access to any margin account.
+#[account]
+pub struct ConsumerApproval {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub margin_account: Pubkey,
+    pub consumer_program: Pubkey,
+    /// Owner that granted the approval, it stops applying once ownership is handed off.
+    pub owner: Pubkey,
+    /// `MarginAccountV2::generation` of the margin account it was granted on.
+    pub margin_generation: u64,
+    pub _reserved: [u8; 24],
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::RateLimit;
+
+// (!) INCLUSIVE of discriminator (8 bytes)
+#[constant]
+#[allow(clippy::identity_op)]
+pub const CPI_CONSUMER_SIZE: usize = 8 + 1 + 1 + 32 + 32 + 1 + 8 + 36 + 3 + 89;
+
+pub const MAX_SEED_PREFIX_LEN: usize = 32;
+
+/// A program allowed to withdraw from margin accounts through `withdraw_margin_account_cpi`.
+///
+/// The consumer signs with a PDA derived from `[seed_prefix, owner, id]` whose data
+/// starts with `signer_discriminator` (eg TAMM pools: `[b"pool", owner, pool_id]`).
+#[account]
+pub struct CpiConsumer {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub program_id: Pubkey,
+    pub seed_prefix: [u8; 32],
+    pub seed_prefix_len: u8,
+    pub signer_discriminator: [u8; 8],
+    /// Cap on what the consumer can withdraw across all margin accounts.
+    pub rate_limit: RateLimit,
+    /// Fee on the consumer's withdrawals, `TSwap::config.fee_bps` when `None`.
+    pub fee_bps: Option<u16>,
+    pub _reserved: [u8; 89],
+}
+
+impl CpiConsumer {
+    pub fn seed_prefix(&self) -> &[u8] {
+        &self.seed_prefix[..self.seed_prefix_len as usize]
+    }
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Set as (Borsh-encoded) return data by every CPI entrypoint, so callers learn what is
+/// left in the margin account without re-reading it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpiReturnData {
+    /// Lamports (tokens for token withdrawals) that left the margin account, 0 when the
+    /// instruction doesn't move any.
+    pub amount: u64,
+    /// Lamports left in the margin account (tokens left in its vault for token withdrawals).
+    pub balance: u64,
+    /// Rent-exempt minimum of the margin account, which `balance` never drops below
+    /// (0 for token withdrawals).
+    pub rent: u64,
+}
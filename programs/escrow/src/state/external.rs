@@ -0,0 +1,56 @@
+//! Partial layouts of accounts owned by TAMM and TCOMP, only up to the fields we read.
+
+use anchor_lang::prelude::*;
+
+/// Prefix of a TAMM `Pool` (after the discriminator), up to `shared_escrow`.
+#[derive(AnchorDeserialize)]
+pub struct TAmmPoolPrefix {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub pool_id: [u8; 32],
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub expiry: i64,
+    pub owner: Pubkey,
+    pub whitelist: Pubkey,
+    pub rent_payer: Pubkey,
+    pub currency: Pubkey,
+    pub amount: u64,
+    pub price_offset: i32,
+    pub nfts_held: u32,
+    pub taker_sell_count: u32,
+    pub taker_buy_count: u32,
+    pub accumulated_mm_profit: u64,
+    /// Zeroed when the pool is not attached to a margin account.
+    pub shared_escrow: Pubkey,
+}
+
+/// Prefix of a TCOMP `BidState` (after the discriminator), up to `margin`.
+#[derive(AnchorDeserialize)]
+pub struct TcompBidStatePrefix {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub owner: Pubkey,
+    pub bid_id: Pubkey,
+    pub target: u8,
+    pub target_id: Pubkey,
+    pub field: Option<u8>,
+    pub field_id: Option<Pubkey>,
+    pub quantity: u32,
+    pub filled_quantity: u32,
+    /// Price per unit.
+    pub amount: u64,
+    pub currency: Option<Pubkey>,
+    pub expiry: i64,
+    pub private_taker: Option<Pubkey>,
+    pub maker_broker: Option<Pubkey>,
+    pub margin: Option<Pubkey>,
+}
+
+impl TcompBidStatePrefix {
+    /// Amount still needed to fill the remaining quantity.
+    pub fn remaining_amount(&self) -> u64 {
+        let remaining = self.quantity.saturating_sub(self.filled_quantity);
+        self.amount.saturating_mul(remaining as u64)
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+// (!) INCLUSIVE of discriminator (8 bytes)
+#[constant]
+#[allow(clippy::identity_op)]
+pub const FEE_EXEMPTION_SIZE: usize = 8 + 1 + 1 + 32 + 32;
+
+/// Margin account owner exempt from CPI withdrawal fees, managed by the program owner.
+///
+/// Derived from `[b"fee_exemption", owner]`, so it covers all of the owner's margin accounts.
+#[account]
+pub struct FeeExemption {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub owner: Pubkey,
+    pub _reserved: [u8; 32],
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::Caller;
+
+// (!) INCLUSIVE of discriminator (8 bytes)
+#[constant]
+#[allow(clippy::identity_op)]
+pub const HOLD_SIZE: usize = 8 + 1 + 1 + 32 + 32 + 1 + 32 + 32 + 8 + 8 + 32;
+
+/// Authorization placed by a TAMM pool or TCOMP bid on part of a margin account,
+/// derived from `[b"hold", margin_account, caller_account, hold_id]`.
+///
+/// Held lamports can only be captured by `caller_account` until `expiry_slot`, what
+/// isn't captured goes back to the available balance once the hold is released. An
+/// expired hold stays locked until then, see `release_hold`.
+#[account]
+pub struct Hold {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub margin_account: Pubkey,
+    /// TAMM pool or TCOMP bid state that placed the hold.
+    pub caller_account: Pubkey,
+    pub caller: Caller,
+    pub hold_id: [u8; 32],
+    /// Refunded the rent once the hold is captured or released.
+    pub rent_payer: Pubkey,
+    pub amount: u64,
+    /// Last slot the hold can be captured in, anyone can release it afterwards.
+    pub expiry_slot: u64,
+    pub _reserved: [u8; 32],
+}
+
+impl Hold {
+    pub fn is_expired(&self, slot: u64) -> bool {
+        slot > self.expiry_slot
+    }
+}
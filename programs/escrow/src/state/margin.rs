@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
 // (!) INCLUSIVE of discriminator (8 bytes)
 #[constant]
@@ -8,14 +9,176 @@ pub const MARGIN_SIZE: usize = 8 + 32 + 32 + 2 + 1 + 4 + 64;
 // TODO: if size ever changes, be sure to update APPROX_SOL_MARGIN_RENT in tensor-infra
 #[account]
 pub struct MarginAccount {
+    /// Current owner, which is not the key the address was derived from once
+    /// ownership has been handed off (see `MarginAccountV2::seed_owner`).
     pub owner: Pubkey,
     pub name: [u8; 32],
     pub nr: u16,
     pub bump: [u8; 1],
     #[deprecated(note = "This field is no longer in sync")]
     pub pools_attached: u32,
-    // TODO: we forgot to track bids attached.
-    // Revisit this maybe for margin account V2.
+    // Bids were never tracked in V1, see `MarginAccountV2` for accurate counters.
     //(!) this is important - otherwise rent will be miscalculated by anchor client-side
     pub _reserved: [u8; 64],
 }
+
+// (!) INCLUSIVE of discriminator (8 bytes)
+#[constant]
+pub const MARGIN_V2_SIZE: usize =
+    MARGIN_SIZE + 1 + 4 + 4 + 4 + 4 + 8 + 8 + 32 + 32 + 9 + 1 + 8 + 8 + 8 + 1 + 69;
+
+/// V2 layout of the margin account.
+///
+/// V2 is a strict extension of V1: it shares the discriminator and the first
+/// `MARGIN_SIZE` bytes, so every instruction (and every external program) that
+/// decodes a `MarginAccount` keeps working on V2 accounts. The extra fields are
+/// appended after the V1 reserved space.
+// TODO: if size ever changes, be sure to update APPROX_SOL_MARGIN_RENT in tensor-infra
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MarginAccountV2 {
+    pub owner: Pubkey,
+    pub name: [u8; 32],
+    pub nr: u16,
+    pub bump: [u8; 1],
+    /// V1 counter, never in sync. Use `pools_attached` below instead.
+    pub _deprecated_pools_attached: u32,
+    pub _reserved: [u8; 64],
+
+    // --- V2 ---
+    pub version: u8,
+    /// Number of live TAMM pools using this account as shared escrow.
+    pub pools_attached: u32,
+    /// Number of live TCOMP bids using this account as margin.
+    pub bids_attached: u32,
+    /// Seconds owner withdrawals have to wait once requested, 0 when disabled.
+    pub withdrawal_delay: u32,
+    /// Lower delay waiting for `withdrawal_delay_unlock_at` before it applies.
+    pub pending_withdrawal_delay: u32,
+    pub withdrawal_delay_unlock_at: i64,
+    /// Lamports held by pending withdrawal requests, not available to CPI withdrawals.
+    pub pending_withdrawals: u64,
+    /// Key the address was derived from, default until ownership is handed off.
+    pub original_owner: Pubkey,
+    /// Owner proposed by the current owner, has to accept to take over.
+    pub pending_owner: Pubkey,
+    /// Owner withdrawals above this many lamports have to be co-signed by `TSwap::cosigner`,
+    /// on top of the global threshold.
+    pub cosign_threshold: Option<u64>,
+    /// Bitmask of `DENY_*` flags, callers the owner doesn't let withdraw from (or attach to) the account.
+    pub cpi_denied: u8,
+    /// Lamports held by reservations, only withdrawable by the callers they are reserved for.
+    pub reserved_lamports: u64,
+    /// Lamports held by holds until they are captured or released, expired ones included.
+    pub held_lamports: u64,
+    /// Slot the account was created (or migrated to V2) in. Allowances and consumer approvals
+    /// only apply to the generation they were granted on, not to a re-created account.
+    pub generation: u64,
+    /// Set on migration: pools and bids attached while on V1 were never counted, so the
+    /// account can't be closed until the owner reconciles them.
+    pub untracked_attachments: bool,
+    pub _reserved_v2: [u8; 69],
+}
+
+impl MarginAccountV2 {
+    /// V2 fields of a V1 account being migrated in slot `generation`, counters start at
+    /// zero: pools attached while on V1 were never tracked, `untracked_attachments` says so.
+    pub fn from_v1(margin: &MarginAccount, generation: u64) -> Self {
+        #[allow(deprecated)]
+        Self {
+            owner: margin.owner,
+            name: margin.name,
+            nr: margin.nr,
+            bump: margin.bump,
+            _deprecated_pools_attached: margin.pools_attached,
+            _reserved: margin._reserved,
+            version: crate::constants::CURRENT_MARGIN_VERSION,
+            pools_attached: 0,
+            bids_attached: 0,
+            withdrawal_delay: 0,
+            pending_withdrawal_delay: 0,
+            withdrawal_delay_unlock_at: 0,
+            pending_withdrawals: 0,
+            original_owner: Pubkey::default(),
+            pending_owner: Pubkey::default(),
+            cosign_threshold: None,
+            cpi_denied: 0,
+            reserved_lamports: 0,
+            held_lamports: 0,
+            generation,
+            untracked_attachments: true,
+            _reserved_v2: [0; 69],
+        }
+    }
+
+    pub fn has_attachments(&self) -> bool {
+        self.pools_attached > 0
+            || self.bids_attached > 0
+            || self.reserved_lamports > 0
+            || self.held_lamports > 0
+    }
+
+    pub fn is_timelocked(&self) -> bool {
+        self.withdrawal_delay > 0
+    }
+
+    /// Key the address was derived from, `owner` unless ownership was handed off.
+    pub fn seed_owner(&self) -> Pubkey {
+        if self.original_owner == Pubkey::default() {
+            self.owner
+        } else {
+            self.original_owner
+        }
+    }
+
+    /// Lamports that can't be withdrawn freely: pending withdrawal requests (only
+    /// executable by the owner), reservations (only drawable by their caller) and
+    /// holds (only capturable by their caller, released by anyone once expired).
+    pub fn locked_lamports(&self) -> u64 {
+        self.pending_withdrawals
+            .saturating_add(self.reserved_lamports)
+            .saturating_add(self.held_lamports)
+    }
+}
+
+impl Discriminator for MarginAccountV2 {
+    const DISCRIMINATOR: [u8; 8] = MarginAccount::DISCRIMINATOR;
+}
+
+impl Owner for MarginAccountV2 {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl AccountSerialize for MarginAccountV2 {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        if writer.write_all(&Self::DISCRIMINATOR).is_err() {
+            return Err(ErrorCode::AccountDidNotSerialize.into());
+        }
+
+        if AnchorSerialize::serialize(self, writer).is_err() {
+            return Err(ErrorCode::AccountDidNotSerialize.into());
+        }
+        Ok(())
+    }
+}
+
+impl AccountDeserialize for MarginAccountV2 {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        if buf.len() < Self::DISCRIMINATOR.len() {
+            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+        }
+        if buf[..8] != Self::DISCRIMINATOR {
+            return Err(error!(ErrorCode::AccountDiscriminatorMismatch)
+                .with_account_name("MarginAccountV2"));
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        // V1 accounts are too short to hold the V2 fields and fail here.
+        let mut data: &[u8] = &buf[8..];
+        AnchorDeserialize::deserialize(&mut data)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
@@ -1,5 +1,29 @@
+mod allowance;
+mod attachment;
+mod consumer_approval;
+mod cpi_consumer;
+mod cpi_return_data;
+mod external;
+mod fee_exemption;
+mod hold;
 mod margin;
+mod rate_limit;
+mod reservation;
 mod tswap;
+mod withdraw_mode;
+mod withdrawal_request;
 
+pub use allowance::*;
+pub use attachment::*;
+pub use consumer_approval::*;
+pub use cpi_consumer::*;
+pub use cpi_return_data::*;
+pub use external::*;
+pub use fee_exemption::*;
+pub use hold::*;
 pub use margin::*;
+pub use rate_limit::*;
+pub use reservation::*;
 pub use tswap::*;
+pub use withdraw_mode::*;
+pub use withdrawal_request::*;
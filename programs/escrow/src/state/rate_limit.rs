@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+
+/// Rolling-window cap on the lamports a CPI caller can withdraw, disabled while `limit` is 0.
+///
+/// Withdrawals are counted in fixed windows, and the previous window is weighted by how
+/// much of it still overlaps the rolling window, so a burst can't straddle two windows.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct RateLimit {
+    /// Lamports that can be withdrawn per window.
+    pub limit: u64,
+    /// Window length in seconds.
+    pub window: u32,
+    /// Start of the current fixed window.
+    pub window_start: i64,
+    /// Lamports withdrawn in the current fixed window.
+    pub current: u64,
+    /// Lamports withdrawn in the fixed window before.
+    pub previous: u64,
+}
+
+impl RateLimit {
+    pub fn is_enabled(&self) -> bool {
+        self.limit > 0 && self.window > 0
+    }
+
+    /// Changing the window restarts the accounting, the fixed windows no longer line up.
+    pub fn configure(&mut self, limit: u64, window: u32) {
+        if window != self.window {
+            *self = Self::default();
+        }
+        self.limit = limit;
+        self.window = window;
+    }
+
+    /// Lamports withdrawn over the rolling window ending at `now`.
+    pub fn used(&self, now: i64) -> u64 {
+        let window = i64::from(self.window);
+        let start = now - now.rem_euclid(window);
+        let (current, previous) = if start == self.window_start {
+            (self.current, self.previous)
+        } else if start - self.window_start == window {
+            (0, self.current)
+        } else {
+            (0, 0)
+        };
+
+        // share of the previous window still inside the rolling window
+        let overlap = u128::from((window - (now - start)) as u64);
+        let previous = u128::from(previous) * overlap / u128::from(self.window);
+        current.saturating_add(previous as u64)
+    }
+
+    /// Counts `lamports` against the limit. Fails with the usage it would have
+    /// reached when that is over the limit, leaving the accounting untouched.
+    pub fn try_consume(&mut self, lamports: u64, now: i64) -> std::result::Result<(), u64> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        let used = self.used(now).saturating_add(lamports);
+        if used > self.limit {
+            return Err(used);
+        }
+
+        let window = i64::from(self.window);
+        let start = now - now.rem_euclid(window);
+        if start != self.window_start {
+            self.previous = if start - self.window_start == window {
+                self.current
+            } else {
+                0
+            };
+            self.current = 0;
+            self.window_start = start;
+        }
+        self.current = self.current.saturating_add(lamports);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: u64 = 1_000;
+    const WINDOW: u32 = 100;
+
+    fn rate_limit() -> RateLimit {
+        let mut rate_limit = RateLimit::default();
+        rate_limit.configure(LIMIT, WINDOW);
+        rate_limit
+    }
+
+    #[test]
+    fn disabled_counts_nothing() {
+        let mut rate_limit = RateLimit::default();
+        assert!(rate_limit.try_consume(u64::MAX, 50).is_ok());
+        assert_eq!(rate_limit.current, 0);
+
+        rate_limit.configure(0, WINDOW);
+        assert!(rate_limit.try_consume(u64::MAX, 50).is_ok());
+        assert_eq!(rate_limit.current, 0);
+    }
+
+    #[test]
+    fn limit_within_window() {
+        let mut rate_limit = rate_limit();
+        assert!(rate_limit.try_consume(400, 10).is_ok());
+        assert!(rate_limit.try_consume(500, 50).is_ok());
+        assert_eq!(rate_limit.used(50), 900);
+
+        assert_eq!(rate_limit.try_consume(200, 60), Err(1_100));
+        // a failed withdrawal isn't counted
+        assert_eq!(rate_limit.used(60), 900);
+        assert!(rate_limit.try_consume(100, 60).is_ok());
+        assert_eq!(rate_limit.used(60), LIMIT);
+    }
+
+    #[test]
+    fn window_rollover_carries_previous_window() {
+        let mut rate_limit = rate_limit();
+        assert!(rate_limit.try_consume(800, 50).is_ok());
+
+        // the whole previous window still overlaps at the start of the next one
+        assert_eq!(rate_limit.used(100), 800);
+        assert_eq!(rate_limit.try_consume(300, 100), Err(1_100));
+
+        assert!(rate_limit.try_consume(200, 100).is_ok());
+        assert_eq!(rate_limit.window_start, 100);
+        assert_eq!(rate_limit.previous, 800);
+        assert_eq!(rate_limit.current, 200);
+    }
+
+    #[test]
+    fn previous_window_weighted_by_overlap() {
+        let mut rate_limit = rate_limit();
+        assert!(rate_limit.try_consume(800, 50).is_ok());
+
+        assert_eq!(rate_limit.used(125), 600);
+        assert_eq!(rate_limit.used(150), 400);
+        assert_eq!(rate_limit.used(175), 200);
+        assert_eq!(rate_limit.used(199), 8);
+
+        assert!(rate_limit.try_consume(600, 150).is_ok());
+        assert_eq!(rate_limit.used(150), LIMIT);
+        assert_eq!(rate_limit.try_consume(1, 150), Err(LIMIT + 1));
+    }
+
+    #[test]
+    fn skipped_windows_reset_usage() {
+        let mut rate_limit = rate_limit();
+        assert!(rate_limit.try_consume(LIMIT, 50).is_ok());
+
+        // a full window passed without withdrawals
+        assert_eq!(rate_limit.used(200), 0);
+        assert!(rate_limit.try_consume(LIMIT, 250).is_ok());
+        assert_eq!(rate_limit.window_start, 200);
+        assert_eq!(rate_limit.previous, 0);
+        assert_eq!(rate_limit.current, LIMIT);
+    }
+
+    #[test]
+    fn configure_resets_on_new_window() {
+        let mut rate_limit = rate_limit();
+        assert!(rate_limit.try_consume(800, 50).is_ok());
+
+        // same window: only the limit changes
+        rate_limit.configure(2 * LIMIT, WINDOW);
+        assert_eq!(rate_limit.limit, 2 * LIMIT);
+        assert_eq!(rate_limit.used(50), 800);
+
+        rate_limit.configure(LIMIT, 2 * WINDOW);
+        assert_eq!(rate_limit.limit, LIMIT);
+        assert_eq!(rate_limit.window, 2 * WINDOW);
+        assert_eq!(rate_limit.window_start, 0);
+        assert_eq!(rate_limit.used(50), 0);
+    }
+}
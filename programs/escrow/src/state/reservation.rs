@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+// (!) INCLUSIVE of discriminator (8 bytes)
+#[constant]
+#[allow(clippy::identity_op)]
+pub const RESERVATION_SIZE: usize = 8 + 1 + 1 + 32 + 32 + 32 + 8 + 32;
+
+/// Lamports of a margin account earmarked for one pool, bid or consumer signer,
+/// derived from `[b"reservation", margin_account, caller_account]`.
+///
+/// Reserved lamports can only be withdrawn by `caller_account`, which draws from
+/// its reservation before the unreserved balance.
+#[account]
+pub struct Reservation {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub margin_account: Pubkey,
+    /// TAMM pool, TCOMP bid state or cpi consumer signer the lamports are reserved for.
+    pub caller_account: Pubkey,
+    /// Refunded the rent once the reservation is released.
+    pub rent_payer: Pubkey,
+    pub amount: u64,
+    pub _reserved: [u8; 32],
+}
@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::RateLimit;
 
 // (!) INCLUSIVE of discriminator (8 bytes)
 #[constant]
@@ -28,3 +31,88 @@ impl TSwap {
 pub struct TSwapConfig {
     pub fee_bps: u16,
 }
+
+// (!) INCLUSIVE of discriminator (8 bytes)
+#[constant]
+pub const TSWAP_V2_SIZE: usize = TSWAP_SIZE + 32 + 32 + 1 + 36 + 36 + 1 + 3 + 3 + 9 + 8 + 95;
+
+/// V2 layout of the TSwap account.
+///
+/// Like `MarginAccountV2`, it shares the discriminator and the first `TSWAP_SIZE`
+/// bytes with V1, so everything decoding a `TSwap` keeps working once migrated.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TSwapV2 {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub config: TSwapConfig,
+    pub owner: Pubkey,
+    pub fee_vault: Pubkey,
+    pub cosigner: Pubkey,
+
+    // --- V2 ---
+    /// Owner proposed by the current owner, has to accept to take over.
+    pub pending_owner: Pubkey,
+    /// Can pause (but not unpause) the program alongside the owner, default when unset.
+    pub guardian: Pubkey,
+    /// Bitmask of `PAUSE_*` flags halting the matching instructions.
+    pub paused: u8,
+    /// Cap on what TAMM can withdraw across all margin accounts.
+    pub tamm_rate_limit: RateLimit,
+    /// Cap on what TCOMP can withdraw across all margin accounts.
+    pub tcomp_rate_limit: RateLimit,
+    /// Whether CPI withdrawals pay a fee into `fee_vault`.
+    pub fees_enabled: bool,
+    /// Fee on TAMM withdrawals, `config.fee_bps` when `None`.
+    pub tamm_fee_bps: Option<u16>,
+    /// Fee on TCOMP withdrawals, `config.fee_bps` when `None`.
+    pub tcomp_fee_bps: Option<u16>,
+    /// Owner withdrawals above this many lamports have to be co-signed by `cosigner`.
+    pub cosign_threshold: Option<u64>,
+    /// Slots a hold lives for once placed, `DEFAULT_HOLD_EXPIRY_SLOTS` when 0.
+    pub hold_expiry_slots: u64,
+    pub _reserved: [u8; 95],
+}
+
+impl Discriminator for TSwapV2 {
+    const DISCRIMINATOR: [u8; 8] = TSwap::DISCRIMINATOR;
+}
+
+impl Owner for TSwapV2 {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl AccountSerialize for TSwapV2 {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        if writer.write_all(&Self::DISCRIMINATOR).is_err() {
+            return Err(ErrorCode::AccountDidNotSerialize.into());
+        }
+
+        if AnchorSerialize::serialize(self, writer).is_err() {
+            return Err(ErrorCode::AccountDidNotSerialize.into());
+        }
+        Ok(())
+    }
+}
+
+impl AccountDeserialize for TSwapV2 {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        if buf.len() < Self::DISCRIMINATOR.len() {
+            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+        }
+        if buf[..8] != Self::DISCRIMINATOR {
+            return Err(
+                error!(ErrorCode::AccountDiscriminatorMismatch).with_account_name("TSwapV2")
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        // V1 accounts are too short to hold the V2 fields and fail here.
+        let mut data: &[u8] = &buf[8..];
+        AnchorDeserialize::deserialize(&mut data)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
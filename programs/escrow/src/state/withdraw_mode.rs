@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+/// How a CPI withdrawal handles a margin account that can't cover the requested lamports.
+///
+/// Trailing argument of the CPI withdraw instructions: callers built before it existed
+/// don't send it, which decodes as `Exact`.
+#[derive(AnchorSerialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WithdrawMode {
+    /// Fail the withdrawal (and with it the caller's transaction).
+    #[default]
+    Exact,
+    /// Withdraw what the margin account can cover above rent instead. The lamports
+    /// actually moved come back in `CpiReturnData::amount`.
+    UpToAvailable,
+}
+
+impl AnchorDeserialize for WithdrawMode {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut tag = [0u8; 1];
+        if reader.read(&mut tag)? == 0 {
+            return Ok(Self::Exact);
+        }
+
+        match tag[0] {
+            0 => Ok(Self::Exact),
+            1 => Ok(Self::UpToAvailable),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "invalid WithdrawMode",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::WithdrawMarginAccountCpiTamm;
+
+    #[test]
+    fn decode_mode() {
+        for mode in [WithdrawMode::Exact, WithdrawMode::UpToAvailable] {
+            let data = mode.try_to_vec().unwrap();
+            assert_eq!(WithdrawMode::try_from_slice(&data).unwrap(), mode);
+        }
+        assert!(WithdrawMode::try_from_slice(&[2]).is_err());
+    }
+
+    #[test]
+    fn missing_mode_decodes_as_exact() {
+        assert_eq!(
+            WithdrawMode::try_from_slice(&[]).unwrap(),
+            WithdrawMode::Exact
+        );
+    }
+
+    #[test]
+    fn decode_instruction_args_with_and_without_mode() {
+        // what callers built before the mode existed send
+        let mut data = vec![7];
+        data.extend_from_slice(&[1; 32]);
+        data.extend_from_slice(&500u64.to_le_bytes());
+
+        let args = WithdrawMarginAccountCpiTamm::try_from_slice(&data).unwrap();
+        assert_eq!(args.lamports, 500);
+        assert_eq!(args.mode, WithdrawMode::Exact);
+
+        data.push(1);
+        let args = WithdrawMarginAccountCpiTamm::try_from_slice(&data).unwrap();
+        assert_eq!(args.lamports, 500);
+        assert_eq!(args.mode, WithdrawMode::UpToAvailable);
+    }
+}
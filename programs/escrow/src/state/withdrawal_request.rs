@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+// (!) INCLUSIVE of discriminator (8 bytes)
+#[constant]
+#[allow(clippy::identity_op)]
+pub const WITHDRAWAL_REQUEST_SIZE: usize = 8 + 1 + 1 + 32 + 2 + 8 + 8 + 32;
+
+/// Owner withdrawal waiting for the margin account's delay, derived from
+/// `[b"withdrawal", margin_account, request_nr]`.
+#[account]
+pub struct WithdrawalRequest {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub margin_account: Pubkey,
+    pub request_nr: u16,
+    pub amount: u64,
+    /// Unix timestamp from which the request can be executed.
+    pub unlock_at: i64,
+    pub _reserved: [u8; 32],
+}
@@ -26,7 +26,52 @@ codama.update(
 codama.update(
   c.updateInstructionsVisitor({
     withdrawMarginAccountCpi: {
-      name: "withdrawMarginAccountFromTBid"
+      name: "withdrawMarginAccountFromConsumer"
+    },
+    reserveMarginLamportsCpi: {
+      name: "reserveMarginLamportsFromConsumer"
+    }
+  })
+);
+
+// V2 layouts reuse the V1 discriminators, so tell them apart by size.
+codama.update(
+  c.setAccountDiscriminatorFromFieldVisitor({
+    marginAccountV2: {
+      field: "discriminator",
+      value: c.bytesValueNode("base16", "85dcadd5b3d32bee")
+    },
+    tSwapV2: {
+      field: "discriminator",
+      value: c.bytesValueNode("base16", "a9d3ab24dbbd4fbc")
+    }
+  })
+);
+codama.update(
+  c.updateAccountsVisitor({
+    marginAccount: {
+      discriminators: [
+        c.sizeDiscriminatorNode(143),
+        c.fieldDiscriminatorNode("discriminator")
+      ]
+    },
+    marginAccountV2: {
+      discriminators: [
+        c.sizeDiscriminatorNode(344),
+        c.fieldDiscriminatorNode("discriminator")
+      ]
+    },
+    tSwap: {
+      discriminators: [
+        c.sizeDiscriminatorNode(108),
+        c.fieldDiscriminatorNode("discriminator")
+      ]
+    },
+    tSwapV2: {
+      discriminators: [
+        c.sizeDiscriminatorNode(364),
+        c.fieldDiscriminatorNode("discriminator")
+      ]
     }
   })
 );
@@ -50,6 +95,18 @@ codama.update(
         c.variablePdaSeedNode("marginNr", c.numberTypeNode("u16"))
       ]
     },
+    // V2 margin accounts are no longer derived from the TSwap singleton.
+    marginAccountV2: {
+      seeds: [
+        c.constantPdaSeedNodeFromString("utf8", "margin"),
+        c.variablePdaSeedNode(
+          "owner",
+          c.publicKeyTypeNode(),
+          "The address of the pool and escrow owner"
+        ),
+        c.variablePdaSeedNode("marginNr", c.numberTypeNode("u16"))
+      ]
+    },
     tSwap: {
       seeds: []
     }
@@ -66,32 +123,56 @@ codama.update(
   ])
 );
 
+// Shared instruction defaults.
+const initMarginAccountDefaults = {
+  accounts: {
+    marginAccount: {
+      defaultValue: c.pdaValueNode("marginAccount", [
+        c.pdaSeedValueNode("tswap", c.accountValueNode("tswap")),
+        c.pdaSeedValueNode("owner", c.accountValueNode("owner")),
+        c.pdaSeedValueNode("marginNr", c.argumentValueNode("marginNr"))
+      ])
+    }
+  },
+  arguments: {
+    marginNr: {
+      defaultValue: c.numberValueNode(0)
+    },
+    name: {
+      type: c.fixedSizeTypeNode(c.bytesTypeNode(), 32),
+      defaultValue: c.bytesValueNode(
+        "base16",
+        "0000000000000000000000000000000000000000000000000000000000000000"
+      )
+    }
+  }
+};
+const withdrawModeDefaults = {
+  arguments: {
+    mode: {
+      defaultValue: c.enumValueNode("withdrawMode", "exact")
+    }
+  }
+};
+
 // Update instructions.
 codama.update(
   c.updateInstructionsVisitor({
-    initMarginAccount: {
+    initMarginAccount: initMarginAccountDefaults,
+    initMarginAccountV2: {
+      ...initMarginAccountDefaults,
       accounts: {
         marginAccount: {
-          defaultValue: c.pdaValueNode("marginAccount", [
-            c.pdaSeedValueNode("tswap", c.accountValueNode("tswap")),
+          defaultValue: c.pdaValueNode("marginAccountV2", [
             c.pdaSeedValueNode("owner", c.accountValueNode("owner")),
             c.pdaSeedValueNode("marginNr", c.argumentValueNode("marginNr"))
           ])
         }
-      },
-      arguments: {
-        marginNr: {
-          defaultValue: c.numberValueNode(0)
-        },
-        name: {
-          type: c.fixedSizeTypeNode(c.bytesTypeNode(), 32),
-          defaultValue: c.bytesValueNode(
-            "base16",
-            "0000000000000000000000000000000000000000000000000000000000000000"
-          )
-        }
       }
     },
+    withdrawMarginAccountCpiTamm: withdrawModeDefaults,
+    withdrawMarginAccountCpiTcomp: withdrawModeDefaults,
+    withdrawMarginAccountFromConsumer: withdrawModeDefaults,
     // Set marginAccount default to marginNr=0 as seeds for depositMarginAccount/withdrawMarginAccount
     // not for closeMarginAccount, so one would have to explicitly state which marginAccount to close!
     depositMarginAccount: {